@@ -0,0 +1,107 @@
+use std::sync::Arc;
+
+use axum::{
+    extract::Extension,
+    http::{HeaderMap, StatusCode},
+    response::IntoResponse,
+    Json,
+};
+use tracing::{error, info, warn};
+use uuid::Uuid;
+
+use helix_common::kill_switch::KillSwitchUpdate;
+use helix_database::DatabaseService;
+use helix_datastore::Auctioneer;
+
+use crate::{
+    admin::error::AdminApiError,
+    gossiper::{traits::GossipClientTrait, types::BroadcastKillSwitchParams},
+};
+
+/// Operator endpoints. Every request must carry the configured `admin_api_key` in the
+/// `x-api-key` header; without a configured key all requests are rejected.
+#[derive(Clone)]
+pub struct AdminApi<A: Auctioneer, DB: DatabaseService, G: GossipClientTrait> {
+    auctioneer: Arc<A>,
+    db: Arc<DB>,
+    gossiper: Arc<G>,
+    api_key: Option<String>,
+}
+
+impl<A, DB, G> AdminApi<A, DB, G>
+where
+    A: Auctioneer + 'static,
+    DB: DatabaseService + 'static,
+    G: GossipClientTrait + 'static,
+{
+    pub fn new(auctioneer: Arc<A>, db: Arc<DB>, gossiper: Arc<G>, api_key: Option<String>) -> Self {
+        Self { auctioneer, db, gossiper, api_key }
+    }
+
+    /// Lists the kill switches currently in force.
+    pub async fn get_kill_switches(
+        Extension(admin_api): Extension<Arc<AdminApi<A, DB, G>>>,
+        headers: HeaderMap,
+    ) -> Result<impl IntoResponse, AdminApiError> {
+        admin_api.authorize(&headers)?;
+
+        Ok(Json(admin_api.auctioneer.get_kill_switches().await?))
+    }
+
+    /// Backpressure counters of the buffered block submission writer.
+    pub async fn get_submission_writer_stats(
+        Extension(admin_api): Extension<Arc<AdminApi<A, DB, G>>>,
+        headers: HeaderMap,
+    ) -> Result<impl IntoResponse, AdminApiError> {
+        admin_api.authorize(&headers)?;
+
+        Ok(Json(admin_api.db.submission_writer_stats()))
+    }
+
+    /// Enables or disables a kill switch, records the change for audit and gossips it so every
+    /// region applies it.
+    pub async fn update_kill_switch(
+        Extension(admin_api): Extension<Arc<AdminApi<A, DB, G>>>,
+        headers: HeaderMap,
+        Json(update): Json<KillSwitchUpdate>,
+    ) -> Result<StatusCode, AdminApiError> {
+        admin_api.authorize(&headers)?;
+
+        let request_id = Uuid::new_v4();
+        info!(request_id = %request_id, update = ?update, "kill switch update");
+
+        match &update {
+            KillSwitchUpdate::Enable(kill_switch) => {
+                admin_api.auctioneer.enable_kill_switch(kill_switch).await?
+            }
+            KillSwitchUpdate::Disable { scope } => {
+                admin_api.auctioneer.disable_kill_switch(scope).await?
+            }
+        }
+
+        if let Err(err) = admin_api.db.save_kill_switch_update(&update).await {
+            error!(request_id = %request_id, err = %err, "failed to save kill switch update");
+        }
+
+        if let Err(err) = admin_api
+            .gossiper
+            .broadcast_kill_switch(BroadcastKillSwitchParams { update, request_id })
+            .await
+        {
+            error!(request_id = %request_id, err = %err, "failed to broadcast kill switch");
+        }
+
+        Ok(StatusCode::OK)
+    }
+
+    fn authorize(&self, headers: &HeaderMap) -> Result<(), AdminApiError> {
+        let api_key = headers.get("x-api-key").and_then(|key| key.to_str().ok());
+        match (&self.api_key, api_key) {
+            (Some(expected), Some(api_key)) if expected == api_key => Ok(()),
+            _ => {
+                warn!("admin request with invalid api key");
+                Err(AdminApiError::InvalidApiKey)
+            }
+        }
+    }
+}
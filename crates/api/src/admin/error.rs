@@ -0,0 +1,27 @@
+use axum::{
+    http::StatusCode,
+    response::{IntoResponse, Response},
+};
+use helix_datastore::error::AuctioneerError;
+
+#[derive(Debug, thiserror::Error)]
+pub enum AdminApiError {
+    #[error("invalid api key")]
+    InvalidApiKey,
+    #[error("auctioneer error: {0}")]
+    AuctioneerError(#[from] AuctioneerError),
+}
+
+impl IntoResponse for AdminApiError {
+    fn into_response(self) -> Response {
+        match self {
+            AdminApiError::InvalidApiKey => {
+                (StatusCode::UNAUTHORIZED, "invalid api key").into_response()
+            }
+            AdminApiError::AuctioneerError(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("auctioneer error: {err}"))
+                    .into_response()
+            }
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod api;
+pub mod error;
+pub mod tests;
+
+pub use api::*;
@@ -0,0 +1,109 @@
+#[cfg(test)]
+mod admin_api_tests {
+    use std::sync::Arc;
+
+    use axum::{
+        http::{HeaderMap, HeaderValue, StatusCode},
+        response::IntoResponse,
+        Extension, Json,
+    };
+    use helix_common::kill_switch::{KillSwitch, KillSwitchScope, KillSwitchUpdate};
+    use helix_database::MockDatabaseService;
+    use helix_datastore::MockAuctioneer;
+
+    use crate::{
+        admin::{error::AdminApiError, AdminApi},
+        gossiper::mock_gossiper::MockGossiper,
+    };
+
+    type TestAdminApi = AdminApi<MockAuctioneer, MockDatabaseService, MockGossiper>;
+
+    const API_KEY: &str = "admin";
+
+    fn admin_api(auctioneer: Arc<MockAuctioneer>, api_key: Option<&str>) -> Arc<TestAdminApi> {
+        Arc::new(AdminApi::new(
+            auctioneer,
+            Arc::new(MockDatabaseService::default()),
+            Arc::new(MockGossiper::new().unwrap()),
+            api_key.map(str::to_string),
+        ))
+    }
+
+    fn headers(api_key: &str) -> HeaderMap {
+        let mut headers = HeaderMap::new();
+        headers.insert("x-api-key", HeaderValue::from_str(api_key).unwrap());
+        headers
+    }
+
+    fn enable_update() -> KillSwitchUpdate {
+        KillSwitchUpdate::Enable(KillSwitch {
+            scope: KillSwitchScope::BuilderId("builder".to_string()),
+            reason: "invalid blocks".to_string(),
+            expires_at_ms: None,
+        })
+    }
+
+    #[tokio::test]
+    async fn test_update_kill_switch() {
+        let auctioneer = Arc::new(MockAuctioneer::default());
+        let api = admin_api(auctioneer.clone(), Some(API_KEY));
+
+        let status = TestAdminApi::update_kill_switch(
+            Extension(api.clone()),
+            headers(API_KEY),
+            Json(enable_update()),
+        )
+        .await
+        .unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(auctioneer.kill_switches.lock().unwrap().len(), 1);
+
+        let disable =
+            KillSwitchUpdate::Disable { scope: KillSwitchScope::BuilderId("builder".to_string()) };
+        TestAdminApi::update_kill_switch(Extension(api), headers(API_KEY), Json(disable))
+            .await
+            .unwrap();
+        assert!(auctioneer.kill_switches.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_update_kill_switch_rejects_invalid_api_key() {
+        let auctioneer = Arc::new(MockAuctioneer::default());
+
+        let api = admin_api(auctioneer.clone(), Some(API_KEY));
+        let res = TestAdminApi::update_kill_switch(
+            Extension(api),
+            headers("wrong"),
+            Json(enable_update()),
+        )
+        .await;
+        assert!(matches!(res, Err(AdminApiError::InvalidApiKey)));
+
+        // Without a configured key every request is rejected.
+        let api = admin_api(auctioneer.clone(), None);
+        let res = TestAdminApi::update_kill_switch(
+            Extension(api),
+            headers(API_KEY),
+            Json(enable_update()),
+        )
+        .await;
+        assert!(matches!(res, Err(AdminApiError::InvalidApiKey)));
+
+        assert!(auctioneer.kill_switches.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_submission_writer_stats() {
+        let api = admin_api(Arc::new(MockAuctioneer::default()), Some(API_KEY));
+
+        let res =
+            TestAdminApi::get_submission_writer_stats(Extension(api.clone()), headers("wrong"))
+                .await;
+        assert!(matches!(res, Err(AdminApiError::InvalidApiKey)));
+
+        let res = TestAdminApi::get_submission_writer_stats(Extension(api), headers(API_KEY))
+            .await
+            .unwrap();
+        assert_eq!(res.into_response().status(), StatusCode::OK);
+    }
+}
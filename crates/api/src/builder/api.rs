@@ -1,6 +1,6 @@
 use std::{
     collections::HashMap,
-    io::Read,
+    future::Future,
     sync::Arc,
     time::{Duration, SystemTime, UNIX_EPOCH},
 };
@@ -18,13 +18,12 @@ use ethereum_consensus::{
     primitives::{BlsPublicKey, Hash32},
     ssz::{self, prelude::*},
 };
-use flate2::read::GzDecoder;
 use futures::StreamExt;
 use hyper::HeaderMap;
 use tokio::{
     sync::{
         mpsc::{self, error::SendError, Receiver, Sender},
-        RwLock,
+        RwLock, Semaphore,
     },
     time::{self, Instant},
 };
@@ -33,7 +32,11 @@ use uuid::Uuid;
 
 use helix_common::{
     api::{
-        builder_api::{BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry},
+        builder_api::{
+            BuilderGetValidatorsResponse, BuilderGetValidatorsResponseEntry, SubmissionStreamAck,
+            SubmissionStreamFrameHeader, SubmissionStreamKind, TopBidEncoding, TopBidEvent,
+            TopBidStreamUpdate, TopBidSubscription, TopBidUpdate,
+        },
         proposer_api::ValidatorRegistrationInfo,
     }, bid_submission::{
         cancellation::SignedCancellation,
@@ -41,13 +44,14 @@ use helix_common::{
             SignedHeaderSubmission,
         },
         BidSubmission, BidTrace, SignedBidSubmission,
-    }, chain_info::ChainInfo, signing::RelaySigningContext, simulator::BlockSimError, versioned_payload::PayloadAndBlobs, BuilderInfo, GossipedHeaderTrace, GossipedPayloadTrace, HeaderSubmissionTrace, RelayConfig, SignedBuilderBid, SubmissionTrace, ValidatorPreferences
+    }, chain_info::ChainInfo, kill_switch::KillSwitchTarget, signing::RelaySigningContext, simulator::BlockSimError, versioned_payload::PayloadAndBlobs, BuilderInfo, GossipedHeaderTrace, GossipedPayloadTrace, HeaderSubmissionTrace, RelayConfig, Route, SignedBuilderBid, SubmissionTrace, ValidatorPreferences
 };
 use helix_database::DatabaseService;
 use helix_datastore::{types::SaveBidAndUpdateTopBidResponse, Auctioneer};
 use helix_housekeeper::{ChainUpdate, PayloadAttributesUpdate, SlotUpdate};
 use helix_utils::{
     get_payload_attributes_key, has_reached_fork,
+    request_encoding::{ContentEncoding, ZstdDictionary},
 };
 
 use crate::{
@@ -66,6 +70,14 @@ use crate::{
 
 pub(crate) const MAX_PAYLOAD_LENGTH: usize = 1024 * 1024 * 10;
 
+/// Size of an SSZ encoded `BidTrace`. It is the first, fixed-size field of every
+/// `SignedBidSubmission`, so it always makes up the start of an SSZ submission body.
+const BID_TRACE_SSZ_LENGTH: usize = 236;
+
+/// Frames of a single submission stream that are processed at once. Further frames are left
+/// unread until one finishes, so a builder that sends faster than it is acked is slowed down.
+const MAX_IN_FLIGHT_STREAM_FRAMES: usize = 16;
+
 #[derive(Clone)]
 pub struct BuilderApi<A, DB, S, G>
 where
@@ -90,6 +102,9 @@ where
 
     proposer_duties_response: Arc<RwLock<Option<Vec<u8>>>>,
     payload_attributes: Arc<RwLock<HashMap<String, PayloadAttributesUpdate>>>,
+
+    /// Loaded from `RelayConfig::zstd_dictionary_path`.
+    zstd_dictionary: Option<ZstdDictionary>,
 }
 
 impl<A, DB, S, G> BuilderApi<A, DB, S, G>
@@ -112,6 +127,7 @@ where
         validator_preferences: Arc<ValidatorPreferences>,
     ) -> Self {
         let (db_sender, db_receiver) = mpsc::channel::<DbInfo>(10_000);
+        let zstd_dictionary = relay_config.zstd_dictionary.clone();
 
         // Spin up db processing task
         let db_clone = db.clone();
@@ -134,6 +150,7 @@ where
             proposer_duties_response: Arc::new(RwLock::new(None)),
             payload_attributes: Arc::new(RwLock::new(HashMap::new())),
             validator_preferences,
+            zstd_dictionary,
         };
 
         // Spin up gossip processing task
@@ -160,16 +177,22 @@ where
     /// Implements this API: <https://flashbots.github.io/relay-specs/#/Builder/getValidators>
     pub async fn get_validators(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
-    ) -> impl IntoResponse {
+    ) -> Result<impl IntoResponse, BuilderApiError> {
+        if let Some(kill_switch) =
+            api.auctioneer.find_kill_switch(KillSwitchTarget::new(Route::GetValidators)).await?
+        {
+            return Err(BuilderApiError::KillSwitchEnabled { reason: kill_switch.reason });
+        }
+
         let duty_bytes = api.proposer_duties_response.read().await.clone();
-        match duty_bytes {
+        Ok(match duty_bytes {
             Some(bytes) => Response::builder()
                 .status(StatusCode::OK)
                 .body(axum::body::Body::from(bytes.clone()))
                 .unwrap()
                 .into_response(),
             None => StatusCode::INTERNAL_SERVER_ERROR.into_response(),
-        }
+        })
     }
 
     /// Handles the submission of a new block by performing various checks and verifications
@@ -198,9 +221,67 @@ where
             timestamp_request_start = trace.receive,
         );
 
-        // Decode the incoming request body into a payload
-        let (payload, is_cancellations_enabled) =
-            decode_payload(req, &mut trace, &request_id).await?;
+        let api_key = get_api_key(req.headers());
+
+        // Decode the incoming request body into a payload. Uncompressed SSZ bodies are streamed so
+        // the bid can be rejected from its `BidTrace` before the full payload has arrived.
+        let is_streamable =
+            is_ssz_content_type(req.headers()) && get_content_encoding(req.headers()).is_none();
+        let (payload, is_cancellations_enabled, builder_info) = if is_streamable {
+            api.decode_payload_streaming(
+                req,
+                &mut trace,
+                head_slot,
+                next_duty.as_ref(),
+                api_key.as_deref(),
+                &request_id,
+            )
+            .await?
+        } else {
+            let (payload, is_cancellations_enabled) =
+                decode_payload(req, &mut trace, &request_id, api.zstd_dictionary.as_ref()).await?;
+            (payload, is_cancellations_enabled, None)
+        };
+
+        // Streamed bodies have already been checked from their bid trace
+        let builder_info = match builder_info {
+            Some(builder_info) => builder_info,
+            None => {
+                api.check_builder_access(
+                    payload.builder_public_key(),
+                    api_key.as_deref(),
+                    &request_id,
+                )
+                .await?
+            }
+        };
+
+        Self::handle_block_submission(
+            api,
+            payload,
+            is_cancellations_enabled,
+            builder_info,
+            trace,
+            head_slot,
+            next_duty,
+            request_id,
+        )
+        .await
+    }
+
+    /// Runs the `submit_block` checks, simulation and storage on an already decoded payload.
+    /// Shared by the HTTP handler and the submission stream, which pass in the builder info
+    /// returned by `check_builder_access`.
+    async fn handle_block_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        payload: SignedBidSubmission,
+        is_cancellations_enabled: bool,
+        builder_info: BuilderInfo,
+        mut trace: SubmissionTrace,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        request_id: Uuid,
+    ) -> Result<StatusCode, BuilderApiError> {
         let block_hash = payload.message().block_hash.clone();
 
         // Verify that we have a validator connected for this slot
@@ -235,6 +316,16 @@ where
             .fetch_payload_attributes(payload.slot(), payload.parent_hash(), &request_id)
             .await?;
 
+        // Enforce the builder's per-slot byte quota
+        api.check_builder_bytes_quota(
+            payload.builder_public_key(),
+            &builder_info,
+            payload.slot(),
+            trace.received_bytes,
+            &request_id,
+        )
+        .await?;
+
         // Handle duplicates.
         if let Err(err) = api
             .check_for_duplicate_block_hash(
@@ -277,8 +368,13 @@ where
             .await?;
         trace.floor_bid_checks = get_nanos_timestamp()?;
 
-        // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        // Handle blocked builders check
+        api.check_builder_not_blocked(
+            &next_duty,
+            &builder_info,
+            payload.builder_public_key(),
+            &request_id,
+        )?;
 
         // Handle trusted builders check
         if !api.check_if_trusted_builder(&next_duty, &builder_info).await {
@@ -294,6 +390,9 @@ where
             });
         }
 
+        // Handle kill switches
+        api.check_kill_switch(Route::SubmitBlock, &payload, &builder_info, &request_id).await?;
+
         // Verify payload has not already been delivered
         match api.auctioneer.get_last_slot_delivered().await {
             Ok(Some(slot)) => {
@@ -419,9 +518,42 @@ where
             timestamp_request_start = trace.receive,
         );
 
+        let api_key = get_api_key(req.headers());
+
         // Decode the incoming request body into a payload
-        let (mut payload, is_cancellations_enabled) =
-            decode_header_submission(req, &mut trace, &request_id).await?;
+        let (payload, is_cancellations_enabled) =
+            decode_header_submission(req, &mut trace, &request_id, api.zstd_dictionary.as_ref())
+                .await?;
+        let builder_info = api
+            .check_builder_access(payload.builder_public_key(), api_key.as_deref(), &request_id)
+            .await?;
+
+        Self::handle_header_submission(
+            api,
+            payload,
+            is_cancellations_enabled,
+            builder_info,
+            trace,
+            head_slot,
+            next_duty,
+            request_id,
+        )
+        .await
+    }
+
+    /// Runs the `submit_header` checks and storage on an already decoded header submission.
+    /// Shared by the HTTP handler and the submission stream, which pass in the builder info
+    /// returned by `check_builder_access`.
+    async fn handle_header_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        mut payload: SignedHeaderSubmission,
+        is_cancellations_enabled: bool,
+        builder_info: BuilderInfo,
+        mut trace: HeaderSubmissionTrace,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        request_id: Uuid,
+    ) -> Result<StatusCode, BuilderApiError> {
         let block_hash = payload.block_hash().clone();
 
         // Verify that we have a validator connected for this slot
@@ -456,8 +588,23 @@ where
             .fetch_payload_attributes(payload.slot(), payload.parent_hash(), &request_id)
             .await?;
 
-        // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        // Enforce the builder's per-slot byte quota
+        api.check_builder_bytes_quota(
+            payload.builder_public_key(),
+            &builder_info,
+            payload.slot(),
+            trace.received_bytes,
+            &request_id,
+        )
+        .await?;
+
+        // Handle blocked builders check
+        api.check_builder_not_blocked(
+            &next_duty,
+            &builder_info,
+            payload.builder_public_key(),
+            &request_id,
+        )?;
 
         // Submit header can only be processed optimistically.
         // Make sure that the builder has enough collateral to cover the submission.
@@ -526,6 +673,9 @@ where
             });
         }
 
+        // Handle kill switches
+        api.check_kill_switch(Route::SubmitHeader, &payload, &builder_info, &request_id).await?;
+
         trace.pre_checks = get_nanos_timestamp()?;
 
         // Verify the payload signature
@@ -652,9 +802,39 @@ where
             timestamp_request_start = trace.receive,
         );
 
+        let api_key = get_api_key(req.headers());
+
         // Decode the incoming request body into a payload
-        let (payload, _) = decode_payload(req, &mut trace, &request_id).await?;
+        let (payload, _) =
+            decode_payload(req, &mut trace, &request_id, api.zstd_dictionary.as_ref()).await?;
+        let builder_info = api
+            .check_builder_access(payload.builder_public_key(), api_key.as_deref(), &request_id)
+            .await?;
+
+        Self::handle_optimistic_v2_submission(
+            api,
+            payload,
+            builder_info,
+            trace,
+            head_slot,
+            next_duty,
+            request_id,
+        )
+        .await
+    }
 
+    /// Runs the `submit_block_v2` checks and storage on an already decoded payload.
+    /// Shared by the HTTP handler and the submission stream, which pass in the builder info
+    /// returned by `check_builder_access`.
+    async fn handle_optimistic_v2_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        payload: SignedBidSubmission,
+        builder_info: BuilderInfo,
+        mut trace: SubmissionTrace,
+        head_slot: u64,
+        next_duty: Option<BuilderGetValidatorsResponseEntry>,
+        request_id: Uuid,
+    ) -> Result<StatusCode, BuilderApiError> {
         let builder_pub_key = payload.builder_public_key().clone();
         let block_hash = payload.message().block_hash.clone();
         debug!(
@@ -704,8 +884,23 @@ where
             .fetch_payload_attributes(payload.slot(), payload.parent_hash(), &request_id)
             .await?;
 
-        // Fetch builder info
-        let builder_info = api.fetch_builder_info(payload.builder_public_key()).await;
+        // Enforce the builder's per-slot byte quota
+        api.check_builder_bytes_quota(
+            payload.builder_public_key(),
+            &builder_info,
+            payload.slot(),
+            trace.received_bytes,
+            &request_id,
+        )
+        .await?;
+
+        // Handle blocked builders check
+        api.check_builder_not_blocked(
+            &next_duty,
+            &builder_info,
+            payload.builder_public_key(),
+            &request_id,
+        )?;
 
         // submit_block_v2 can only be processed optimistically.
         // Make sure that the builder has enough collateral to cover the submission.
@@ -734,6 +929,10 @@ where
             });
         }
 
+        // Handle kill switches
+        api.check_kill_switch(Route::SubmitBlockOptimistic, &payload, &builder_info, &request_id)
+            .await?;
+
         // Verify payload has not already been delivered
         match api.auctioneer.get_last_slot_delivered().await {
             Ok(Some(slot)) => {
@@ -846,6 +1045,7 @@ where
     /// all other relays.
     pub async fn cancel_bid(
         Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        headers: HeaderMap,
         Json(mut signed_cancellation): Json<SignedCancellation>,
     ) -> Result<StatusCode, BuilderApiError> {
         let request_id = Uuid::new_v4();
@@ -873,6 +1073,27 @@ where
             return Err(BuilderApiError::SignatureVerificationFailed);
         }
 
+        // Verify the api key if the builder requires one
+        let builder_info =
+            api.fetch_builder_info(&signed_cancellation.message.builder_public_key).await;
+        if let Err(err) = check_builder_api_key(&builder_info, get_api_key(&headers).as_deref()) {
+            warn!(request_id = %request_id, "builder api key missing or invalid");
+            return Err(err);
+        }
+
+        // Handle kill switches
+        let mut kill_switch_target = KillSwitchTarget::new(Route::CancelBid);
+        kill_switch_target.builder_pubkey = Some(&signed_cancellation.message.builder_public_key);
+        kill_switch_target.proposer_pubkey = Some(&signed_cancellation.message.proposer_public_key);
+        if let Some(kill_switch) = api.auctioneer.find_kill_switch(kill_switch_target).await? {
+            warn!(
+                request_id = %request_id,
+                reason = %kill_switch.reason,
+                "cancellation rejected by kill switch",
+            );
+            return Err(BuilderApiError::KillSwitchEnabled { reason: kill_switch.reason });
+        }
+
         // Verify payload has not already been delivered
         match api.auctioneer.get_last_slot_delivered().await {
             Ok(Some(del_slot)) => {
@@ -915,20 +1136,250 @@ where
         headers: HeaderMap,
         ws: WebSocketUpgrade,
     ) -> Result<impl IntoResponse, BuilderApiError> {
-        let api_key = headers.get("x-api-key").and_then(|key| key.to_str().ok());
-        match api_key {
-            Some(key) => match api.db.check_builder_api_key(key).await {
-                Ok(true) => {}
-                Ok(false) => return Err(BuilderApiError::InvalidApiKey),
-                Err(err) => {
-                    error!(error = %err, "failed to check api key");
-                    return Err(BuilderApiError::InternalError)
+        let builder_pubkeys = api.fetch_builder_pubkeys_for_api_key(&headers).await?;
+
+        if let Some(kill_switch) =
+            api.auctioneer.find_kill_switch(KillSwitchTarget::new(Route::GetTopBid)).await?
+        {
+            return Err(BuilderApiError::KillSwitchEnabled { reason: kill_switch.reason });
+        }
+
+        Ok(ws.on_upgrade(move |socket| {
+            push_top_bids(socket, api.auctioneer.clone(), builder_pubkeys)
+        }))
+    }
+
+    /// Long-lived alternative to `submit_block`, `submit_header` and `submit_block_v2`.
+    ///
+    /// Builders authenticate the upgrade with their API key and then send binary frames, each a
+    /// `SubmissionStreamFrameHeader` followed by an SSZ encoded submission. Up to
+    /// `MAX_IN_FLIGHT_STREAM_FRAMES` frames are processed concurrently through the same pipeline
+    /// as the matching HTTP route and every frame is answered with a JSON `SubmissionStreamAck`
+    /// carrying the frame id. Submissions must be signed by a builder pubkey registered to the
+    /// API key.
+    pub async fn submit_block_stream(
+        Extension(api): Extension<Arc<BuilderApi<A, DB, S, G>>>,
+        headers: HeaderMap,
+        ws: WebSocketUpgrade,
+    ) -> Result<impl IntoResponse, BuilderApiError> {
+        let builder_pubkeys = api.fetch_builder_pubkeys_for_api_key(&headers).await?;
+        let api_key = get_api_key(&headers);
+
+        if let Some(kill_switch) =
+            api.auctioneer.find_kill_switch(KillSwitchTarget::new(Route::SubmitBlockStream)).await?
+        {
+            return Err(BuilderApiError::KillSwitchEnabled { reason: kill_switch.reason });
+        }
+
+        Ok(ws.max_message_size(MAX_PAYLOAD_LENGTH + SubmissionStreamFrameHeader::LEN).on_upgrade(
+            move |socket| Self::handle_submission_stream(api, socket, builder_pubkeys, api_key),
+        ))
+    }
+}
+
+// Handle the submission stream
+impl<A, DB, S, G> BuilderApi<A, DB, S, G>
+where
+    A: Auctioneer + 'static,
+    DB: DatabaseService + 'static,
+    S: BlockSimulator + 'static,
+    G: GossipClientTrait + 'static,
+{
+    async fn handle_submission_stream(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        mut socket: WebSocket,
+        builder_pubkeys: Vec<BlsPublicKey>,
+        api_key: Option<String>,
+    ) {
+        let builder_pubkeys = Arc::new(builder_pubkeys);
+        let (ack_sender, mut ack_receiver) = mpsc::channel::<SubmissionStreamAck>(1_000);
+        let in_flight = Arc::new(Semaphore::new(MAX_IN_FLIGHT_STREAM_FRAMES));
+        let mut interval = time::interval(Duration::from_secs(10));
+
+        loop {
+            tokio::select! {
+                Some(ack) = ack_receiver.recv() => {
+                    let message = match serde_json::to_string(&ack) {
+                        Ok(ack) => Message::Text(ack),
+                        Err(e) => {
+                            error!("Failed to serialize submission ack: {}", e);
+                            continue;
+                        }
+                    };
+                    if socket.send(message).await.is_err() {
+                        error!("Failed to send submission ack. Disconnecting.");
+                        break;
+                    }
+                },
+                _ = interval.tick() => {
+                    if socket.send(Message::Ping(Vec::new())).await.is_err() {
+                        error!("Failed to send ping.");
+                        break;
+                    }
+                },
+                msg = socket.next(), if in_flight.available_permits() > 0 => {
+                    match msg {
+                        Some(Ok(Message::Binary(frame))) => {
+                            // Only this loop acquires permits and it checked one is available
+                            let Ok(permit) = in_flight.clone().try_acquire_owned() else {
+                                continue;
+                            };
+                            let api = api.clone();
+                            let builder_pubkeys = builder_pubkeys.clone();
+                            let api_key = api_key.clone();
+                            let ack_sender = ack_sender.clone();
+                            tokio::spawn(async move {
+                                let ack = Self::process_stream_frame(
+                                    api,
+                                    &frame,
+                                    &builder_pubkeys,
+                                    api_key.as_deref(),
+                                )
+                                .await;
+                                drop(permit);
+                                // The receiver is only gone once the connection is closed.
+                                let _ = ack_sender.send(ack).await;
+                            });
+                        },
+                        Some(Ok(Message::Ping(data))) => {
+                            if socket.send(Message::Pong(data)).await.is_err() {
+                                error!("Failed to respond to ping.");
+                                break;
+                            }
+                        },
+                        Some(Ok(Message::Pong(_))) => {
+                            debug!("Received pong response.");
+                        },
+                        Some(Ok(Message::Text(_))) => {
+                            let ack = submission_stream_ack(
+                                0,
+                                Err(BuilderApiError::InvalidSubmissionStreamFrame),
+                            );
+                            if ack_sender.send(ack).await.is_err() {
+                                break;
+                            }
+                        },
+                        Some(Ok(Message::Close(_))) => {
+                            debug!("Received close frame.");
+                            break;
+                        },
+                        Some(Err(e)) => {
+                            error!("Error in WebSocket connection: {}", e);
+                            break;
+                        },
+                        None => {
+                            error!("WebSocket connection closed by the other side.");
+                            break;
+                        }
+                    }
                 }
-            },
-            None => return Err(BuilderApiError::InvalidApiKey),
+            }
         }
+    }
+
+    /// Decodes a single submission stream frame, runs it through the pipeline of the matching
+    /// HTTP route and builds its ack.
+    async fn process_stream_frame(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        frame: &[u8],
+        builder_pubkeys: &[BlsPublicKey],
+        api_key: Option<&str>,
+    ) -> SubmissionStreamAck {
+        let Some((header, body)) = SubmissionStreamFrameHeader::decode(frame) else {
+            return submission_stream_ack(0, Err(BuilderApiError::InvalidSubmissionStreamFrame));
+        };
+
+        let result =
+            Self::handle_stream_submission(api, header, body, builder_pubkeys, api_key).await;
+        submission_stream_ack(header.id, result)
+    }
+
+    async fn handle_stream_submission(
+        api: Arc<BuilderApi<A, DB, S, G>>,
+        header: SubmissionStreamFrameHeader,
+        body: &[u8],
+        builder_pubkeys: &[BlsPublicKey],
+        api_key: Option<&str>,
+    ) -> Result<StatusCode, BuilderApiError> {
+        let request_id = Uuid::new_v4();
+        let receive = get_nanos_timestamp()?;
+        let (head_slot, next_duty) = api.curr_slot_info.read().await.clone();
+
+        info!(
+            request_id = %request_id,
+            event = "submit_block_stream",
+            kind = ?header.kind,
+            frame_id = header.id,
+            head_slot = head_slot,
+            timestamp_request_start = receive,
+        );
 
-        Ok(ws.on_upgrade(move |socket| push_top_bids(socket, api.auctioneer.clone())))
+        match header.kind {
+            SubmissionStreamKind::SubmitBlock | SubmissionStreamKind::SubmitBlockOptimisticV2 => {
+                let received_bytes = body.len() as u64;
+                let mut trace = SubmissionTrace { receive, received_bytes, ..Default::default() };
+                let payload: SignedBidSubmission = ssz::prelude::deserialize(body)?;
+                trace.decode = get_nanos_timestamp()?;
+                check_builder_registered_for_api_key(
+                    payload.builder_public_key(),
+                    builder_pubkeys,
+                )?;
+                let builder_info = api
+                    .check_builder_access(payload.builder_public_key(), api_key, &request_id)
+                    .await?;
+
+                if header.kind == SubmissionStreamKind::SubmitBlock {
+                    Self::handle_block_submission(
+                        api,
+                        payload,
+                        header.cancellations_enabled,
+                        builder_info,
+                        trace,
+                        head_slot,
+                        next_duty,
+                        request_id,
+                    )
+                    .await
+                } else {
+                    Self::handle_optimistic_v2_submission(
+                        api,
+                        payload,
+                        builder_info,
+                        trace,
+                        head_slot,
+                        next_duty,
+                        request_id,
+                    )
+                    .await
+                }
+            }
+            SubmissionStreamKind::SubmitHeader => {
+                let received_bytes = body.len() as u64;
+                let mut trace =
+                    HeaderSubmissionTrace { receive, received_bytes, ..Default::default() };
+                let payload: SignedHeaderSubmission = ssz::prelude::deserialize(body)?;
+                trace.decode = get_nanos_timestamp()?;
+                check_builder_registered_for_api_key(
+                    payload.builder_public_key(),
+                    builder_pubkeys,
+                )?;
+                let builder_info = api
+                    .check_builder_access(payload.builder_public_key(), api_key, &request_id)
+                    .await?;
+
+                Self::handle_header_submission(
+                    api,
+                    payload,
+                    header.cancellations_enabled,
+                    builder_info,
+                    trace,
+                    head_slot,
+                    next_duty,
+                    request_id,
+                )
+                .await
+            }
+        }
     }
 }
 
@@ -955,7 +1406,7 @@ where
         };
 
         // Verify that the gossiped header is not for a past slot
-        let (head_slot, _) = self.curr_slot_info.read().await.clone();
+        let (head_slot, next_duty) = self.curr_slot_info.read().await.clone();
         if req.slot <= head_slot {
             warn!(
                 request_id = %request_id,
@@ -993,6 +1444,19 @@ where
             }
         }
 
+        // Drop headers a kill switch applies to
+        let builder_info = self.fetch_builder_info(&req.builder_pub_key).await;
+        let kill_switch_target = KillSwitchTarget {
+            route: Route::SubmitHeader,
+            builder_pubkey: Some(&req.builder_pub_key),
+            builder_id: builder_info.builder_id.as_deref(),
+            proposer_pubkey: Some(&req.proposer_pub_key),
+            proposer_pool: None,
+        };
+        if self.is_gossip_killed(kill_switch_target, &request_id).await {
+            return;
+        }
+
         // Verify the bid value is above the floor bid
         let floor_bid_value = match self
             .check_if_bid_is_below_floor(
@@ -1013,6 +1477,19 @@ where
             }
         };
 
+        // Drop headers from builders the proposer has blocked
+        if let Some(next_duty) = next_duty.as_ref().filter(|duty| duty.slot == req.slot) {
+            if self.check_if_blocked_builder(next_duty, &builder_info) {
+                warn!(
+                    request_id = %request_id,
+                    builder_pub_key = ?req.builder_pub_key,
+                    builder_id = ?builder_info.builder_id,
+                    "gossiped header from builder in proposer blocked builders list",
+                );
+                return;
+            }
+        }
+
         trace.pre_checks = get_nanos_timestamp().unwrap_or_default();
 
         // Save header to auctioneer
@@ -1103,6 +1580,13 @@ where
             }
         }
 
+        // Drop payloads a kill switch applies to
+        let mut kill_switch_target = KillSwitchTarget::new(Route::SubmitBlock);
+        kill_switch_target.proposer_pubkey = Some(&req.proposer_pub_key);
+        if self.is_gossip_killed(kill_switch_target, &request_id).await {
+            return;
+        }
+
         trace.pre_checks = get_nanos_timestamp().unwrap_or_default();
 
         // Save payload to auctioneer
@@ -1241,6 +1725,28 @@ where
         self.gossip_payload(payload, execution_payload, request_id).await;
     }
 
+    /// Returns true if a kill switch applies to a gossiped header or payload. Gossip doesn't
+    /// carry the route a submission came in on, so headers are checked as header submissions
+    /// and payloads as block submissions.
+    async fn is_gossip_killed(&self, target: KillSwitchTarget<'_>, request_id: &Uuid) -> bool {
+        match self.auctioneer.find_kill_switch(target).await {
+            Ok(Some(kill_switch)) => {
+                warn!(
+                    request_id = %request_id,
+                    scope = ?kill_switch.scope,
+                    reason = %kill_switch.reason,
+                    "gossiped message dropped by kill switch",
+                );
+                true
+            }
+            Ok(None) => false,
+            Err(err) => {
+                error!(request_id = %request_id, error = %err, "failed to check kill switches");
+                false
+            }
+        }
+    }
+
     async fn gossip_header(
         &self,
         builder_bid: SignedBuilderBid,
@@ -1491,6 +1997,42 @@ where
         }
     }
 
+    /// Returns true if the proposer has blocked the builder submitting a request.
+    ///
+    /// Builders without an ID are never blocked, as the blocked list only holds builder IDs.
+    fn check_if_blocked_builder(
+        &self,
+        next_duty: &BuilderGetValidatorsResponseEntry,
+        builder_info: &BuilderInfo,
+    ) -> bool {
+        match (&next_duty.entry.preferences.blocked_builders, &builder_info.builder_id) {
+            (Some(blocked_builders), Some(builder_id)) => blocked_builders.contains(builder_id),
+            _ => false,
+        }
+    }
+
+    /// Rejects a submission from a builder the proposer has blocked.
+    fn check_builder_not_blocked(
+        &self,
+        next_duty: &BuilderGetValidatorsResponseEntry,
+        builder_info: &BuilderInfo,
+        builder_pub_key: &BlsPublicKey,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        if self.check_if_blocked_builder(next_duty, builder_info) {
+            warn!(
+                request_id = %request_id,
+                builder_pub_key = ?builder_pub_key,
+                builder_id = ?builder_info.builder_id,
+                "builder in proposer blocked builders list",
+            );
+            return Err(BuilderApiError::BuilderBlockedByProposer {
+                builder_id: builder_info.builder_id.clone().unwrap_or_default(),
+            });
+        }
+        Ok(())
+    }
+
     /// Simulates a new block payload.
     ///
     /// 1. Checks the current top bid value from the auctioneer.
@@ -1704,60 +2246,280 @@ where
         Ok(())
     }
 
-    /// Checks if the builder has enough collateral to submit an optimistic bid.
-    /// Or if the builder is not optimistic.
-    ///
-    /// This function compares the builder's collateral with the block value for a bid submission.
-    /// If the builder's collateral is less than the required value, it returns an error.
-    async fn check_builder_collateral(
+    /// Streams an uncompressed SSZ `submit_block` body, running `check_bid_trace_prefix` as soon
+    /// as the `BidTrace` has arrived. Returns the builder info from `check_builder_access` if the
+    /// prefix checks ran, which they don't for bodies that turn out to be JSON.
+    async fn decode_payload_streaming(
         &self,
-        payload: &impl BidSubmission,
-        builder_info: &BuilderInfo,
+        req: Request<Body>,
+        trace: &mut SubmissionTrace,
+        head_slot: u64,
+        next_duty: Option<&BuilderGetValidatorsResponseEntry>,
+        api_key: Option<&str>,
         request_id: &Uuid,
-    ) -> Result<(), BuilderApiError> {
-        if !builder_info.is_optimistic {
-            warn!(
-                request_id = %request_id,
-                builder=%payload.builder_public_key(),
-                "builder is not optimistic"
-            );
-            return Err(BuilderApiError::BuilderNotOptimistic {
-                builder_pub_key: payload.builder_public_key().clone(),
-            });
-        } else if builder_info.collateral < payload.value() {
+    ) -> Result<(SignedBidSubmission, bool, Option<BuilderInfo>), BuilderApiError> {
+        let is_cancellations_enabled = get_cancellations_enabled(&req);
+
+        let (body_bytes, builder_info) =
+            read_body_with_bid_trace_checks(req.into_body(), |bid_trace| {
+                self.check_bid_trace_prefix(
+                    bid_trace,
+                    head_slot,
+                    next_duty,
+                    is_cancellations_enabled,
+                    api_key,
+                    request_id,
+                )
+            })
+            .await?;
+        trace.received_bytes = body_bytes.len() as u64;
+
+        let payload = decode_submission_body(&body_bytes, true, trace, request_id)?;
+        Ok((payload, is_cancellations_enabled, builder_info))
+    }
+
+    /// Early checks on the `BidTrace` of a submission that is still streaming in. The builder's
+    /// api key and per-second quota are checked first and only here. The full pipeline repeats
+    /// the other checks once the payload is decoded, so they must not cause side effects for a
+    /// bid that passes. This is why duplicate block hashes, which `submit_block` records and
+    /// tolerates, are not checked here.
+    async fn check_bid_trace_prefix(
+        &self,
+        bid_trace: BidTrace,
+        head_slot: u64,
+        next_duty: Option<&BuilderGetValidatorsResponseEntry>,
+        is_cancellations_enabled: bool,
+        api_key: Option<&str>,
+        request_id: &Uuid,
+    ) -> Result<BuilderInfo, BuilderApiError> {
+        let result = async {
+            let builder_info = self
+                .check_builder_access(&bid_trace.builder_public_key, api_key, request_id)
+                .await?;
+
+            if bid_trace.slot <= head_slot {
+                return Err(BuilderApiError::SubmissionForPastSlot {
+                    current_slot: head_slot,
+                    submission_slot: bid_trace.slot,
+                });
+            }
+
+            let Some(next_duty) = next_duty else {
+                return Err(BuilderApiError::ProposerDutyNotFound);
+            };
+            if bid_trace.slot != next_duty.slot {
+                return Err(BuilderApiError::SlotMismatch {
+                    got: bid_trace.slot,
+                    expected: next_duty.slot,
+                });
+            }
+            let expected_proposer = &next_duty.entry.registration.message.public_key;
+            if bid_trace.proposer_public_key != *expected_proposer {
+                return Err(BuilderApiError::ProposerPublicKeyMismatch {
+                    got: bid_trace.proposer_public_key.clone(),
+                    expected: expected_proposer.clone(),
+                });
+            }
+
+            self.check_if_bid_is_below_floor(
+                bid_trace.slot,
+                &bid_trace.parent_hash,
+                &bid_trace.proposer_public_key,
+                &bid_trace.builder_public_key,
+                bid_trace.value,
+                is_cancellations_enabled,
+                request_id,
+            )
+            .await?;
+            Ok(builder_info)
+        }
+        .await;
+
+        if let Err(err) = &result {
             warn!(
                 request_id = %request_id,
-                builder=?payload.builder_public_key(),
-                collateral=%builder_info.collateral,
-                collateral_required=%payload.value(),
-                "builder does not have enough collateral"
+                error = %err,
+                block_hash = ?bid_trace.block_hash,
+                "rejected submission from bid trace prefix",
             );
-            return Err(BuilderApiError::NotEnoughOptimisticCollateral {
-                builder_pub_key: payload.builder_public_key().clone(),
-                collateral: builder_info.collateral,
-                collateral_required: payload.value(),
-                is_optimistic: builder_info.is_optimistic,
-            });
         }
-
-        // Builder has enough collateral
-        Ok(())
+        result
     }
 
-    /// Fetch the builder's information. Default info is returned if fetching fails.
-    async fn fetch_builder_info(&self, builder_pub_key: &BlsPublicKey) -> BuilderInfo {
-        match self.auctioneer.get_builder_info(builder_pub_key).await {
-            Ok(info) => info,
+    /// Resolves the builder pubkeys registered to the `x-api-key` header.
+    async fn fetch_builder_pubkeys_for_api_key(
+        &self,
+        headers: &HeaderMap,
+    ) -> Result<Vec<BlsPublicKey>, BuilderApiError> {
+        let Some(api_key) = get_api_key(headers) else {
+            return Err(BuilderApiError::InvalidApiKey);
+        };
+
+        match self.db.get_builder_infos_by_api_key(&api_key).await {
+            Ok(builder_infos) if !builder_infos.is_empty() => {
+                Ok(builder_infos.into_iter().map(|builder_info| builder_info.pub_key).collect())
+            }
+            Ok(_) => Err(BuilderApiError::InvalidApiKey),
             Err(err) => {
-                warn!(
-                    builder=?builder_pub_key,
-                    err=%err,
-                    "Failed to retrieve builder info"
+                error!(error = %err, "failed to check api key");
+                Err(BuilderApiError::InternalError)
+            }
+        }
+    }
+
+    /// Fails with `KillSwitchEnabled` if a kill switch applies to the route, the submitting
+    /// builder or the proposer the submission is for.
+    async fn check_kill_switch(
+        &self,
+        route: Route,
+        payload: &impl BidSubmission,
+        builder_info: &BuilderInfo,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        let target = KillSwitchTarget {
+            route,
+            builder_pubkey: Some(payload.builder_public_key()),
+            builder_id: builder_info.builder_id.as_deref(),
+            proposer_pubkey: Some(payload.proposer_public_key()),
+            proposer_pool: None,
+        };
+        if let Some(kill_switch) = self.auctioneer.find_kill_switch(target).await? {
+            warn!(
+                request_id = %request_id,
+                scope = ?kill_switch.scope,
+                reason = %kill_switch.reason,
+                "submission rejected by kill switch",
+            );
+            return Err(BuilderApiError::KillSwitchEnabled { reason: kill_switch.reason });
+        }
+        Ok(())
+    }
+
+    /// Fetches the submitting builder's info, rejecting the submission if the builder requires an
+    /// api key that wasn't provided or is over its per-second quota. Runs as soon as the builder is
+    /// known, before anything touches the auction: the floor check, for one, deletes the
+    /// builder's bid when cancellations are enabled. If the quota counter can't be reached the
+    /// submission is let through rather than dropped.
+    async fn check_builder_access(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        api_key: Option<&str>,
+        request_id: &Uuid,
+    ) -> Result<BuilderInfo, BuilderApiError> {
+        let builder_info = self.fetch_builder_info(builder_pub_key).await;
+        if let Err(err) = check_builder_api_key(&builder_info, api_key) {
+            warn!(request_id = %request_id, "builder api key missing or invalid");
+            return Err(err);
+        }
+
+        if builder_info.max_submissions_per_second.is_none() {
+            return Ok(builder_info);
+        }
+
+        let submissions_this_second =
+            match self.auctioneer.record_builder_submission(builder_pub_key).await {
+                Ok(submissions) => submissions,
+                Err(err) => {
+                    error!(request_id = %request_id, error = %err, "failed to count submission");
+                    return Ok(builder_info);
+                }
+            };
+
+        if let Err(err) = check_submission_rate_quota(&builder_info, submissions_this_second) {
+            warn!(request_id = %request_id, error = %err, "builder submission quota exceeded");
+            return Err(err);
+        }
+        Ok(builder_info)
+    }
+
+    /// Counts the submitted bytes towards the builder's per-slot quota, failing open like
+    /// `check_builder_access`.
+    async fn check_builder_bytes_quota(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        builder_info: &BuilderInfo,
+        slot: u64,
+        received_bytes: u64,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        if builder_info.max_submission_bytes_per_slot.is_none() {
+            return Ok(());
+        }
+
+        let bytes_this_slot = match self
+            .auctioneer
+            .record_builder_submission_bytes(builder_pub_key, slot, received_bytes)
+            .await
+        {
+            Ok(bytes_this_slot) => bytes_this_slot,
+            Err(err) => {
+                error!(request_id = %request_id, error = %err, "failed to count submission bytes");
+                return Ok(());
+            }
+        };
+
+        if let Err(err) = check_submission_bytes_quota(builder_info, bytes_this_slot) {
+            warn!(request_id = %request_id, error = %err, "builder submission quota exceeded");
+            return Err(err);
+        }
+        Ok(())
+    }
+
+    /// Checks if the builder has enough collateral to submit an optimistic bid.
+    /// Or if the builder is not optimistic.
+    ///
+    /// This function compares the builder's collateral with the block value for a bid submission.
+    /// If the builder's collateral is less than the required value, it returns an error.
+    async fn check_builder_collateral(
+        &self,
+        payload: &impl BidSubmission,
+        builder_info: &BuilderInfo,
+        request_id: &Uuid,
+    ) -> Result<(), BuilderApiError> {
+        if !builder_info.is_optimistic {
+            warn!(
+                request_id = %request_id,
+                builder=%payload.builder_public_key(),
+                "builder is not optimistic"
+            );
+            return Err(BuilderApiError::BuilderNotOptimistic {
+                builder_pub_key: payload.builder_public_key().clone(),
+            });
+        } else if builder_info.collateral < payload.value() {
+            warn!(
+                request_id = %request_id,
+                builder=?payload.builder_public_key(),
+                collateral=%builder_info.collateral,
+                collateral_required=%payload.value(),
+                "builder does not have enough collateral"
+            );
+            return Err(BuilderApiError::NotEnoughOptimisticCollateral {
+                builder_pub_key: payload.builder_public_key().clone(),
+                collateral: builder_info.collateral,
+                collateral_required: payload.value(),
+                is_optimistic: builder_info.is_optimistic,
+            });
+        }
+
+        // Builder has enough collateral
+        Ok(())
+    }
+
+    /// Fetch the builder's information. Default info is returned if fetching fails.
+    async fn fetch_builder_info(&self, builder_pub_key: &BlsPublicKey) -> BuilderInfo {
+        match self.auctioneer.get_builder_info(builder_pub_key).await {
+            Ok(info) => info,
+            Err(err) => {
+                warn!(
+                    builder=?builder_pub_key,
+                    err=%err,
+                    "Failed to retrieve builder info"
                 );
                 BuilderInfo {
                     collateral: U256::ZERO,
                     is_optimistic: false,
                     builder_id: None,
+                    ..Default::default()
                 }
             }
         }
@@ -1896,35 +2658,15 @@ pub async fn decode_payload(
     req: Request<Body>,
     trace: &mut SubmissionTrace,
     request_id: &Uuid,
+    zstd_dictionary: Option<&ZstdDictionary>,
 ) -> Result<(SignedBidSubmission, bool), BuilderApiError> {
     // Extract the query parameters
-    let is_cancellations_enabled = req
-        .uri()
-        .query()
-        .unwrap_or("")
-        .split('&')
-        .find_map(|part| {
-            let mut split = part.splitn(2, '=');
-            if split.next()? == "cancellations" {
-                Some(split.next()? == "1")
-            } else {
-                None
-            }
-        })
-        .unwrap_or(false);
+    let is_cancellations_enabled = get_cancellations_enabled(&req);
 
     // Get content encoding and content type
-    let is_gzip = req
-        .headers()
-        .get("Content-Encoding")
-        .and_then(|val| val.to_str().ok())
-        .map_or(false, |v| v == "gzip");
+    let content_encoding = get_content_encoding(req.headers());
 
-    let is_ssz = req
-        .headers()
-        .get("Content-Type")
-        .and_then(|val| val.to_str().ok())
-        .map_or(false, |v| v == "application/octet-stream");
+    let is_ssz = is_ssz_content_type(req.headers());
 
     // Read the body
     let body = req.into_body();
@@ -1935,31 +2677,35 @@ pub async fn decode_payload(
             size: body_bytes.len(),
         });
     }
+    trace.received_bytes = body_bytes.len() as u64;
 
     // Decompress if necessary
-    if is_gzip {
-        let mut decoder = GzDecoder::new(&body_bytes[..]);
-
-        // TODO: profile this. 2 is a guess.
-        let estimated_size = body_bytes.len() * 2;
-        let mut buf = Vec::with_capacity(estimated_size);
-
-        decoder.read_to_end(&mut buf)?;
-        body_bytes = buf.into();
+    if let Some(content_encoding) = content_encoding {
+        body_bytes = decompress_body(&body_bytes, content_encoding, zstd_dictionary)?.into();
     }
 
-    // Decode payload
+    let payload = decode_submission_body(&body_bytes, is_ssz, trace, request_id)?;
+    Ok((payload, is_cancellations_enabled))
+}
+
+/// Decodes a fully read, uncompressed `submit_block` body.
+fn decode_submission_body(
+    body_bytes: &[u8],
+    is_ssz: bool,
+    trace: &mut SubmissionTrace,
+    request_id: &Uuid,
+) -> Result<SignedBidSubmission, BuilderApiError> {
     let payload: SignedBidSubmission = if is_ssz {
-        match ssz::prelude::deserialize(&body_bytes) {
+        match ssz::prelude::deserialize(body_bytes) {
             Ok(payload) => payload,
             Err(err) => {
                 // Fallback to JSON
                 warn!(request_id = %request_id, error = %err, "Failed to decode payload using SSZ; falling back to JSON");
-                serde_json::from_slice(&body_bytes)?
+                serde_json::from_slice(body_bytes)?
             }
         }
     } else {
-        serde_json::from_slice(&body_bytes)?
+        serde_json::from_slice(body_bytes)?
     };
 
     trace.decode = get_nanos_timestamp()?;
@@ -1975,7 +2721,63 @@ pub async fn decode_payload(
         num_tx = payload.execution_payload().transactions().len(),
     );
 
-    Ok((payload, is_cancellations_enabled))
+    Ok(payload)
+}
+
+/// Reads an uncompressed SSZ `submit_block` body. As soon as the fixed-size `BidTrace` prefix
+/// has arrived it is passed to `prefix_checks`, which runs while the rest of the body keeps
+/// streaming. If the checks fail the read is abandoned, so the bid is rejected without waiting
+/// for the full payload. Returns the body and the output of the checks, if they ran.
+async fn read_body_with_bid_trace_checks<F, Fut, T>(
+    body: Body,
+    prefix_checks: F,
+) -> Result<(Vec<u8>, Option<T>), BuilderApiError>
+where
+    F: FnOnce(BidTrace) -> Fut,
+    Fut: Future<Output = Result<T, BuilderApiError>>,
+{
+    let mut stream = body.into_data_stream();
+    let mut body_bytes = Vec::new();
+
+    // Read until the bid trace prefix is available
+    while body_bytes.len() < BID_TRACE_SSZ_LENGTH {
+        match stream.next().await {
+            Some(chunk) => extend_body(&mut body_bytes, &chunk?)?,
+            // Too short for a submission; the full decode reports the error
+            None => return Ok((body_bytes, None)),
+        }
+    }
+
+    // Bodies labelled as SSZ may still be JSON, which the full decode falls back to. Don't
+    // reject those based on a bogus bid trace. An SSZ body that looks like JSON only skips the
+    // early checks.
+    let first_byte = body_bytes.iter().find(|byte| !byte.is_ascii_whitespace());
+    if first_byte.map_or(true, |byte| *byte == b'{') {
+        while let Some(chunk) = stream.next().await {
+            extend_body(&mut body_bytes, &chunk?)?;
+        }
+        return Ok((body_bytes, None));
+    }
+
+    let bid_trace: BidTrace = ssz::prelude::deserialize(&body_bytes[..BID_TRACE_SSZ_LENGTH])?;
+    let read_rest = async {
+        while let Some(chunk) = stream.next().await {
+            extend_body(&mut body_bytes, &chunk?)?;
+        }
+        Ok::<_, BuilderApiError>(())
+    };
+    let (checked, _) = tokio::try_join!(prefix_checks(bid_trace), read_rest)?;
+
+    Ok((body_bytes, Some(checked)))
+}
+
+fn extend_body(body_bytes: &mut Vec<u8>, chunk: &[u8]) -> Result<(), BuilderApiError> {
+    let size = body_bytes.len() + chunk.len();
+    if size > MAX_PAYLOAD_LENGTH {
+        return Err(BuilderApiError::PayloadTooLarge { max_size: MAX_PAYLOAD_LENGTH, size });
+    }
+    body_bytes.extend_from_slice(chunk);
+    Ok(())
 }
 
 /// `push_top_bids` manages a WebSocket connection to continuously send the top auction bids to a
@@ -1983,6 +2785,9 @@ pub async fn decode_payload(
 ///
 /// - Periodically fetches the latest auction bids via a stream and sends them to the client in ssz
 ///   format.
+/// - Once the client sends a `TopBidSubscription` text frame, only bids for matching auctions are
+///   sent, as `TopBidStreamUpdate`s in the chosen encoding. If the subscription names a single
+///   auction its current top bid is sent straight away.
 /// - Sends a ping message every 10 seconds to maintain the connection's liveliness.
 /// - Terminates the connection on sending failures or if a bid stream error occurs, ensuring clean
 ///   disconnection.
@@ -1990,16 +2795,52 @@ pub async fn decode_payload(
 /// This function operates in an asynchronous loop until the WebSocket connection is closed either
 /// due to an error or when the auction ends. It returns after the socket has been closed, logging
 /// the closure status.
-async fn push_top_bids<A: Auctioneer + 'static>(mut socket: WebSocket, auctioneer: Arc<A>) {
+async fn push_top_bids<A: Auctioneer + 'static>(
+    mut socket: WebSocket,
+    auctioneer: Arc<A>,
+    builder_pubkeys: Vec<BlsPublicKey>,
+) {
     let mut bid_stream = auctioneer.get_best_bids().await;
     let mut interval = time::interval(Duration::from_secs(10));
+    let mut subscription: Option<TopBidSubscription> = None;
 
     loop {
         tokio::select! {
             Some(result) = bid_stream.next() => {
                 match result {
-                    Ok(bid) => {
-                        if socket.send(Message::Binary(bid)).await.is_err() {
+                    Ok(event) => {
+                        let message = match &subscription {
+                            None => match ssz::prelude::serialize(&event.update) {
+                                Ok(bid) => Message::Binary(bid),
+                                Err(e) => {
+                                    error!("Failed to serialize top bid update: {}", e);
+                                    continue;
+                                }
+                            },
+                            Some(subscription) if subscription.matches(&event) => {
+                                let TopBidEvent { update, proposer_pubkey, latest_bid_values } =
+                                    event;
+                                // Every subscriber receives the same event, so only the first
+                                // one to get here fetches the values
+                                let fetch_values = || {
+                                    get_latest_bid_values(&*auctioneer, &update, &proposer_pubkey)
+                                };
+                                let latest_values =
+                                    latest_bid_values.get_or_init(fetch_values).await;
+                                match encode_top_bid_update(
+                                    update,
+                                    proposer_pubkey,
+                                    latest_values,
+                                    &builder_pubkeys,
+                                    subscription.encoding,
+                                ) {
+                                    Some(message) => message,
+                                    None => continue,
+                                }
+                            }
+                            Some(_) => continue,
+                        };
+                        if socket.send(message).await.is_err() {
                             error!("Failed to send bid. Disconnecting.");
                             break;
                         }
@@ -2034,8 +2875,30 @@ async fn push_top_bids<A: Auctioneer + 'static>(mut socket: WebSocket, auctionee
                     Some(Ok(Message::Binary(_))) => {
                         debug!("Received Binary frame.");
                     },
-                    Some(Ok(Message::Text(_))) => {
-                        debug!("Received Text frame.");
+                    Some(Ok(Message::Text(text))) => {
+                        let parsed: Result<TopBidSubscription, _> = serde_json::from_str(&text);
+                        let new_subscription = match parsed {
+                            Ok(new_subscription) => new_subscription,
+                            Err(e) => {
+                                debug!("Received invalid subscription: {}", e);
+                                let error = Message::Text(format!("invalid subscription: {e}"));
+                                if socket.send(error).await.is_err() {
+                                    error!("Failed to send subscription error.");
+                                    break;
+                                }
+                                continue;
+                            }
+                        };
+
+                        let snapshot =
+                            top_bid_snapshot(&*auctioneer, &new_subscription, &builder_pubkeys);
+                        if let Some(message) = snapshot.await {
+                            if socket.send(message).await.is_err() {
+                                error!("Failed to send top bid snapshot. Disconnecting.");
+                                break;
+                            }
+                        }
+                        subscription = Some(new_subscription);
                     },
                     Some(Err(e)) => {
                         error!("Error in WebSocket connection: {}", e);
@@ -2055,6 +2918,176 @@ async fn push_top_bids<A: Auctioneer + 'static>(mut socket: WebSocket, auctionee
     debug!("Socket connection closed gracefully.");
 }
 
+/// Sends the current top bid when `subscription` names a single auction.
+async fn top_bid_snapshot<A: Auctioneer>(
+    auctioneer: &A,
+    subscription: &TopBidSubscription,
+    builder_pubkeys: &[BlsPublicKey],
+) -> Option<Message> {
+    let (Some(slot), Some(parent_hash), Some(proposer_pubkey)) =
+        (subscription.slot, &subscription.parent_hash, &subscription.proposer_pubkey)
+    else {
+        return None;
+    };
+
+    match auctioneer.get_top_bid_update(slot, parent_hash, proposer_pubkey).await {
+        Ok(Some(top_bid)) => {
+            let latest_values = get_latest_bid_values(auctioneer, &top_bid, proposer_pubkey).await;
+            encode_top_bid_update(
+                top_bid,
+                proposer_pubkey.clone(),
+                &latest_values,
+                builder_pubkeys,
+                subscription.encoding,
+            )
+        }
+        Ok(None) => None,
+        Err(err) => {
+            error!(err = %err, "failed to get top bid snapshot");
+            None
+        }
+    }
+}
+
+/// Latest bid value of every builder in the auction `top_bid` is for. Empty if the lookup fails.
+async fn get_latest_bid_values<A: Auctioneer>(
+    auctioneer: &A,
+    top_bid: &TopBidUpdate,
+    proposer_pubkey: &BlsPublicKey,
+) -> Vec<(BlsPublicKey, U256)> {
+    match auctioneer
+        .get_latest_bid_values(top_bid.slot, &top_bid.parent_hash, proposer_pubkey)
+        .await
+    {
+        Ok(latest_values) => latest_values,
+        Err(err) => {
+            error!(err = %err, "failed to get latest bid values");
+            Vec::new()
+        }
+    }
+}
+
+/// Adds the subscriber's own latest bid and rank, taken from `latest_values`, to `top_bid` and
+/// encodes it.
+fn encode_top_bid_update(
+    top_bid: TopBidUpdate,
+    proposer_pubkey: BlsPublicKey,
+    latest_values: &[(BlsPublicKey, U256)],
+    builder_pubkeys: &[BlsPublicKey],
+    encoding: TopBidEncoding,
+) -> Option<Message> {
+    let own_bid_value = latest_values
+        .iter()
+        .filter(|(builder_pubkey, _)| builder_pubkeys.contains(builder_pubkey))
+        .map(|(_, value)| *value)
+        .max();
+    let own_rank = own_bid_value.map(|own_bid_value| {
+        1 + latest_values.iter().filter(|(_, value)| *value > own_bid_value).count() as u64
+    });
+
+    let update = TopBidStreamUpdate {
+        top_bid,
+        proposer_pubkey,
+        own_bid_value: own_bid_value.unwrap_or_default(),
+        own_rank: own_rank.unwrap_or_default(),
+    };
+
+    match encoding {
+        TopBidEncoding::Ssz => match ssz::prelude::serialize(&update) {
+            Ok(bytes) => Some(Message::Binary(bytes)),
+            Err(err) => {
+                error!(err = %err, "failed to serialize top bid update");
+                None
+            }
+        },
+        TopBidEncoding::Json => match serde_json::to_string(&update) {
+            Ok(json) => Some(Message::Text(json)),
+            Err(err) => {
+                error!(err = %err, "failed to serialize top bid update");
+                None
+            }
+        },
+    }
+}
+
+/// Rejects submissions signed by a builder pubkey that isn't registered to the api key.
+fn check_builder_registered_for_api_key(
+    builder_pub_key: &BlsPublicKey,
+    builder_pubkeys: &[BlsPublicKey],
+) -> Result<(), BuilderApiError> {
+    if !builder_pubkeys.contains(builder_pub_key) {
+        return Err(BuilderApiError::BuilderNotRegisteredForApiKey {
+            pubkey: builder_pub_key.clone(),
+        });
+    }
+    Ok(())
+}
+
+/// Returns the `x-api-key` header, if present.
+fn get_api_key(headers: &HeaderMap) -> Option<String> {
+    headers.get("x-api-key").and_then(|key| key.to_str().ok()).map(str::to_string)
+}
+
+/// Rejects requests that don't carry the builder's api key if the builder requires one.
+fn check_builder_api_key(
+    builder_info: &BuilderInfo,
+    api_key: Option<&str>,
+) -> Result<(), BuilderApiError> {
+    if !builder_info.api_key_required {
+        return Ok(());
+    }
+    match api_key {
+        Some(api_key) if builder_info.is_api_key(api_key) => Ok(()),
+        _ => Err(BuilderApiError::InvalidApiKey),
+    }
+}
+
+/// Rejects the submission if it takes the builder over its per-second quota.
+fn check_submission_rate_quota(
+    builder_info: &BuilderInfo,
+    submissions_this_second: u64,
+) -> Result<(), BuilderApiError> {
+    match builder_info.max_submissions_per_second {
+        Some(limit) if submissions_this_second > limit => {
+            Err(BuilderApiError::SubmissionQuotaExceeded {
+                quota: "max_submissions_per_second",
+                limit,
+            })
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Rejects the submission if it takes the builder over its per-slot byte quota.
+fn check_submission_bytes_quota(
+    builder_info: &BuilderInfo,
+    bytes_this_slot: u64,
+) -> Result<(), BuilderApiError> {
+    match builder_info.max_submission_bytes_per_slot {
+        Some(limit) if bytes_this_slot > limit => Err(BuilderApiError::SubmissionQuotaExceeded {
+            quota: "max_submission_bytes_per_slot",
+            limit,
+        }),
+        _ => Ok(()),
+    }
+}
+
+/// Builds the ack for a submission stream frame, using the status code and message the HTTP
+/// route would have responded with.
+fn submission_stream_ack(
+    id: u64,
+    result: Result<StatusCode, BuilderApiError>,
+) -> SubmissionStreamAck {
+    match result {
+        Ok(status) => SubmissionStreamAck { id, status: status.as_u16(), error: None },
+        Err(err) => {
+            let error = err.to_string();
+            let status = err.into_response().status().as_u16();
+            SubmissionStreamAck { id, status, error: Some(error) }
+        }
+    }
+}
+
 /// `decode_payload` decodes the payload from `SubmitBlockParams` into a `SignedHeaderSubmission`
 /// object.
 ///
@@ -2067,38 +3100,31 @@ pub async fn decode_header_submission(
     req: Request<Body>,
     trace: &mut HeaderSubmissionTrace,
     request_id: &Uuid,
+    zstd_dictionary: Option<&ZstdDictionary>,
 ) -> Result<(SignedHeaderSubmission, bool), BuilderApiError> {
     // Extract the query parameters
-    let is_cancellations_enabled = req
-        .uri()
-        .query()
-        .unwrap_or("")
-        .split('&')
-        .find_map(|part| {
-            let mut split = part.splitn(2, '=');
-            if split.next()? == "cancellations" {
-                Some(split.next()? == "1")
-            } else {
-                None
-            }
-        })
-        .unwrap_or(false);
+    let is_cancellations_enabled = get_cancellations_enabled(&req);
 
-    let is_ssz = req
-        .headers()
-        .get("Content-Type")
-        .and_then(|val| val.to_str().ok())
-        .map_or(false, |v| v == "application/octet-stream");
+    // Get content encoding and content type
+    let content_encoding = get_content_encoding(req.headers());
+
+    let is_ssz = is_ssz_content_type(req.headers());
 
     // Read the body
     let body = req.into_body();
-    let body_bytes = to_bytes(body, MAX_PAYLOAD_LENGTH).await?;
+    let mut body_bytes = to_bytes(body, MAX_PAYLOAD_LENGTH).await?;
     if body_bytes.len() > MAX_PAYLOAD_LENGTH {
         return Err(BuilderApiError::PayloadTooLarge {
             max_size: MAX_PAYLOAD_LENGTH,
             size: body_bytes.len(),
         });
     }
+    trace.received_bytes = body_bytes.len() as u64;
+
+    // Decompress if necessary
+    if let Some(content_encoding) = content_encoding {
+        body_bytes = decompress_body(&body_bytes, content_encoding, zstd_dictionary)?.into();
+    }
 
     // Decode header
     let header: SignedHeaderSubmission = if is_ssz {
@@ -2129,6 +3155,57 @@ pub async fn decode_header_submission(
     Ok((header, is_cancellations_enabled))
 }
 
+/// Whether the request opted into cancellations with the `cancellations=1` query parameter.
+fn get_cancellations_enabled(req: &Request<Body>) -> bool {
+    req.uri()
+        .query()
+        .unwrap_or("")
+        .split('&')
+        .find_map(|part| {
+            let mut split = part.splitn(2, '=');
+            if split.next()? == "cancellations" {
+                Some(split.next()? == "1")
+            } else {
+                None
+            }
+        })
+        .unwrap_or(false)
+}
+
+/// Whether the body is SSZ encoded rather than JSON.
+fn is_ssz_content_type(headers: &HeaderMap) -> bool {
+    headers
+        .get("Content-Type")
+        .and_then(|val| val.to_str().ok())
+        .map_or(false, |v| v == "application/octet-stream")
+}
+
+/// Returns the compression named by the `Content-Encoding` header, if we support it.
+fn get_content_encoding(headers: &HeaderMap) -> Option<ContentEncoding> {
+    headers
+        .get("Content-Encoding")
+        .and_then(|val| val.to_str().ok())
+        .and_then(ContentEncoding::from_header_value)
+}
+
+/// Inflates a compressed submission body, rejecting bodies that inflate past
+/// `MAX_PAYLOAD_LENGTH`.
+fn decompress_body(
+    body_bytes: &[u8],
+    content_encoding: ContentEncoding,
+    zstd_dictionary: Option<&ZstdDictionary>,
+) -> Result<Vec<u8>, BuilderApiError> {
+    let decompressed =
+        content_encoding.decompress(body_bytes, zstd_dictionary, MAX_PAYLOAD_LENGTH + 1)?;
+    if decompressed.len() > MAX_PAYLOAD_LENGTH {
+        return Err(BuilderApiError::PayloadTooLarge {
+            max_size: MAX_PAYLOAD_LENGTH,
+            size: decompressed.len(),
+        });
+    }
+    Ok(decompressed)
+}
+
 /// - Validates the expected block.timestamp.
 /// - Ensures that the fee recipients in the payload and proposer duty match.
 /// - Ensures that the slot in the payload and payload attributes match.
@@ -2333,9 +3410,20 @@ mod tests {
         header::{CONTENT_ENCODING, CONTENT_TYPE},
         HeaderValue, Uri,
     };
+    use helix_common::{
+        capella, hash_api_key,
+        kill_switch::{KillSwitch, KillSwitchScope},
+    };
+    use futures::SinkExt;
+    use helix_database::MockDatabaseService;
+    use helix_datastore::MockAuctioneer;
+    use std::sync::atomic::Ordering;
+    use tokio_tungstenite::{connect_async, tungstenite};
 
     use uuid::Uuid;
 
+    use crate::{builder::mock_simulator::MockSimulator, gossiper::mock_gossiper::MockGossiper};
+
     async fn build_test_request(payload: Vec<u8>, is_gzip: bool, is_ssz: bool) -> Request<Body> {
         let mut req = Request::new(Body::from(payload));
         *req.uri_mut() = Uri::from_static("/some_path?cancellations=1");
@@ -2624,7 +3712,7 @@ mod tests {
         let mut trace = create_test_submission_trace().await;
         let request_id = create_test_uuid().await;
 
-        let result = decode_payload(req, &mut trace, &request_id).await;
+        let result = decode_payload(req, &mut trace, &request_id, None).await;
         match result {
             Ok(_) => panic!("Should have failed"),
             Err(err) => match err {
@@ -2635,4 +3723,638 @@ mod tests {
             },
         }
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_encode_top_bid_update() {
+        let own_pubkey = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        let other_pubkey = BlsPublicKey::try_from([2u8; 48].as_ref()).unwrap();
+        let latest_values = vec![
+            (own_pubkey.clone(), U256::from(200)),
+            (other_pubkey, U256::from(300)),
+            (BlsPublicKey::default(), U256::from(100)),
+        ];
+
+        let subscription: TopBidSubscription =
+            serde_json::from_str(r#"{"slot": 1, "encoding": "json"}"#).unwrap();
+        assert_eq!(subscription.slot, Some(1));
+        assert_eq!(subscription.encoding, TopBidEncoding::Json);
+
+        let top_bid = TopBidUpdate { slot: 1, value: U256::from(300), ..Default::default() };
+        let message = encode_top_bid_update(
+            top_bid,
+            BlsPublicKey::default(),
+            &latest_values,
+            &[own_pubkey],
+            subscription.encoding,
+        );
+
+        let Some(Message::Text(json)) = message else { panic!("expected a text message") };
+        let update: TopBidStreamUpdate = serde_json::from_str(&json).unwrap();
+        assert_eq!(update.top_bid.slot, 1);
+        assert_eq!(update.own_bid_value, U256::from(200));
+        assert_eq!(update.own_rank, 2);
+    }
+
+    #[test]
+    fn test_encode_top_bid_update_without_own_bid() {
+        let latest_values = vec![(BlsPublicKey::default(), U256::from(100))];
+
+        let own_pubkey = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        let message = encode_top_bid_update(
+            TopBidUpdate::default(),
+            BlsPublicKey::default(),
+            &latest_values,
+            &[own_pubkey],
+            TopBidEncoding::Ssz,
+        );
+
+        let Some(Message::Binary(bytes)) = message else { panic!("expected a binary message") };
+        let update: TopBidStreamUpdate = ssz::prelude::deserialize(&bytes).unwrap();
+        assert_eq!(update.own_bid_value, U256::ZERO);
+        assert_eq!(update.own_rank, 0);
+    }
+
+    #[test]
+    fn test_submission_stream_frame_header_roundtrip() {
+        let header = SubmissionStreamFrameHeader {
+            kind: SubmissionStreamKind::SubmitHeader,
+            cancellations_enabled: true,
+            id: 42,
+        };
+        let mut frame = header.encode().to_vec();
+        frame.extend_from_slice(&[1, 2, 3]);
+
+        let (decoded, body) = SubmissionStreamFrameHeader::decode(&frame).unwrap();
+        assert_eq!(decoded, header);
+        assert_eq!(body, &[1, 2, 3]);
+
+        // Unknown kind
+        frame[0] = 9;
+        assert!(SubmissionStreamFrameHeader::decode(&frame).is_none());
+
+        // Truncated header
+        assert!(SubmissionStreamFrameHeader::decode(&frame[..4]).is_none());
+    }
+
+    #[test]
+    fn test_submission_stream_ack() {
+        let ack = submission_stream_ack(7, Ok(StatusCode::OK));
+        assert_eq!(ack, SubmissionStreamAck { id: 7, status: 200, error: None });
+
+        let ack = submission_stream_ack(8, Err(BuilderApiError::ProposerDutyNotFound));
+        assert_eq!(ack.id, 8);
+        assert_eq!(ack.status, 400);
+        assert_eq!(ack.error.as_deref(), Some("could not find proposer duty for slot"));
+    }
+
+    type SubmissionStream = tokio_tungstenite::WebSocketStream<
+        tokio_tungstenite::MaybeTlsStream<tokio::net::TcpStream>,
+    >;
+
+    /// Serves `submit_block_stream` on a local port and connects to it with a valid API key.
+    async fn connect_submission_stream(api: Arc<TestBuilderApi>) -> SubmissionStream {
+        let router = axum::Router::new()
+            .route(
+                &Route::SubmitBlockStream.path(),
+                axum::routing::get(TestBuilderApi::submit_block_stream),
+            )
+            .layer(Extension(api));
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+
+        let request = tungstenite::http::Request::builder()
+            .uri(format!("ws://{addr}{}", Route::SubmitBlockStream.path()))
+            .header("X-api-key", "valid")
+            .body(())
+            .unwrap();
+        connect_async(request).await.unwrap().0
+    }
+
+    fn stream_frame(kind: SubmissionStreamKind, id: u64, body: &[u8]) -> Vec<u8> {
+        let header = SubmissionStreamFrameHeader { kind, cancellations_enabled: false, id };
+        [header.encode().as_slice(), body].concat()
+    }
+
+    /// Next message other than the relay's keepalive pings, or `None` if there is none within
+    /// `wait`.
+    async fn next_stream_message(
+        stream: &mut SubmissionStream,
+        wait: Duration,
+    ) -> Option<tungstenite::Message> {
+        loop {
+            match time::timeout(wait, stream.next()).await {
+                Ok(Some(Ok(tungstenite::Message::Ping(_)))) => continue,
+                Ok(Some(Ok(message))) => return Some(message),
+                _ => return None,
+            }
+        }
+    }
+
+    fn parse_ack(message: tungstenite::Message) -> SubmissionStreamAck {
+        let tungstenite::Message::Text(ack) = message else { panic!("expected a text ack") };
+        serde_json::from_str(&ack).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_submission_stream_acks_every_frame() {
+        let api = build_test_api(Arc::new(MockAuctioneer::default()));
+        let mut stream = connect_submission_stream(api).await;
+
+        let frames = [
+            stream_frame(SubmissionStreamKind::SubmitBlock, 1, &[1, 2, 3]),
+            stream_frame(SubmissionStreamKind::SubmitHeader, 2, &[]),
+            // Too short to hold a frame header
+            vec![0, 0, 1],
+        ];
+        for frame in frames {
+            stream.send(tungstenite::Message::Binary(frame)).await.unwrap();
+        }
+        stream.send(tungstenite::Message::Text("not a frame".to_string())).await.unwrap();
+
+        let mut acks = Vec::new();
+        for _ in 0..4 {
+            let message = next_stream_message(&mut stream, Duration::from_secs(5)).await;
+            acks.push(parse_ack(message.expect("missing ack")));
+        }
+        acks.sort_by_key(|ack| ack.id);
+
+        assert_eq!(acks.iter().map(|ack| ack.id).collect::<Vec<_>>(), vec![0, 0, 1, 2]);
+        for ack in &acks {
+            assert_eq!(ack.status, 400);
+            assert!(ack.error.is_some());
+        }
+        let invalid_frame = BuilderApiError::InvalidSubmissionStreamFrame.to_string();
+        assert!(acks[..2].iter().all(|ack| ack.error.as_deref() == Some(invalid_frame.as_str())));
+    }
+
+    #[tokio::test]
+    async fn test_submission_stream_backpressure() {
+        let api = build_test_api(Arc::new(MockAuctioneer::default()));
+        let mut stream = connect_submission_stream(api.clone()).await;
+
+        // Frames wait on the slot info while it is locked, so none of them completes
+        let slot_info = api.curr_slot_info.write().await;
+        let num_frames = MAX_IN_FLIGHT_STREAM_FRAMES as u64 + 1;
+        for id in 1..=num_frames {
+            let frame = stream_frame(SubmissionStreamKind::SubmitBlock, id, &[]);
+            stream.send(tungstenite::Message::Binary(frame)).await.unwrap();
+        }
+        stream.send(tungstenite::Message::Ping(vec![7])).await.unwrap();
+
+        // With every permit taken the relay stops reading, so the ping isn't answered either
+        assert!(next_stream_message(&mut stream, Duration::from_millis(300)).await.is_none());
+
+        drop(slot_info);
+        let mut ack_ids = Vec::new();
+        let mut ponged = false;
+        while ack_ids.len() < num_frames as usize || !ponged {
+            match next_stream_message(&mut stream, Duration::from_secs(5)).await {
+                Some(tungstenite::Message::Pong(data)) => {
+                    assert_eq!(data, vec![7]);
+                    ponged = true;
+                }
+                Some(message) => ack_ids.push(parse_ack(message).id),
+                None => panic!("stream stalled after the frames were released"),
+            }
+        }
+        ack_ids.sort();
+        assert_eq!(ack_ids, (1..=num_frames).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_check_builder_registered_for_api_key() {
+        let registered = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        assert!(check_builder_registered_for_api_key(&registered, &[registered.clone()]).is_ok());
+
+        let res = check_builder_registered_for_api_key(&BlsPublicKey::default(), &[registered]);
+        assert!(matches!(res, Err(BuilderApiError::BuilderNotRegisteredForApiKey { .. })));
+    }
+
+    #[test]
+    fn test_check_builder_api_key() {
+        let mut builder_info =
+            BuilderInfo { api_key_hash: Some(hash_api_key("key")), ..Default::default() };
+        assert!(check_builder_api_key(&builder_info, None).is_ok());
+
+        builder_info.api_key_required = true;
+        assert!(check_builder_api_key(&builder_info, Some("key")).is_ok());
+        let res = check_builder_api_key(&builder_info, Some("other"));
+        assert!(matches!(res, Err(BuilderApiError::InvalidApiKey)));
+        let res = check_builder_api_key(&builder_info, None);
+        assert!(matches!(res, Err(BuilderApiError::InvalidApiKey)));
+
+        builder_info.api_key_hash = None;
+        let res = check_builder_api_key(&builder_info, Some("key"));
+        assert!(matches!(res, Err(BuilderApiError::InvalidApiKey)));
+    }
+
+    #[test]
+    fn test_check_builder_quotas() {
+        let builder_info = BuilderInfo {
+            max_submissions_per_second: Some(2),
+            max_submission_bytes_per_slot: Some(1_000),
+            ..Default::default()
+        };
+
+        assert!(check_submission_rate_quota(&builder_info, 2).is_ok());
+        assert!(check_submission_bytes_quota(&builder_info, 1_000).is_ok());
+
+        let res = check_submission_rate_quota(&builder_info, 3);
+        assert!(matches!(
+            res,
+            Err(BuilderApiError::SubmissionQuotaExceeded {
+                quota: "max_submissions_per_second",
+                limit: 2
+            })
+        ));
+
+        let err = check_submission_bytes_quota(&builder_info, 1_001).unwrap_err();
+        assert_eq!(err.into_response().status(), StatusCode::TOO_MANY_REQUESTS);
+
+        assert!(check_submission_rate_quota(&BuilderInfo::default(), 100).is_ok());
+        assert!(check_submission_bytes_quota(&BuilderInfo::default(), u64::MAX).is_ok());
+    }
+
+    fn bid_trace_prefixed_body(slot: u64) -> Vec<u8> {
+        let bid_trace = BidTrace { slot, ..Default::default() };
+        let mut body = ssz::prelude::serialize(&bid_trace).unwrap();
+        body.extend_from_slice(&[0u8; 64]);
+        body
+    }
+
+    #[test]
+    fn test_bid_trace_ssz_length() {
+        let bid_trace = ssz::prelude::serialize(&BidTrace::default()).unwrap();
+        assert_eq!(bid_trace.len(), BID_TRACE_SSZ_LENGTH);
+    }
+
+    #[tokio::test]
+    async fn test_read_body_rejects_from_bid_trace_before_body_completes() {
+        let prefix = bid_trace_prefixed_body(5);
+        // The body never finishes streaming
+        let chunks = futures::stream::iter([Ok::<_, std::io::Error>(prefix)])
+            .chain(futures::stream::pending());
+
+        let result = time::timeout(
+            Duration::from_secs(1),
+            read_body_with_bid_trace_checks(Body::from_stream(chunks), |bid_trace| async move {
+                assert_eq!(bid_trace.slot, 5);
+                Err::<(), _>(BuilderApiError::ProposerDutyNotFound)
+            }),
+        )
+        .await
+        .expect("should reject without waiting for the rest of the body");
+
+        assert!(matches!(result, Err(BuilderApiError::ProposerDutyNotFound)));
+    }
+
+    #[tokio::test]
+    async fn test_read_body_with_passing_bid_trace_checks() {
+        let body = bid_trace_prefixed_body(5);
+        let chunks: Vec<_> =
+            body.chunks(100).map(|chunk| Ok::<_, std::io::Error>(chunk.to_vec())).collect();
+
+        let (result, checked) = read_body_with_bid_trace_checks(
+            Body::from_stream(futures::stream::iter(chunks)),
+            |_| async { Ok::<_, BuilderApiError>(()) },
+        )
+        .await
+        .unwrap();
+
+        assert_eq!(result, body);
+        assert!(checked.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_read_body_skips_bid_trace_checks_for_json() {
+        for whitespace in ["", "\n  ", "\r\n\t"] {
+            let body =
+                format!("{whitespace}{{\"message\": \"{}\"}}", "a".repeat(BID_TRACE_SSZ_LENGTH))
+                    .into_bytes();
+
+            let (result, checked) =
+                read_body_with_bid_trace_checks(Body::from(body.clone()), |_| async {
+                    Err::<(), _>(BuilderApiError::ProposerDutyNotFound)
+                })
+                .await
+                .unwrap();
+
+            assert_eq!(result, body);
+            assert!(checked.is_none());
+        }
+    }
+
+    const BLOCKED_BUILDER_ID: &str = "blocked-builder";
+
+    type TestBuilderApi =
+        BuilderApi<MockAuctioneer, MockDatabaseService, MockSimulator, MockGossiper>;
+
+    fn build_test_api(auctioneer: Arc<MockAuctioneer>) -> Arc<TestBuilderApi> {
+        let (slot_update_sender, _slot_update_receiver) = mpsc::channel(32);
+        let (_gossip_sender, gossip_receiver) = mpsc::channel(32);
+        Arc::new(BuilderApi::new(
+            auctioneer,
+            Arc::new(MockDatabaseService::default()),
+            Arc::new(ChainInfo::for_mainnet()),
+            MockSimulator::default(),
+            Arc::new(MockGossiper::new().unwrap()),
+            Arc::new(RelaySigningContext::default()),
+            RelayConfig::default(),
+            slot_update_sender,
+            gossip_receiver,
+            Arc::new(ValidatorPreferences::default()),
+        ))
+    }
+
+    fn blocked_builder_info() -> BuilderInfo {
+        BuilderInfo { builder_id: Some(BLOCKED_BUILDER_ID.to_string()), ..Default::default() }
+    }
+
+    fn blocked_builder_auctioneer() -> Arc<MockAuctioneer> {
+        Arc::new(MockAuctioneer {
+            builder_info: Some(blocked_builder_info()),
+            ..Default::default()
+        })
+    }
+
+    fn blocked_builder_duty(slot: u64) -> BuilderGetValidatorsResponseEntry {
+        let mut duty = BuilderGetValidatorsResponseEntry { slot, ..Default::default() };
+        duty.entry.preferences.blocked_builders = Some(vec![BLOCKED_BUILDER_ID.to_string()]);
+        duty
+    }
+
+    async fn insert_payload_attributes(api: &TestBuilderApi, slot: u64, parent_hash: &Hash32) {
+        api.payload_attributes.write().await.insert(
+            get_payload_attributes_key(parent_hash, slot),
+            PayloadAttributesUpdate {
+                slot,
+                parent_hash: parent_hash.clone(),
+                ..Default::default()
+            },
+        );
+    }
+
+    fn read_test_data(filename: &str) -> Vec<u8> {
+        let mut path = std::env::current_dir().expect("Failed to get current directory");
+        if !path.ends_with("api") {
+            path.push("crates/api/");
+        }
+        path.push("test_data/");
+        path.push(filename);
+        std::fs::read(path).unwrap()
+    }
+
+    fn load_test_data<T: serde::de::DeserializeOwned>(filename: &str) -> T {
+        serde_json::from_slice(&read_test_data(filename)).unwrap()
+    }
+
+    fn assert_blocked(result: Result<StatusCode, BuilderApiError>) {
+        match result {
+            Err(BuilderApiError::BuilderBlockedByProposer { builder_id }) => {
+                assert_eq!(builder_id, BLOCKED_BUILDER_ID)
+            }
+            other => panic!("expected BuilderBlockedByProposer, got {other:?}"),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_submit_block_from_blocked_builder() {
+        let api = build_test_api(blocked_builder_auctioneer());
+        let payload: SignedBidSubmission = load_test_data("submitBlockPayloadCapella_Goerli.json");
+        insert_payload_attributes(&api, payload.slot(), payload.parent_hash()).await;
+
+        let next_duty = blocked_builder_duty(payload.slot());
+        let result = BuilderApi::handle_block_submission(
+            api,
+            payload.clone(),
+            false,
+            blocked_builder_info(),
+            SubmissionTrace::default(),
+            payload.slot() - 1,
+            Some(next_duty),
+            Uuid::new_v4(),
+        )
+        .await;
+
+        assert_blocked(result);
+    }
+
+    #[tokio::test]
+    async fn test_submit_header_from_blocked_builder() {
+        let api = build_test_api(blocked_builder_auctioneer());
+        let payload: SignedHeaderSubmission =
+            load_test_data("submitBlockPayloadHeaderCapella.json");
+        insert_payload_attributes(&api, payload.slot(), payload.parent_hash()).await;
+
+        let next_duty = blocked_builder_duty(payload.slot());
+        let result = BuilderApi::handle_header_submission(
+            api,
+            payload.clone(),
+            false,
+            blocked_builder_info(),
+            HeaderSubmissionTrace::default(),
+            payload.slot() - 1,
+            Some(next_duty),
+            Uuid::new_v4(),
+        )
+        .await;
+
+        assert_blocked(result);
+    }
+
+    #[tokio::test]
+    async fn test_submit_block_v2_from_blocked_builder() {
+        let api = build_test_api(blocked_builder_auctioneer());
+        let payload: SignedBidSubmission = load_test_data("submitBlockPayloadCapella_Goerli.json");
+        insert_payload_attributes(&api, payload.slot(), payload.parent_hash()).await;
+
+        let next_duty = blocked_builder_duty(payload.slot());
+        let result = BuilderApi::handle_optimistic_v2_submission(
+            api,
+            payload.clone(),
+            blocked_builder_info(),
+            SubmissionTrace::default(),
+            payload.slot() - 1,
+            Some(next_duty),
+            Uuid::new_v4(),
+        )
+        .await;
+
+        assert_blocked(result);
+    }
+
+    #[tokio::test]
+    async fn test_gossiped_header_from_blocked_builder_is_dropped() {
+        let slot = 33;
+        let gossiped_header = || BroadcastHeaderParams {
+            signed_builder_bid: SignedBuilderBid::Capella(capella::SignedBuilderBid {
+                message: capella::BuilderBid { value: U256::from(1), ..Default::default() },
+                ..Default::default()
+            }),
+            bid_trace: BidTrace::default(),
+            slot,
+            parent_hash: Hash32::default(),
+            proposer_pub_key: BlsPublicKey::default(),
+            builder_pub_key: BlsPublicKey::default(),
+            is_cancellations_enabled: false,
+            on_receive: 0,
+        };
+
+        let auctioneer = blocked_builder_auctioneer();
+        let api = build_test_api(auctioneer.clone());
+        *api.curr_slot_info.write().await = (slot - 1, Some(blocked_builder_duty(slot)));
+
+        api.process_gossiped_header(gossiped_header()).await;
+        assert!(auctioneer.best_bid.lock().unwrap().is_none());
+
+        // The same header is saved once the proposer no longer blocks the builder
+        *api.curr_slot_info.write().await =
+            (slot - 1, Some(BuilderGetValidatorsResponseEntry { slot, ..Default::default() }));
+        api.process_gossiped_header(gossiped_header()).await;
+        assert!(auctioneer.best_bid.lock().unwrap().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_gossiped_header_dropped_by_kill_switch() {
+        let slot = 33;
+        let gossiped_header = || BroadcastHeaderParams {
+            signed_builder_bid: SignedBuilderBid::Capella(capella::SignedBuilderBid {
+                message: capella::BuilderBid { value: U256::from(1), ..Default::default() },
+                ..Default::default()
+            }),
+            bid_trace: BidTrace::default(),
+            slot,
+            parent_hash: Hash32::default(),
+            proposer_pub_key: BlsPublicKey::default(),
+            builder_pub_key: BlsPublicKey::default(),
+            is_cancellations_enabled: false,
+            on_receive: 0,
+        };
+
+        let auctioneer = Arc::new(MockAuctioneer::default());
+        auctioneer.kill_switches.lock().unwrap().push(KillSwitch {
+            scope: KillSwitchScope::Proposer(BlsPublicKey::default()),
+            reason: "test".to_string(),
+            expires_at_ms: None,
+        });
+        let api = build_test_api(auctioneer.clone());
+        *api.curr_slot_info.write().await =
+            (slot - 1, Some(BuilderGetValidatorsResponseEntry { slot, ..Default::default() }));
+
+        api.process_gossiped_header(gossiped_header()).await;
+        assert!(auctioneer.best_bid.lock().unwrap().is_none());
+
+        auctioneer.kill_switches.lock().unwrap().clear();
+        api.process_gossiped_header(gossiped_header()).await;
+        assert!(auctioneer.best_bid.lock().unwrap().is_some());
+    }
+
+    /// Builder that requires the api key "key". Every bid is below the floor, so a submission
+    /// that reaches the floor check, with cancellations enabled, deletes the builder's bid.
+    fn api_key_auctioneer() -> Arc<MockAuctioneer> {
+        Arc::new(MockAuctioneer {
+            builder_info: Some(BuilderInfo {
+                api_key_hash: Some(hash_api_key("key")),
+                api_key_required: true,
+                ..Default::default()
+            }),
+            floor_bid_value: Some(U256::MAX),
+            ..Default::default()
+        })
+    }
+
+    #[tokio::test]
+    async fn test_submit_block_checks_api_key_before_floor() {
+        let body = read_test_data("submitBlockPayloadCapella_Goerli.json");
+        let payload: SignedBidSubmission = serde_json::from_slice(&body).unwrap();
+
+        for (api_key, is_valid) in [(None, false), (Some("other"), false), (Some("key"), true)] {
+            let auctioneer = api_key_auctioneer();
+            let api = build_test_api(auctioneer.clone());
+            insert_payload_attributes(&api, payload.slot(), payload.parent_hash()).await;
+            let next_duty =
+                BuilderGetValidatorsResponseEntry { slot: payload.slot(), ..Default::default() };
+            *api.curr_slot_info.write().await = (payload.slot() - 1, Some(next_duty));
+
+            let mut req = build_test_request(body.clone(), false, false).await;
+            if let Some(api_key) = api_key {
+                req.headers_mut().insert("x-api-key", HeaderValue::from_static(api_key));
+            }
+            let result = BuilderApi::submit_block(Extension(api), req).await;
+
+            if is_valid {
+                assert!(matches!(result, Err(BuilderApiError::BidBelowFloor)));
+            } else {
+                assert!(matches!(result, Err(BuilderApiError::InvalidApiKey)));
+            }
+            assert_eq!(auctioneer.builder_bid_deleted.load(Ordering::Relaxed), is_valid);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_check_bid_trace_prefix_checks_api_key_before_floor() {
+        let duty = BuilderGetValidatorsResponseEntry { slot: 33, ..Default::default() };
+        let bid_trace = BidTrace { slot: 33, value: U256::from(1), ..Default::default() };
+
+        let auctioneer = api_key_auctioneer();
+        let api = build_test_api(auctioneer.clone());
+        let result = api
+            .check_bid_trace_prefix(bid_trace, 32, Some(&duty), true, None, &Uuid::new_v4())
+            .await;
+
+        assert!(matches!(result, Err(BuilderApiError::InvalidApiKey)));
+        assert!(!auctioneer.builder_bid_deleted.load(Ordering::Relaxed));
+    }
+
+    async fn check_bid_trace_prefix(
+        bid_trace: BidTrace,
+        next_duty: Option<&BuilderGetValidatorsResponseEntry>,
+    ) -> Result<(), BuilderApiError> {
+        let api = build_test_api(Arc::new(MockAuctioneer::default()));
+        api.check_bid_trace_prefix(bid_trace, 32, next_duty, false, None, &Uuid::new_v4())
+            .await
+            .map(|_| ())
+    }
+
+    #[tokio::test]
+    async fn test_check_bid_trace_prefix() {
+        let duty = BuilderGetValidatorsResponseEntry { slot: 33, ..Default::default() };
+        let bid_trace = BidTrace { slot: 33, value: U256::from(1), ..Default::default() };
+        assert!(check_bid_trace_prefix(bid_trace, Some(&duty)).await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_check_bid_trace_prefix_past_slot() {
+        let duty = BuilderGetValidatorsResponseEntry { slot: 32, ..Default::default() };
+        let bid_trace = BidTrace { slot: 32, value: U256::from(1), ..Default::default() };
+        let result = check_bid_trace_prefix(bid_trace, Some(&duty)).await;
+        assert!(matches!(result, Err(BuilderApiError::SubmissionForPastSlot { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_check_bid_trace_prefix_duty_mismatch() {
+        let bid_trace = BidTrace { slot: 33, value: U256::from(1), ..Default::default() };
+        let result = check_bid_trace_prefix(bid_trace.clone(), None).await;
+        assert!(matches!(result, Err(BuilderApiError::ProposerDutyNotFound)));
+
+        let duty = BuilderGetValidatorsResponseEntry { slot: 34, ..Default::default() };
+        let result = check_bid_trace_prefix(bid_trace.clone(), Some(&duty)).await;
+        assert!(matches!(result, Err(BuilderApiError::SlotMismatch { got: 33, expected: 34 })));
+
+        let mut duty = BuilderGetValidatorsResponseEntry { slot: 33, ..Default::default() };
+        duty.entry.registration.message.public_key =
+            BlsPublicKey::try_from([1u8; 48].as_slice()).unwrap();
+        let result = check_bid_trace_prefix(bid_trace, Some(&duty)).await;
+        assert!(matches!(result, Err(BuilderApiError::ProposerPublicKeyMismatch { .. })));
+    }
+
+    #[tokio::test]
+    async fn test_check_bid_trace_prefix_below_floor() {
+        // Without a floor bid the floor is zero, and bids at the floor are rejected
+        let duty = BuilderGetValidatorsResponseEntry { slot: 33, ..Default::default() };
+        let bid_trace = BidTrace { slot: 33, value: U256::ZERO, ..Default::default() };
+        let result = check_bid_trace_prefix(bid_trace, Some(&duty)).await;
+        assert!(matches!(result, Err(BuilderApiError::BidBelowFloor)));
+    }
+}
@@ -136,8 +136,23 @@ pub enum BuilderApiError {
     #[error("builder not in proposer's trusted list: {proposer_trusted_builders:?}")]
     BuilderNotInProposersTrustedList { proposer_trusted_builders: Vec<String> },
 
+    #[error("builder {builder_id} is in proposer's blocked list")]
+    BuilderBlockedByProposer { builder_id: String },
+
     #[error("V2 submissions invalid if proposer requires regional filtering")]
     V2SubmissionsInvalidIfProposerRequiresRegionalFiltering,
+
+    #[error("kill switch enabled: {reason}")]
+    KillSwitchEnabled { reason: String },
+
+    #[error("invalid submission stream frame")]
+    InvalidSubmissionStreamFrame,
+
+    #[error("builder not registered for api key. pubkey: {pubkey:?}")]
+    BuilderNotRegisteredForApiKey { pubkey: BlsPublicKey },
+
+    #[error("submission quota exceeded. quota: {quota}, limit: {limit}")]
+    SubmissionQuotaExceeded { quota: &'static str, limit: u64 },
 }
 
 impl IntoResponse for BuilderApiError {
@@ -278,9 +293,24 @@ impl IntoResponse for BuilderApiError {
             BuilderApiError::BuilderNotInProposersTrustedList { proposer_trusted_builders } => {
                 (StatusCode::BAD_REQUEST, format!("builder not in proposer's trusted list: {proposer_trusted_builders:?}")).into_response()
             },
+            BuilderApiError::BuilderBlockedByProposer { builder_id } => {
+                (StatusCode::BAD_REQUEST, format!("builder {builder_id} is in proposer's blocked list")).into_response()
+            },
             BuilderApiError::V2SubmissionsInvalidIfProposerRequiresRegionalFiltering => {
                 (StatusCode::BAD_REQUEST, "V2 submissions invalid if proposer requires regional filtering").into_response()
             }
+            BuilderApiError::KillSwitchEnabled { reason } => {
+                (StatusCode::SERVICE_UNAVAILABLE, format!("kill switch enabled: {reason}")).into_response()
+            }
+            BuilderApiError::InvalidSubmissionStreamFrame => {
+                (StatusCode::BAD_REQUEST, "Invalid submission stream frame").into_response()
+            }
+            BuilderApiError::BuilderNotRegisteredForApiKey { pubkey } => {
+                (StatusCode::UNAUTHORIZED, format!("Builder not registered for api key. pubkey: {pubkey:?}")).into_response()
+            }
+            BuilderApiError::SubmissionQuotaExceeded { quota, limit } => {
+                (StatusCode::TOO_MANY_REQUESTS, format!("Submission quota exceeded. quota: {quota}, limit: {limit}")).into_response()
+            }
         }
     }
 }
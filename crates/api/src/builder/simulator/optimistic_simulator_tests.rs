@@ -102,6 +102,7 @@ mod simulator_tests {
             collateral: U256::from(100),
             is_optimistic: true,
             builder_id: None,
+            ..Default::default()
         };
         let simulator = get_optimistic_simulator(
             &server.url(),
@@ -136,6 +137,7 @@ mod simulator_tests {
             collateral: U256::from(100),
             is_optimistic: true,
             builder_id: None,
+            ..Default::default()
         };
         let simulator = get_optimistic_simulator(
             &server.url(),
@@ -170,6 +172,7 @@ mod simulator_tests {
             collateral: U256::from(100),
             is_optimistic: false,
             builder_id: None,
+            ..Default::default()
         };
         let simulator = get_optimistic_simulator(
             &server.url(),
@@ -204,6 +207,7 @@ mod simulator_tests {
             collateral: U256::from(100),
             is_optimistic: false,
             builder_id: None,
+            ..Default::default()
         };
         let simulator = get_optimistic_simulator(
             &server.url(),
@@ -5,6 +5,7 @@ mod tests {
     use crate::{
         builder::{
             api::{decode_header_submission, decode_payload, BuilderApi, MAX_PAYLOAD_LENGTH},
+            error::BuilderApiError,
             mock_simulator::MockSimulator,
         },
         gossiper::mock_gossiper::MockGossiper,
@@ -43,7 +44,10 @@ mod tests {
     use helix_database::MockDatabaseService;
     use helix_datastore::MockAuctioneer;
     use helix_housekeeper::{ChainUpdate, PayloadAttributesUpdate, SlotUpdate};
-    use helix_utils::{calculate_withdrawals_root, request_encoding::Encoding};
+    use helix_utils::{
+        calculate_withdrawals_root,
+        request_encoding::{ContentEncoding, Encoding, ZstdDictionary},
+    };
     use rand::Rng;
     use reqwest::{Client, Response};
     use reth_primitives::hex;
@@ -417,7 +421,9 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &mut header_submission_trace, &uuid, None)
+                .await
+                .unwrap();
 
         assert_eq!(decoded_submission.0.slot(), 5552306);
         assert!(matches!(
@@ -457,7 +463,9 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, false, true, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &mut header_submission_trace, &uuid, None)
+                .await
+                .unwrap();
 
         assert!(matches!(decoded_submission.0, SignedHeaderSubmission::Capella(_)));
         assert!(decoded_submission.0.commitments().is_none());
@@ -481,7 +489,9 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, false, true, &req_payload_bytes);
         let decoded_submission =
-            decode_header_submission(request, &mut header_submission_trace, &uuid).await.unwrap();
+            decode_header_submission(request, &mut header_submission_trace, &uuid, None)
+                .await
+                .unwrap();
 
         assert!(matches!(decoded_submission.0, SignedHeaderSubmission::Deneb(_)));
         assert!(decoded_submission.0.commitments().is_some());
@@ -505,7 +515,7 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let decoded_submission =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &mut submission_trace, &uuid, None).await.unwrap();
 
         assert_eq!(decoded_submission.0.message().slot, 5552306);
         assert!(matches!(decoded_submission.0.execution_payload(), ExecutionPayload::Capella(_)));
@@ -527,7 +537,7 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, true, false, &req_payload_bytes);
         let decoded_submission =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &mut submission_trace, &uuid, None).await.unwrap();
 
         assert_eq!(decoded_submission.0.message().slot, 5552306);
         assert!(matches!(decoded_submission.0.execution_payload(), ExecutionPayload::Capella(_)));
@@ -535,6 +545,96 @@ mod tests {
         assert!(decoded_submission.0.blobs_bundle().is_none());
     }
 
+    fn generate_compressed_request(
+        content_encoding: ContentEncoding,
+        dictionary: Option<&[u8]>,
+        payload: &[u8],
+    ) -> Request<axum::body::Body> {
+        let body = content_encoding.compress(payload, dictionary).unwrap();
+        Request::builder()
+            .method(Method::POST)
+            .uri("http://example.com")
+            .header(header::CONTENT_ENCODING, content_encoding.header_value())
+            .body(axum::body::Body::from(body))
+            .unwrap()
+    }
+
+    fn load_capella_payload() -> Vec<u8> {
+        let mut current_dir = std::env::current_dir().expect("Failed to get current directory");
+        if !current_dir.ends_with("api") {
+            current_dir.push("crates/api/");
+        }
+        current_dir.push("test_data/submitBlockPayloadCapella_Goerli.json");
+        load_bytes(current_dir.to_str().expect("Failed to convert path to string"))
+    }
+
+    #[tokio::test]
+    async fn test_signed_bid_submission_decoding_capella_zstd_and_lz4() {
+        let req_payload_bytes = load_capella_payload();
+
+        for content_encoding in [ContentEncoding::Zstd, ContentEncoding::Lz4] {
+            let mut submission_trace = SubmissionTrace::default();
+            let uuid = uuid::Uuid::new_v4();
+            let request = generate_compressed_request(content_encoding, None, &req_payload_bytes);
+            let (decoded_submission, _) =
+                decode_payload(request, &mut submission_trace, &uuid, None).await.unwrap();
+
+            assert_eq!(decoded_submission.message().slot, 5552306);
+            assert!(matches!(decoded_submission.execution_payload(), ExecutionPayload::Capella(_)));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_signed_bid_submission_decoding_capella_zstd_dictionary() {
+        let req_payload_bytes = load_capella_payload();
+        // Any content works as a raw zstd dictionary
+        let dictionary = req_payload_bytes.clone();
+        let zstd_dictionary = ZstdDictionary::new(&dictionary).unwrap();
+
+        let mut submission_trace = SubmissionTrace::default();
+        let uuid = uuid::Uuid::new_v4();
+        let request = generate_compressed_request(
+            ContentEncoding::Zstd,
+            Some(&dictionary),
+            &req_payload_bytes,
+        );
+        let (decoded_submission, _) =
+            decode_payload(request, &mut submission_trace, &uuid, Some(&zstd_dictionary))
+                .await
+                .unwrap();
+        assert_eq!(decoded_submission.message().slot, 5552306);
+
+        // Decoding without the dictionary fails
+        let request = generate_compressed_request(
+            ContentEncoding::Zstd,
+            Some(&dictionary),
+            &req_payload_bytes,
+        );
+        assert!(decode_payload(request, &mut submission_trace, &uuid, None).await.is_err());
+    }
+
+    #[test]
+    fn test_zstd_dictionary_rejects_invalid() {
+        assert!(ZstdDictionary::load("does/not/exist.dict").is_err());
+        assert!(ZstdDictionary::new(&[]).is_err());
+
+        // Starts with the magic number of a trained dictionary but has no valid entropy tables
+        let mut malformed = 0xEC30A437u32.to_le_bytes().to_vec();
+        malformed.extend([0xff; 64]);
+        assert!(ZstdDictionary::new(&malformed).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_decode_payload_decompressed_too_large() {
+        let payload = vec![0u8; MAX_PAYLOAD_LENGTH + 1];
+        let request = generate_compressed_request(ContentEncoding::Zstd, None, &payload);
+
+        let mut submission_trace = SubmissionTrace::default();
+        let uuid = uuid::Uuid::new_v4();
+        let result = decode_payload(request, &mut submission_trace, &uuid, None).await;
+        assert!(matches!(result, Err(BuilderApiError::PayloadTooLarge { .. })));
+    }
+
     #[tokio::test]
     async fn test_signed_bid_submission_decoding_deneb() {
         let mut current_dir = std::env::current_dir().expect("Failed to get current directory");
@@ -549,7 +649,7 @@ mod tests {
         let uuid = uuid::Uuid::new_v4();
         let request = generate_request(false, false, false, &req_payload_bytes);
         let (decoded_submission, _) =
-            decode_payload(request, &mut submission_trace, &uuid).await.unwrap();
+            decode_payload(request, &mut submission_trace, &uuid, None).await.unwrap();
 
         assert_eq!(decoded_submission.message().slot, 5552306);
         assert!(matches!(decoded_submission.execution_payload(), ExecutionPayload::Deneb(_)));
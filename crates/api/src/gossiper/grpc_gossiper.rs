@@ -10,8 +10,8 @@ use crate::{
         error::GossipError,
         traits::GossipClientTrait,
         types::{
-            BroadcastGetPayloadParams, BroadcastHeaderParams, BroadcastPayloadParams,
-            GossipedMessage,
+            BroadcastGetPayloadParams, BroadcastHeaderParams, BroadcastKillSwitchParams,
+            BroadcastPayloadParams, BroadcastValidatorRegistrationsParams, GossipedMessage,
         },
     },
     grpc::{
@@ -140,6 +140,46 @@ impl GrpcGossiperClient {
         }
         Ok(())
     }
+
+    pub async fn broadcast_validator_registrations(
+        &self,
+        request: grpc::BroadcastValidatorRegistrationsParams,
+    ) -> Result<(), GossipError> {
+        let request = Request::new(request);
+        let client = {
+            let client_guard = self.client.read().await;
+            client_guard.clone()
+        };
+
+        if let Some(mut client) = client {
+            if let Err(err) = client.broadcast_validator_registrations(request).await {
+                return Err(GossipError::BroadcastError(err));
+            }
+        } else {
+            return Err(GossipError::ClientNotConnected);
+        }
+        Ok(())
+    }
+
+    pub async fn broadcast_kill_switch(
+        &self,
+        request: grpc::BroadcastKillSwitchParams,
+    ) -> Result<(), GossipError> {
+        let request = Request::new(request);
+        let client = {
+            let client_guard = self.client.read().await;
+            client_guard.clone()
+        };
+
+        if let Some(mut client) = client {
+            if let Err(err) = client.broadcast_kill_switch(request).await {
+                return Err(GossipError::BroadcastError(err));
+            }
+        } else {
+            return Err(GossipError::ClientNotConnected);
+        }
+        Ok(())
+    }
 }
 
 /// `GrpcGossiperClientManager` manages multiple gRPC connections used for gossiping new bids
@@ -247,6 +287,42 @@ impl GossipClientTrait for GrpcGossiperClientManager {
         }
         Ok(())
     }
+
+    async fn broadcast_validator_registrations(
+        &self,
+        request: BroadcastValidatorRegistrationsParams,
+    ) -> Result<(), GossipError> {
+        let request = request.to_proto();
+
+        for client in self.clients.iter() {
+            let client = client.clone();
+            let request = request.clone();
+            tokio::spawn(async move {
+                if let Err(err) = client.broadcast_validator_registrations(request).await {
+                    error!(err = %err, "failed to broadcast validator registrations");
+                }
+            });
+        }
+        Ok(())
+    }
+
+    async fn broadcast_kill_switch(
+        &self,
+        request: BroadcastKillSwitchParams,
+    ) -> Result<(), GossipError> {
+        let request = request.to_proto();
+
+        for client in self.clients.iter() {
+            let client = client.clone();
+            let request = request.clone();
+            tokio::spawn(async move {
+                if let Err(err) = client.broadcast_kill_switch(request).await {
+                    error!(err = %err, "failed to broadcast kill switch");
+                }
+            });
+        }
+        Ok(())
+    }
 }
 
 /// `GrpcGossiperService` listens to incoming requests from the other geo-distributed instances
@@ -309,4 +385,32 @@ impl GossipService for GrpcGossiperService {
         }
         Ok(Response::new(()))
     }
+
+    async fn broadcast_validator_registrations(
+        &self,
+        request: Request<grpc::BroadcastValidatorRegistrationsParams>,
+    ) -> Result<Response<()>, Status> {
+        let request = BroadcastValidatorRegistrationsParams::from_proto(request.into_inner());
+        if let Err(err) = self
+            .proposer_api_sender
+            .send(GossipedMessage::ValidatorRegistrations(Box::new(request)))
+            .await
+        {
+            error!(err = %err, "failed to send validator registrations to proposer");
+        }
+        Ok(Response::new(()))
+    }
+
+    async fn broadcast_kill_switch(
+        &self,
+        request: Request<grpc::BroadcastKillSwitchParams>,
+    ) -> Result<Response<()>, Status> {
+        let request = BroadcastKillSwitchParams::from_proto(request.into_inner());
+        if let Err(err) =
+            self.proposer_api_sender.send(GossipedMessage::KillSwitch(Box::new(request))).await
+        {
+            error!(err = %err, "failed to send kill switch to proposer");
+        }
+        Ok(Response::new(()))
+    }
 }
@@ -5,7 +5,10 @@ use crate::{
     gossiper::{
         error::GossipError,
         traits::GossipClientTrait,
-        types::{BroadcastGetPayloadParams, BroadcastHeaderParams, BroadcastPayloadParams},
+        types::{
+            BroadcastGetPayloadParams, BroadcastHeaderParams, BroadcastKillSwitchParams,
+            BroadcastPayloadParams, BroadcastValidatorRegistrationsParams,
+        },
     },
     grpc::{self, gossip_service_server::GossipService},
 };
@@ -45,6 +48,18 @@ impl GossipClientTrait for MockGossiper {
     ) -> Result<(), GossipError> {
         Ok(())
     }
+    async fn broadcast_validator_registrations(
+        &self,
+        _request: BroadcastValidatorRegistrationsParams,
+    ) -> Result<(), GossipError> {
+        Ok(())
+    }
+    async fn broadcast_kill_switch(
+        &self,
+        _request: BroadcastKillSwitchParams,
+    ) -> Result<(), GossipError> {
+        Ok(())
+    }
 }
 
 pub struct MockGossiperService {}
@@ -78,4 +93,18 @@ impl GossipService for MockGossiperService {
     ) -> Result<Response<()>, Status> {
         Ok(tonic::Response::new(()))
     }
+
+    async fn broadcast_validator_registrations(
+        &self,
+        _request: Request<grpc::BroadcastValidatorRegistrationsParams>,
+    ) -> Result<Response<()>, Status> {
+        Ok(tonic::Response::new(()))
+    }
+
+    async fn broadcast_kill_switch(
+        &self,
+        _request: Request<grpc::BroadcastKillSwitchParams>,
+    ) -> Result<Response<()>, Status> {
+        Ok(tonic::Response::new(()))
+    }
 }
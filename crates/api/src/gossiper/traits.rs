@@ -4,7 +4,8 @@ use crate::gossiper::{
     error::GossipError,
     types::{
         broadcast_cancellation::BroadcastCancellationParams, BroadcastGetPayloadParams,
-        BroadcastHeaderParams, BroadcastPayloadParams,
+        BroadcastHeaderParams, BroadcastKillSwitchParams, BroadcastPayloadParams,
+        BroadcastValidatorRegistrationsParams,
     },
 };
 
@@ -33,4 +34,19 @@ pub trait GossipClientTrait: Send + Sync + Clone {
         &self,
         request: BroadcastCancellationParams,
     ) -> Result<(), GossipError>;
+
+    /// Broadcast a batch of validated registrations. The receiving relay re-validates the
+    /// signatures and saves them to its own database, so regions that do not share a database
+    /// still know about every registered validator.
+    async fn broadcast_validator_registrations(
+        &self,
+        request: BroadcastValidatorRegistrationsParams,
+    ) -> Result<(), GossipError>;
+
+    /// Broadcast a kill switch being enabled or disabled, so that every region enforces the same
+    /// set of switches.
+    async fn broadcast_kill_switch(
+        &self,
+        request: BroadcastKillSwitchParams,
+    ) -> Result<(), GossipError>;
 }
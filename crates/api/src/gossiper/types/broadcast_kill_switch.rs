@@ -0,0 +1,26 @@
+use helix_common::kill_switch::KillSwitchUpdate;
+use uuid::Uuid;
+
+use crate::grpc;
+
+#[derive(Clone, Debug)]
+pub struct BroadcastKillSwitchParams {
+    pub update: KillSwitchUpdate,
+    pub request_id: Uuid,
+}
+
+impl BroadcastKillSwitchParams {
+    pub fn from_proto(proto_params: grpc::BroadcastKillSwitchParams) -> Self {
+        Self {
+            update: serde_json::from_slice(&proto_params.update).unwrap(),
+            request_id: Uuid::from_slice(&proto_params.request_id).unwrap(),
+        }
+    }
+
+    pub fn to_proto(&self) -> grpc::BroadcastKillSwitchParams {
+        grpc::BroadcastKillSwitchParams {
+            update: serde_json::to_vec(&self.update).unwrap(),
+            request_id: self.request_id.as_bytes().to_vec(),
+        }
+    }
+}
@@ -0,0 +1,32 @@
+use helix_common::api::proposer_api::ValidatorRegistrationInfo;
+use uuid::Uuid;
+
+use crate::grpc;
+
+#[derive(Clone, Debug)]
+pub struct BroadcastValidatorRegistrationsParams {
+    pub registrations: Vec<ValidatorRegistrationInfo>,
+    pub pool_name: Option<String>,
+    pub request_id: Uuid,
+}
+
+impl BroadcastValidatorRegistrationsParams {
+    pub fn from_proto(proto_params: grpc::BroadcastValidatorRegistrationsParams) -> Self {
+        Self {
+            registrations: serde_json::from_slice(&proto_params.registrations).unwrap(),
+            pool_name: match proto_params.pool_name.is_empty() {
+                true => None,
+                false => Some(proto_params.pool_name),
+            },
+            request_id: Uuid::from_slice(&proto_params.request_id).unwrap(),
+        }
+    }
+
+    pub fn to_proto(&self) -> grpc::BroadcastValidatorRegistrationsParams {
+        grpc::BroadcastValidatorRegistrationsParams {
+            registrations: serde_json::to_vec(&self.registrations).unwrap(),
+            pool_name: self.pool_name.clone().unwrap_or_default(),
+            request_id: self.request_id.as_bytes().to_vec(),
+        }
+    }
+}
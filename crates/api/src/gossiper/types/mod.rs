@@ -1,12 +1,16 @@
 pub mod broadcast_cancellation;
 pub mod broadcast_get_payload;
 pub mod broadcast_header;
+pub mod broadcast_kill_switch;
 pub mod broadcast_payload;
+pub mod broadcast_validator_registrations;
 
 use broadcast_cancellation::BroadcastCancellationParams;
 pub use broadcast_get_payload::*;
 pub use broadcast_header::*;
+pub use broadcast_kill_switch::*;
 pub use broadcast_payload::*;
+pub use broadcast_validator_registrations::*;
 
 #[derive(Debug, Clone)]
 pub enum GossipedMessage {
@@ -14,4 +18,6 @@ pub enum GossipedMessage {
     Payload(Box<BroadcastPayloadParams>),
     GetPayload(Box<BroadcastGetPayloadParams>),
     Cancellation(Box<BroadcastCancellationParams>),
+    ValidatorRegistrations(Box<BroadcastValidatorRegistrationsParams>),
+    KillSwitch(Box<BroadcastKillSwitchParams>),
 }
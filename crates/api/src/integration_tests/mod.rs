@@ -1,2 +1,4 @@
 #[cfg(test)]
+mod registration_verification_bench;
+#[cfg(test)]
 mod replay_validator_registrations;
@@ -0,0 +1,77 @@
+//! Compares individual and batched signature verification of validator registrations.
+//!
+//! Run with `cargo test -p helix-api --release bench_registration_verification -- --ignored
+//! --nocapture`; results are logged at info level. Set `REGISTRATIONS_FIXTURE_PATH` to a file written by
+//! `dump_registrations_fixture` to use real registrations, otherwise random ones are generated.
+
+use std::time::{Duration, Instant};
+
+use ethereum_consensus::builder::SignedValidatorRegistration;
+use helix_common::chain_info::ChainInfo;
+use helix_utils::signing::{
+    batch_verify_signatures, compute_builder_signing_root, verify_signed_builder_message,
+};
+use tracing::info;
+
+use crate::proposer::{api::REGISTRATION_BATCH_SIZE, tests::gen_signed_vr};
+
+const NUM_REGISTRATIONS: usize = 10_000;
+
+fn load_registrations() -> Vec<SignedValidatorRegistration> {
+    match std::env::var("REGISTRATIONS_FIXTURE_PATH") {
+        Ok(path) => {
+            let bytes = std::fs::read(path).expect("failed to read registrations fixture");
+            serde_json::from_slice(&bytes).expect("failed to parse registrations fixture")
+        }
+        Err(_) => (0..NUM_REGISTRATIONS).map(|_| gen_signed_vr()).collect(),
+    }
+}
+
+fn registrations_per_sec(num_registrations: usize, elapsed: Duration) -> f64 {
+    num_registrations as f64 / elapsed.as_secs_f64()
+}
+
+#[test]
+#[ignore]
+fn bench_registration_verification() {
+    let _ = tracing_subscriber::fmt().with_max_level(tracing::Level::INFO).try_init();
+
+    let context = ChainInfo::for_mainnet().context;
+    let mut registrations = load_registrations();
+    let num_registrations = registrations.len();
+
+    let start = Instant::now();
+    for registration in registrations.iter_mut() {
+        let public_key = registration.message.public_key.clone();
+        verify_signed_builder_message(
+            &mut registration.message,
+            &registration.signature,
+            &public_key,
+            &context,
+        )
+        .expect("invalid registration signature");
+    }
+    let individual = start.elapsed();
+
+    let start = Instant::now();
+    for batch in registrations.chunks_mut(REGISTRATION_BATCH_SIZE) {
+        let signing_roots: Vec<_> = batch
+            .iter_mut()
+            .map(|r| compute_builder_signing_root(&mut r.message, &context).unwrap())
+            .collect();
+        let signatures: Vec<_> = batch.iter().map(|r| &r.signature).collect();
+        let public_keys: Vec<_> = batch.iter().map(|r| &r.message.public_key).collect();
+        assert!(batch_verify_signatures(&signing_roots, &signatures, &public_keys));
+    }
+    let batched = start.elapsed();
+
+    info!(
+        num_registrations,
+        individual_ms = individual.as_millis(),
+        individual_per_sec = registrations_per_sec(num_registrations, individual),
+        batched_ms = batched.as_millis(),
+        batched_per_sec = registrations_per_sec(num_registrations, batched),
+        speedup = individual.as_secs_f64() / batched.as_secs_f64(),
+        "registration verification benchmark",
+    );
+}
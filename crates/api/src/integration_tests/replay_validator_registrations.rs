@@ -18,6 +18,14 @@ use helix_common::{api::{
 }, BeaconClientConfig};
 use url::Url;
 
+/// Public mainnet relays serving the builder `getValidators` endpoint.
+const RELAY_VALIDATORS_ENDPOINTS: [&str; 4] = [
+    "https://boost-relay.flashbots.net/relay/v1/builder/validators",
+    "https://relay.ultrasound.money/relay/v1/builder/validators",
+    "https://agnostic-relay.net/relay/v1/builder/validators",
+    "https://aestus.live/relay/v1/builder/validators",
+];
+
 #[derive(Serialize, Deserialize, Debug, Clone, Default)]
 pub struct BuilderGetValidatorsResponseEntryExternal {
     #[serde(with = "as_str")]
@@ -112,6 +120,27 @@ async fn get_status(endpoint: &str) -> Result<Response, Error> {
     Ok(resp)
 }
 
+/// Writes up to 10k registrations currently served by `RELAY_VALIDATORS_ENDPOINTS` to
+/// `REGISTRATIONS_FIXTURE_PATH`, as a fixture for `bench_registration_verification`.
+#[tokio::test]
+#[ignore]
+async fn dump_registrations_fixture() {
+    let path =
+        std::env::var("REGISTRATIONS_FIXTURE_PATH").expect("REGISTRATIONS_FIXTURE_PATH not set");
+
+    let registrations: Vec<SignedValidatorRegistration> =
+        fetch_and_aggregate_validators(&RELAY_VALIDATORS_ENDPOINTS)
+            .await
+            .expect("failed to fetch validators")
+            .into_iter()
+            .map(|v| v.registration)
+            .take(10_000)
+            .collect();
+    info!(path = %path, "writing {} registrations", registrations.len());
+
+    std::fs::write(path, serde_json::to_vec(&registrations).unwrap()).unwrap();
+}
+
 #[tokio::test]
 #[ignore]
 async fn run() {
@@ -1,5 +1,6 @@
 #![allow(clippy::too_many_arguments)]
 
+pub mod admin;
 pub mod builder;
 pub mod gossiper;
 pub mod integration_tests;
@@ -5,9 +5,12 @@ use std::{
 
 use axum::{
     body::{to_bytes, Body},
-    extract::{Json, Path},
-    http::{HeaderMap, Request, StatusCode},
-    response::IntoResponse,
+    extract::Path,
+    http::{
+        header::{ACCEPT, CONTENT_TYPE},
+        HeaderMap, HeaderValue, Request, StatusCode,
+    },
+    response::{IntoResponse, Response},
     Extension,
 };
 use ethereum_consensus::{
@@ -23,6 +26,7 @@ use ethereum_consensus::{
         ExecutionPayloadHeader, ExecutionPayloadHeaderRef, SignedBeaconBlock,
         SignedBlindedBeaconBlock,
     },
+    Fork,
 };
 
 use tokio::{
@@ -45,22 +49,29 @@ use helix_common::{
     deneb::{
         BlobSidecars, BuildBlobSidecarError,
     },
+    kill_switch::{KillSwitchScope, KillSwitchTarget, KillSwitchUpdate},
     signed_proposal::VersionedSignedProposal,
     try_execution_header_from_payload,
     versioned_payload::PayloadAndBlobs,
-    BidRequest, Filtering, GetHeaderTrace, GetPayloadTrace, RegisterValidatorsTrace,
+    BidRequest, Filtering, GetHeaderTrace, GetPayloadTrace, RegisterValidatorsTrace, Route,
     ValidatorPreferences,
 };
 use helix_common::beacon_api::PublishBlobsRequest;
 use helix_database::DatabaseService;
 use helix_datastore::{error::AuctioneerError, Auctioneer};
 use helix_housekeeper::{ChainUpdate, SlotUpdate};
-use helix_utils::signing::{verify_signed_builder_message, verify_signed_consensus_message};
+use helix_utils::signing::{
+    batch_verify_signatures, compute_builder_signing_root, verify_signed_builder_message,
+    verify_signed_consensus_message,
+};
 
 use crate::{
     gossiper::{
         traits::GossipClientTrait,
-        types::{BroadcastGetPayloadParams, GossipedMessage},
+        types::{
+            BroadcastGetPayloadParams, BroadcastKillSwitchParams,
+            BroadcastValidatorRegistrationsParams, GossipedMessage,
+        },
     },
     proposer::{
         error::ProposerApiError, unblind_beacon_block, GetHeaderParams, PreferencesHeader,
@@ -71,6 +82,14 @@ use crate::{
 const GET_PAYLOAD_REQUEST_CUTOFF_MS: i64 = 4000;
 pub(crate) const MAX_BLINDED_BLOCK_LENGTH: usize = 1024 * 1024;
 pub(crate) const MAX_VAL_REGISTRATIONS_LENGTH: usize = 425 * 10_000; // 425 bytes per registration (json) * 10,000 registrations
+/// Registrations verified per signature batch. A bad signature only costs its own batch a
+/// fallback to individual verification.
+pub(crate) const REGISTRATION_BATCH_SIZE: usize = 512;
+
+pub(crate) const CONSENSUS_VERSION_HEADER: &str = "eth-consensus-version";
+pub(crate) const SSZ_CONTENT_TYPE: &str = "application/octet-stream";
+/// Size of an SSZ encoded `SignedValidatorRegistration`, a list of them is a plain concatenation.
+const SIGNED_VALIDATOR_REGISTRATION_SSZ_LENGTH: usize = 180;
 
 #[derive(Clone)]
 pub struct ProposerApi<A, DB, M, G>
@@ -168,8 +187,9 @@ where
     pub async fn register_validators(
         Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
         headers: HeaderMap,
-        Json(registrations): Json<Vec<SignedValidatorRegistration>>,
+        req: Request<Body>,
     ) -> Result<StatusCode, ProposerApiError> {
+        let registrations = deserialize_registrations(req).await?;
         if registrations.is_empty() {
             return Err(ProposerApiError::EmptyRequest);
         }
@@ -188,12 +208,18 @@ where
             None => None,
         };
 
+        let mut kill_switch_target = KillSwitchTarget::new(Route::RegisterValidators);
+        kill_switch_target.proposer_pool = pool_name.as_deref();
+        proposer_api.check_kill_switch(kill_switch_target).await?;
+
         // Set using default preferences from config
         let mut validator_preferences = ValidatorPreferences {
             filtering: proposer_api.validator_preferences.filtering,
             trusted_builders: proposer_api.validator_preferences.trusted_builders.clone(),
+            blocked_builders: proposer_api.validator_preferences.blocked_builders.clone(),
             header_delay: proposer_api.validator_preferences.header_delay,
             gossip_blobs: proposer_api.validator_preferences.gossip_blobs,
+            min_bid_value: proposer_api.validator_preferences.min_bid_value,
         };
 
         let preferences_header = headers.get("x-preferences");
@@ -222,6 +248,10 @@ where
                 validator_preferences.trusted_builders = Some(trusted_builders);
             }
 
+            if let Some(blocked_builders) = preferences.blocked_builders {
+                validator_preferences.blocked_builders = Some(blocked_builders);
+            }
+
             if let Some(header_delay) = preferences.header_delay {
                 validator_preferences.header_delay = header_delay;
             }
@@ -229,6 +259,10 @@ where
             if let Some(gossip_blobs) = preferences.gossip_blobs {
                 validator_preferences.gossip_blobs = gossip_blobs;
             }
+
+            if let Some(min_bid_value) = preferences.min_bid_value {
+                validator_preferences.min_bid_value = Some(min_bid_value);
+            }
         }
 
         let request_id = Uuid::new_v4();
@@ -252,12 +286,9 @@ where
         // Check each registration
         let mut valid_registrations = Vec::with_capacity(known_pub_keys.len());
 
-        let mut handles = Vec::with_capacity(registrations.len());
-
-        for mut registration in registrations {
-            let proposer_api_clone = proposer_api.clone();
-            let start_time = Instant::now();
+        let mut registrations_to_verify = Vec::with_capacity(known_pub_keys.len());
 
+        for registration in registrations {
             let pub_key = registration.message.public_key.clone();
 
             debug!(
@@ -277,46 +308,49 @@ where
                 continue;
             }
 
-            if !proposer_api_clone.db.is_registration_update_required(&registration).await? {
-                debug!(
+            let mut kill_switch_target = KillSwitchTarget::new(Route::RegisterValidators);
+            kill_switch_target.proposer_pubkey = Some(&pub_key);
+            kill_switch_target.proposer_pool = pool_name.as_deref();
+            if let Some(kill_switch) =
+                proposer_api.auctioneer.find_kill_switch(kill_switch_target).await?
+            {
+                warn!(
                     request_id = %request_id,
                     pub_key = ?pub_key,
-                    "Registration update not required",
+                    reason = %kill_switch.reason,
+                    "Registration rejected by kill switch",
                 );
-                valid_registrations.push(registration);
                 continue;
             }
 
-            let handle = tokio::task::spawn_blocking(move || {
-                let res = match proposer_api_clone.validate_registration(&mut registration) {
-                    Ok(_) => Some(registration),
-                    Err(err) => {
-                        warn!(
-                            request_id = %request_id,
-                            err = %err,
-                            pub_key = ?pub_key,
-                            "Failed to register validator",
-                        );
-                        None
-                    }
-                };
-
-                trace!(
+            if !proposer_api.db.is_registration_update_required(&registration).await? {
+                debug!(
                     request_id = %request_id,
                     pub_key = ?pub_key,
-                    elapsed_time = %start_time.elapsed().as_nanos(),
+                    "Registration update not required",
                 );
+                valid_registrations.push(registration);
+                continue;
+            }
 
-                res
-            });
-            handles.push(handle);
+            registrations_to_verify.push(registration);
+        }
+
+        // Verify signatures in batches, spread over the blocking pool
+        let mut handles = Vec::new();
+        let mut registrations_to_verify = registrations_to_verify.into_iter().peekable();
+        while registrations_to_verify.peek().is_some() {
+            let batch: Vec<_> =
+                registrations_to_verify.by_ref().take(REGISTRATION_BATCH_SIZE).collect();
+            let proposer_api_clone = proposer_api.clone();
+            handles.push(tokio::task::spawn_blocking(move || {
+                proposer_api_clone.validate_registrations(batch, request_id)
+            }));
         }
 
         for handle in handles {
-            let reg = handle.await.map_err(|_| ProposerApiError::InternalServerError)?;
-            if let Some(reg) = reg {
-                valid_registrations.push(reg);
-            }
+            let regs = handle.await.map_err(|_| ProposerApiError::InternalServerError)?;
+            valid_registrations.extend(regs);
         }
         trace.registrations_complete = get_nanos_timestamp()?;
 
@@ -336,6 +370,21 @@ where
                 .push(ValidatorRegistrationInfo { registration: reg, preferences });
         }
 
+        // Gossip registrations to other regions
+        if !valid_registrations_infos.is_empty() {
+            if let Err(err) = proposer_api
+                .gossiper
+                .broadcast_validator_registrations(BroadcastValidatorRegistrationsParams {
+                    registrations: valid_registrations_infos.clone(),
+                    pool_name: pool_name.clone(),
+                    request_id,
+                })
+                .await
+            {
+                error!(request_id = %request_id, error = %err, "failed to broadcast validator registrations");
+            }
+        }
+
         // Bulk write registrations to db
         tokio::spawn(async move {
             if let Err(err) = proposer_api
@@ -376,9 +425,9 @@ where
         headers: HeaderMap,
         Path(GetHeaderParams { slot, parent_hash, public_key }): Path<GetHeaderParams>,
     ) -> Result<impl IntoResponse, ProposerApiError> {
-        if proposer_api.auctioneer.kill_switch_enabled().await? {
-            return Err(ProposerApiError::ServiceUnavailableError);
-        }
+        let mut kill_switch_target = KillSwitchTarget::new(Route::GetHeader);
+        kill_switch_target.proposer_pubkey = Some(&public_key);
+        proposer_api.check_kill_switch(kill_switch_target).await?;
 
         let request_id = Uuid::new_v4();
         let mut trace = GetHeaderTrace { receive: get_nanos_timestamp()?, ..Default::default() };
@@ -410,7 +459,7 @@ where
             debug!(%request_id, "proposer duty not found");
             return Err(ProposerApiError::ProposerNotRegistered);
         }
-        let _duty = duty.unwrap();
+        let duty = duty.unwrap();
 
         let _ms_into_slot = match proposer_api.validate_bid_request_time(&bid_request) {
             Ok(ms_into_slot) => ms_into_slot,
@@ -439,6 +488,21 @@ where
                     return Err(ProposerApiError::BidValueZero);
                 }
 
+                if let Some(min_bid_value) = duty.entry.preferences.min_bid_value {
+                    if bid.value() < min_bid_value {
+                        warn!(
+                            request_id = %request_id,
+                            value = ?bid.value(),
+                            min_bid_value = ?min_bid_value,
+                            "best bid value below proposer minimum",
+                        );
+                        return Err(ProposerApiError::BidBelowMinimum {
+                            value: bid.value(),
+                            min_bid_value,
+                        });
+                    }
+                }
+
                 info!(
                     request_id = %request_id,
                     value = ?bid.value(),
@@ -460,7 +524,13 @@ where
                     .await;
 
                 // Return header
-                Ok(axum::Json(bid))
+                let mut response = if accepts_ssz(&headers) {
+                    ([(CONTENT_TYPE, SSZ_CONTENT_TYPE)], bid.ssz_bytes()?).into_response()
+                } else {
+                    axum::Json(&bid).into_response()
+                };
+                set_consensus_version(&mut response, bid.version());
+                Ok(response)
             }
             Ok(None) => {
                 warn!(request_id = %request_id, "no bid found");
@@ -486,12 +556,14 @@ where
     /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/submitBlindedBlock>
     pub async fn get_payload(
         Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
-        _headers: HeaderMap,
+        headers: HeaderMap,
         req: Request<Body>,
     ) -> Result<impl IntoResponse, ProposerApiError> {
         let mut trace = GetPayloadTrace { receive: get_nanos_timestamp()?, ..Default::default() };
         let request_id = Uuid::new_v4();
 
+        proposer_api.check_route_kill_switch(Route::GetPayload).await?;
+
         let signed_blinded_block: SignedBlindedBeaconBlock =
             match deserialize_get_payload_bytes(req).await {
                 Ok(signed_block) => signed_block,
@@ -526,7 +598,73 @@ where
             ._get_payload(signed_blinded_block, &mut trace, &request_id)
             .await
         {
-            Ok(get_payload_response) => Ok(axum::Json(get_payload_response)),
+            Ok(get_payload_response) => {
+                encode_get_payload_response(&headers, &get_payload_response)
+            }
+            Err(err) => {
+                // Save error to DB
+                if let Err(err) = proposer_api
+                    .db
+                    .save_failed_get_payload(slot, block_hash, err.to_string(), trace)
+                    .await
+                {
+                    error!(err = ?err, "error saving failed get payload");
+                }
+
+                Err(err)
+            }
+        }
+    }
+
+    /// Like `get_payload`, but the relay only publishes the block and the proposer gets a 202
+    /// without the unblinded payload.
+    ///
+    /// Implements this API: <https://ethereum.github.io/builder-specs/#/Builder/submitBlindedBlockV2>
+    pub async fn get_payload_v2(
+        Extension(proposer_api): Extension<Arc<ProposerApi<A, DB, M, G>>>,
+        req: Request<Body>,
+    ) -> Result<StatusCode, ProposerApiError> {
+        let mut trace =
+            GetPayloadTrace { receive: get_nanos_timestamp()?, v2: true, ..Default::default() };
+        let request_id = Uuid::new_v4();
+
+        proposer_api.check_route_kill_switch(Route::GetPayloadV2).await?;
+
+        let signed_blinded_block: SignedBlindedBeaconBlock =
+            match deserialize_get_payload_bytes(req).await {
+                Ok(signed_block) => signed_block,
+                Err(err) => {
+                    warn!(
+                        request_id = %request_id,
+                        event = "get_payload_v2",
+                        error = %err,
+                        "failed to deserialize signed block",
+                    );
+                    return Err(err);
+                }
+            };
+        let block_hash =
+            signed_blinded_block.message().body().execution_payload_header().block_hash().clone();
+
+        let slot = signed_blinded_block.message().slot();
+
+        // Broadcast get payload request
+        if let Err(e) = proposer_api
+            .gossiper
+            .broadcast_get_payload(BroadcastGetPayloadParams {
+                signed_blinded_beacon_block: signed_blinded_block.clone(),
+                request_id,
+            })
+            .await
+        {
+            error!(request_id = %request_id, error = %e, "failed to broadcast get payload");
+        };
+
+        match proposer_api.publish_payload(signed_blinded_block, &mut trace, &request_id).await {
+            Ok(_) => {
+                info!(request_id = %request_id, trace = ?trace, "block published");
+                Ok(StatusCode::ACCEPTED)
+            }
             Err(err) => {
                 // Save error to DB
                 if let Err(err) = proposer_api
@@ -544,10 +682,37 @@ where
 
     pub async fn _get_payload(
         &self,
-        mut signed_blinded_block: SignedBlindedBeaconBlock,
+        signed_blinded_block: SignedBlindedBeaconBlock,
         trace: &mut GetPayloadTrace,
         request_id: &Uuid,
     ) -> Result<GetPayloadResponse, ProposerApiError> {
+        let payload = self.publish_payload(signed_blinded_block, trace, request_id).await?;
+
+        let get_payload_response = match GetPayloadResponse::try_from_execution_payload(&payload) {
+            Some(get_payload_response) => get_payload_response,
+            None => {
+                error!(
+                    request_id = %request_id,
+                    "payload type mismatch getting payload response from execution payload.
+                    All previous validation steps have passed, this should not happen",
+                );
+                return Err(ProposerApiError::PayloadTypeMismatch);
+            }
+        };
+
+        // Return response
+        info!(request_id = %request_id, trace = ?trace, timestamp = get_nanos_timestamp()?, "delivering payload");
+        Ok(get_payload_response)
+    }
+
+    /// Validates the signed blinded block, unblinds it and publishes the block. Unless the
+    /// request is a v2 one, waits for the block to propagate before returning the payload.
+    async fn publish_payload(
+        &self,
+        mut signed_blinded_block: SignedBlindedBeaconBlock,
+        trace: &mut GetPayloadTrace,
+        request_id: &Uuid,
+    ) -> Result<Arc<PayloadAndBlobs>, ProposerApiError> {
         let block_hash =
             signed_blinded_block.message().body().execution_payload_header().block_hash().clone();
 
@@ -721,9 +886,11 @@ where
 
         let is_trusted_proposer = self.is_trusted_proposer(&proposer_public_key).await?;
 
-        // Publish and validate payload with multi-beacon-client
+        // Publish and validate payload with multi-beacon-client. Trusted proposers get their
+        // payload without waiting for the publish, unless they use v2 and only get the publish
+        // result back.
         let fork = unblinded_payload.version();
-        if is_trusted_proposer {
+        if is_trusted_proposer && !trace.v2 {
             let self_clone = self.clone();
             let unblinded_payload_clone = unblinded_payload.clone();
             let request_id_clone = *request_id;
@@ -803,32 +970,18 @@ where
             // Calculate the remaining time needed to reach the target propagation duration.
             // Conditionally pause the execution until we hit
             // `TARGET_GET_PAYLOAD_PROPAGATION_DURATION_MS` to allow the block to
-            // propagate through the network.
+            // propagate through the network. v2 proposers don't wait for the payload.
             let elapsed_since_propagate_start_ms =
                 (get_nanos_timestamp()?.saturating_sub(trace.beacon_client_broadcast)) / 1_000_000;
             let remaining_sleep_ms = self
                 .target_get_payload_propagation_duration_ms
                 .saturating_sub(elapsed_since_propagate_start_ms);
-            if remaining_sleep_ms > 0 {
+            if remaining_sleep_ms > 0 && !trace.v2 {
                 sleep(Duration::from_millis(remaining_sleep_ms)).await;
             }
         }
 
-        let get_payload_response = match GetPayloadResponse::try_from_execution_payload(&payload) {
-            Some(get_payload_response) => get_payload_response,
-            None => {
-                error!(
-                    request_id = %request_id,
-                    "payload type mismatch getting payload response from execution payload.
-                    All previous validation steps have passed, this should not happen",
-                );
-                return Err(ProposerApiError::PayloadTypeMismatch);
-            }
-        };
-
-        // Return response
-        info!(request_id = %request_id, trace = ?trace, timestamp = get_nanos_timestamp()?, "delivering payload");
-        Ok(get_payload_response)
+        Ok(payload)
     }
 }
 
@@ -840,6 +993,31 @@ where
     M: MultiBeaconClientTrait + 'static,
     G: GossipClientTrait + 'static,
 {
+    /// Fails with `KillSwitchEnabled` if a kill switch applies to `target`.
+    async fn check_kill_switch(
+        &self,
+        target: KillSwitchTarget<'_>,
+    ) -> Result<(), ProposerApiError> {
+        match self.auctioneer.find_kill_switch(target).await? {
+            Some(kill_switch) => {
+                Err(ProposerApiError::KillSwitchEnabled { reason: kill_switch.reason })
+            }
+            None => Ok(()),
+        }
+    }
+
+    /// Like `check_kill_switch`, but only switches on the route itself apply. Used for
+    /// get_payload, where the proposer has already signed one of our headers and would miss its
+    /// slot if a relay-wide switch turned it away.
+    async fn check_route_kill_switch(&self, route: Route) -> Result<(), ProposerApiError> {
+        match self.auctioneer.find_kill_switch(KillSwitchTarget::new(route)).await? {
+            Some(kill_switch) if matches!(kill_switch.scope, KillSwitchScope::Route(_)) => {
+                Err(ProposerApiError::KillSwitchEnabled { reason: kill_switch.reason })
+            }
+            _ => Ok(()),
+        }
+    }
+
     /// Validate a single registration.
     pub fn validate_registration(
         &self,
@@ -863,6 +1041,87 @@ where
         Ok(())
     }
 
+    /// Validates a batch of registrations and returns the valid ones. Signatures are verified
+    /// together, and one by one only if the batch fails.
+    pub fn validate_registrations(
+        &self,
+        registrations: Vec<SignedValidatorRegistration>,
+        request_id: Uuid,
+    ) -> Vec<SignedValidatorRegistration> {
+        let start_time = Instant::now();
+        let num_registrations = registrations.len();
+
+        let mut timely_registrations = Vec::with_capacity(registrations.len());
+        let mut signing_roots = Vec::with_capacity(registrations.len());
+        for mut registration in registrations {
+            if let Err(err) = self.validate_registration_time(&registration) {
+                warn!(
+                    request_id = %request_id,
+                    err = %err,
+                    pub_key = ?registration.message.public_key,
+                    "Failed to register validator",
+                );
+                continue;
+            }
+
+            match compute_builder_signing_root(&mut registration.message, &self.chain_info.context)
+            {
+                Ok(signing_root) => signing_roots.push(signing_root),
+                Err(err) => {
+                    warn!(
+                        request_id = %request_id,
+                        err = %err,
+                        pub_key = ?registration.message.public_key,
+                        "Failed to compute registration signing root",
+                    );
+                    continue;
+                }
+            }
+            timely_registrations.push(registration);
+        }
+
+        let signatures: Vec<_> = timely_registrations.iter().map(|r| &r.signature).collect();
+        let public_keys: Vec<_> =
+            timely_registrations.iter().map(|r| &r.message.public_key).collect();
+        let batch_valid = batch_verify_signatures(&signing_roots, &signatures, &public_keys);
+
+        let valid_registrations = if batch_valid {
+            timely_registrations
+        } else {
+            debug!(
+                request_id = %request_id,
+                num_registrations = timely_registrations.len(),
+                "Batch signature verification failed, verifying registrations individually",
+            );
+            timely_registrations
+                .into_iter()
+                .filter_map(|mut registration| {
+                    match self.validate_registration(&mut registration) {
+                        Ok(_) => Some(registration),
+                        Err(err) => {
+                            warn!(
+                                request_id = %request_id,
+                                err = %err,
+                                pub_key = ?registration.message.public_key,
+                                "Failed to register validator",
+                            );
+                            None
+                        }
+                    }
+                })
+                .collect()
+        };
+
+        trace!(
+            request_id = %request_id,
+            num_registrations = num_registrations,
+            batch_valid = batch_valid,
+            elapsed_time = %start_time.elapsed().as_nanos(),
+        );
+
+        valid_registrations
+    }
+
     /// Validates the timestamp in a `SignedValidatorRegistration` message.
     ///
     /// - Ensures the timestamp is not too early (before genesis time)
@@ -1096,34 +1355,150 @@ where
         }
     }
 
+    /// Processes gossiped validator registrations from another region.
+    ///
+    /// Signatures are re-validated before the registrations, along with their preferences and
+    /// pool name, are saved to the local database.
+    pub async fn process_gossiped_validator_registrations(
+        &self,
+        req: BroadcastValidatorRegistrationsParams,
+    ) {
+        let request_id = req.request_id;
+        let num_registrations = req.registrations.len();
+        info!(
+            request_id = %request_id,
+            num_registrations = num_registrations,
+            "received gossiped validator registrations",
+        );
+
+        let mut handles = Vec::with_capacity(num_registrations);
+        for mut registration_info in req.registrations {
+            match self.db.is_registration_update_required(&registration_info.registration).await {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(err) => {
+                    error!(request_id = %request_id, error = %err, "failed to check if registration update is required");
+                    continue;
+                }
+            }
+
+            let api_clone = self.clone();
+            let handle = tokio::task::spawn_blocking(move || {
+                match api_clone.validate_registration(&mut registration_info.registration) {
+                    Ok(_) => Some(registration_info),
+                    Err(err) => {
+                        warn!(
+                            request_id = %request_id,
+                            err = %err,
+                            pub_key = ?registration_info.registration.message.public_key,
+                            "invalid gossiped validator registration",
+                        );
+                        None
+                    }
+                }
+            });
+            handles.push(handle);
+        }
+
+        let mut valid_registrations_infos = Vec::with_capacity(handles.len());
+        for handle in handles {
+            match handle.await {
+                Ok(Some(registration_info)) => valid_registrations_infos.push(registration_info),
+                Ok(None) => {}
+                Err(err) => {
+                    error!(request_id = %request_id, error = %err, "failed to join registration validation task");
+                }
+            }
+        }
+
+        if valid_registrations_infos.is_empty() {
+            return;
+        }
+
+        let num_valid_registrations = valid_registrations_infos.len();
+        if let Err(err) =
+            self.db.save_validator_registrations(valid_registrations_infos, req.pool_name).await
+        {
+            error!(
+                request_id = %request_id,
+                err = %err,
+                "failed to save gossiped validator registrations",
+            );
+            return;
+        }
+
+        info!(
+            request_id = %request_id,
+            successful_registrations = num_valid_registrations,
+            failed_registrations = num_registrations - num_valid_registrations,
+            "gossiped validator registrations processed",
+        );
+    }
+
+    /// Applies a kill switch update gossiped from another region and records it for audit.
+    pub async fn process_gossiped_kill_switch(&self, req: BroadcastKillSwitchParams) {
+        let request_id = req.request_id;
+        info!(request_id = %request_id, update = ?req.update, "received gossiped kill switch");
+
+        let res = match &req.update {
+            KillSwitchUpdate::Enable(kill_switch) => {
+                self.auctioneer.enable_kill_switch(kill_switch).await
+            }
+            KillSwitchUpdate::Disable { scope } => self.auctioneer.disable_kill_switch(scope).await,
+        };
+        if let Err(err) = res {
+            error!(request_id = %request_id, err = %err, "failed to apply gossiped kill switch");
+            return;
+        }
+
+        if let Err(err) = self.db.save_kill_switch_update(&req.update).await {
+            error!(request_id = %request_id, err = %err, "failed to save gossiped kill switch");
+        }
+    }
+
     /// This function should be run as a seperate async task.
     /// Will process new gossiped messages from
     async fn process_gossiped_info(&self, mut recveiver: Receiver<GossipedMessage>) {
         while let Some(msg) = recveiver.recv().await {
-            if let GossipedMessage::GetPayload(payload) = msg {
-                let api_clone = self.clone();
-                tokio::spawn(async move {
-                    let mut trace = GetPayloadTrace {
-                        receive: get_nanos_timestamp().unwrap_or_default(),
-                        ..Default::default()
-                    };
-                    info!(request_id = %payload.request_id, "processing gossiped payload");
-                    match api_clone
-                        ._get_payload(
-                            payload.signed_blinded_beacon_block,
-                            &mut trace,
-                            &payload.request_id,
-                        )
-                        .await
-                    {
-                        Ok(_get_payload_response) => {
-                            info!(request_id = %payload.request_id, "gossiped payload processed");
+            match msg {
+                GossipedMessage::GetPayload(payload) => {
+                    let api_clone = self.clone();
+                    tokio::spawn(async move {
+                        let mut trace = GetPayloadTrace {
+                            receive: get_nanos_timestamp().unwrap_or_default(),
+                            ..Default::default()
+                        };
+                        info!(request_id = %payload.request_id, "processing gossiped payload");
+                        match api_clone
+                            ._get_payload(
+                                payload.signed_blinded_beacon_block,
+                                &mut trace,
+                                &payload.request_id,
+                            )
+                            .await
+                        {
+                            Ok(_get_payload_response) => {
+                                info!(request_id = %payload.request_id, "gossiped payload processed");
+                            }
+                            Err(err) => {
+                                error!(request_id = %payload.request_id, error = %err, "error processing gossiped payload");
+                            }
                         }
-                        Err(err) => {
-                            error!(request_id = %payload.request_id, error = %err, "error processing gossiped payload");
-                        }
-                    }
-                });
+                    });
+                }
+                GossipedMessage::ValidatorRegistrations(registrations) => {
+                    let api_clone = self.clone();
+                    tokio::spawn(async move {
+                        api_clone.process_gossiped_validator_registrations(*registrations).await;
+                    });
+                }
+                GossipedMessage::KillSwitch(kill_switch) => {
+                    let api_clone = self.clone();
+                    tokio::spawn(async move {
+                        api_clone.process_gossiped_kill_switch(*kill_switch).await;
+                    });
+                }
+                _ => {}
             }
         }
     }
@@ -1241,8 +1616,22 @@ where
         user_agent: Option<String>,
     ) {
         let db = self.db.clone();
+        let auctioneer = self.auctioneer.clone();
 
         tokio::spawn(async move {
+            // Snapshot the bids that were competing when the best bid was fetched.
+            let bid_history =
+                match auctioneer.get_bid_history(slot, &parent_hash, &public_key).await {
+                    Ok(bid_history) => bid_history
+                        .into_iter()
+                        .filter(|entry| entry.received_at <= trace.best_bid_fetched)
+                        .collect(),
+                    Err(err) => {
+                        error!(request_id = %request_id, error = %err, "error getting bid history");
+                        vec![]
+                    }
+                };
+
             if let Err(err) = db
                 .save_get_header_call(
                     slot,
@@ -1251,6 +1640,7 @@ where
                     best_block_hash,
                     trace,
                     user_agent,
+                    bid_history,
                 )
                 .await
             {
@@ -1268,12 +1658,111 @@ where
     }
 }
 
-async fn deserialize_get_payload_bytes(
+pub(crate) async fn deserialize_get_payload_bytes(
     req: Request<Body>,
 ) -> Result<SignedBlindedBeaconBlock, ProposerApiError> {
+    let is_ssz = is_ssz_content(req.headers());
+    let consensus_version = req
+        .headers()
+        .get(CONSENSUS_VERSION_HEADER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.to_lowercase());
+
     let body = req.into_body();
     let body_bytes = to_bytes(body, MAX_BLINDED_BLOCK_LENGTH).await?;
-    Ok(serde_json::from_slice(&body_bytes)?)
+    if !is_ssz {
+        return Ok(serde_json::from_slice(&body_bytes)?);
+    }
+
+    // Decode as the announced fork, otherwise try each fork in turn
+    match consensus_version.as_deref() {
+        Some("bellatrix") => Ok(SignedBlindedBeaconBlock::Bellatrix(deserialize(&body_bytes)?)),
+        Some("capella") => Ok(SignedBlindedBeaconBlock::Capella(deserialize(&body_bytes)?)),
+        Some("deneb") => Ok(SignedBlindedBeaconBlock::Deneb(deserialize(&body_bytes)?)),
+        Some(_) => Err(ProposerApiError::UnsupportedBeaconChainVersion),
+        None => Ok(deserialize(&body_bytes)?),
+    }
+}
+
+/// Decodes a `register_validators` body, sent either as a JSON array or an SSZ list.
+pub(crate) async fn deserialize_registrations(
+    req: Request<Body>,
+) -> Result<Vec<SignedValidatorRegistration>, ProposerApiError> {
+    let is_ssz = is_ssz_content(req.headers());
+
+    let body = req.into_body();
+    let body_bytes = to_bytes(body, MAX_VAL_REGISTRATIONS_LENGTH).await?;
+    if !is_ssz {
+        return Ok(serde_json::from_slice(&body_bytes)?);
+    }
+
+    if body_bytes.len() % SIGNED_VALIDATOR_REGISTRATION_SSZ_LENGTH != 0 {
+        return Err(ProposerApiError::InvalidSszLength(body_bytes.len()));
+    }
+    body_bytes
+        .chunks(SIGNED_VALIDATOR_REGISTRATION_SSZ_LENGTH)
+        .map(|chunk| Ok(deserialize(chunk)?))
+        .collect()
+}
+
+fn is_ssz_content(headers: &HeaderMap) -> bool {
+    headers
+        .get(CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .map_or(false, |v| v.starts_with(SSZ_CONTENT_TYPE))
+}
+
+/// Returns true if the `Accept` header ranks SSZ at least as high as JSON. Clients that don't
+/// mention SSZ get JSON.
+pub(crate) fn accepts_ssz(headers: &HeaderMap) -> bool {
+    let Some(accept) = headers.get(ACCEPT).and_then(|v| v.to_str().ok()) else {
+        return false;
+    };
+
+    let mut ssz_quality: Option<f32> = None;
+    let mut json_quality: Option<f32> = None;
+    for media_range in accept.split(',') {
+        let mut params = media_range.split(';');
+        let media_type = params.next().unwrap_or_default().trim();
+        let quality = params
+            .filter_map(|param| param.trim().strip_prefix("q="))
+            .find_map(|q| q.parse::<f32>().ok())
+            .unwrap_or(1.0);
+
+        let best_quality = match media_type {
+            SSZ_CONTENT_TYPE => &mut ssz_quality,
+            "application/json" | "application/*" | "*/*" => &mut json_quality,
+            _ => continue,
+        };
+        *best_quality = Some(best_quality.map_or(quality, |q| q.max(quality)));
+    }
+
+    match ssz_quality {
+        Some(ssz_quality) => ssz_quality > 0.0 && ssz_quality >= json_quality.unwrap_or(0.0),
+        None => false,
+    }
+}
+
+/// Encodes a `get_payload` response as SSZ if the `Accept` header prefers it, otherwise as JSON.
+pub(crate) fn encode_get_payload_response(
+    headers: &HeaderMap,
+    get_payload_response: &GetPayloadResponse,
+) -> Result<Response, ProposerApiError> {
+    let mut response = if accepts_ssz(headers) {
+        let bytes =
+            get_payload_response.ssz_bytes()?.ok_or(ProposerApiError::PayloadTypeMismatch)?;
+        ([(CONTENT_TYPE, SSZ_CONTENT_TYPE)], bytes).into_response()
+    } else {
+        axum::Json(get_payload_response).into_response()
+    };
+    set_consensus_version(&mut response, get_payload_response.version());
+    Ok(response)
+}
+
+fn set_consensus_version(response: &mut Response, fork: Fork) {
+    if let Ok(value) = HeaderValue::from_str(&fork.to_string()) {
+        response.headers_mut().insert(CONSENSUS_VERSION_HEADER, value);
+    }
 }
 
 // STATE SYNC
@@ -5,8 +5,8 @@ use axum::{
     response::{IntoResponse, Response},
 };
 use ethereum_consensus::{
-    primitives::{BlsPublicKey, ExecutionAddress, Hash32, Slot},
-    ssz::prelude::MerkleizationError,
+    primitives::{BlsPublicKey, ExecutionAddress, Hash32, Slot, U256},
+    ssz::prelude::{DeserializeError, MerkleizationError, SerializeError},
 };
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
@@ -38,6 +38,9 @@ pub enum ProposerApiError {
     #[error("bid has value 0")]
     BidValueZero,
 
+    #[error("bid value {value} is below the proposer minimum {min_bid_value}")]
+    BidBelowMinimum { value: U256, min_bid_value: U256 },
+
     #[error("could not find relay with outstanding bid to accept")]
     MissingOpenBid,
 
@@ -65,6 +68,15 @@ pub enum ProposerApiError {
     #[error("serde decode error: {0}")]
     SerdeDecodeError(#[from] serde_json::Error),
 
+    #[error("ssz deserialize error: {0}")]
+    SszDeserializeError(#[from] DeserializeError),
+
+    #[error("ssz serialize error: {0}")]
+    SszSerializeError(#[from] SerializeError),
+
+    #[error("invalid ssz length: {0}")]
+    InvalidSszLength(usize),
+
     #[error("block does not match the provided header")]
     UnknownBlock,
 
@@ -180,6 +192,9 @@ pub enum ProposerApiError {
 
     #[error("not serving headers")]
     NotServingHeaders,
+
+    #[error("kill switch enabled: {reason}")]
+    KillSwitchEnabled { reason: String },
 }
 
 impl IntoResponse for ProposerApiError {
@@ -206,6 +221,9 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::BidValueZero => {
                 (StatusCode::NO_CONTENT, "Bid has value 0").into_response()
             },
+            ProposerApiError::BidBelowMinimum { value, min_bid_value } => {
+                (StatusCode::NO_CONTENT, format!("Bid value {value} is below the proposer minimum {min_bid_value}")).into_response()
+            },
             ProposerApiError::MissingOpenBid => {
                 (StatusCode::BAD_REQUEST, "Could not find relay with outstanding bid to accept").into_response()
             },
@@ -233,6 +251,15 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::SerdeDecodeError(err) => {
                 (StatusCode::BAD_REQUEST, format!("Serde decode error: {err}")).into_response()
             },
+            ProposerApiError::SszDeserializeError(err) => {
+                (StatusCode::BAD_REQUEST, format!("SSZ deserialize error: {err}")).into_response()
+            },
+            ProposerApiError::SszSerializeError(err) => {
+                (StatusCode::INTERNAL_SERVER_ERROR, format!("SSZ serialize error: {err}")).into_response()
+            },
+            ProposerApiError::InvalidSszLength(len) => {
+                (StatusCode::BAD_REQUEST, format!("Invalid SSZ length: {len}")).into_response()
+            },
             ProposerApiError::UnknownBlock => {
                 (StatusCode::BAD_REQUEST, "Block does not match the provided header").into_response()
             },
@@ -353,6 +380,9 @@ impl IntoResponse for ProposerApiError {
             ProposerApiError::NotServingHeaders => {
                 (StatusCode::NO_CONTENT, ProposerApiError::NotServingHeaders.to_string()).into_response()
             }
+            ProposerApiError::KillSwitchEnabled { reason } => {
+                (StatusCode::SERVICE_UNAVAILABLE, format!("kill switch enabled: {reason}")).into_response()
+            }
         }
     }
 }
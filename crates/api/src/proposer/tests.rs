@@ -34,19 +34,32 @@ mod proposer_api_tests {
     use crate::{
         gossiper::{mock_gossiper::MockGossiper, types::GossipedMessage},
         proposer::{
-            api::{get_nanos_timestamp, ProposerApi},
-            PATH_GET_PAYLOAD, PATH_PROPOSER_API,
+            api::{
+                accepts_ssz, deserialize_get_payload_bytes, deserialize_registrations,
+                encode_get_payload_response, get_nanos_timestamp, ProposerApi,
+            },
+            error::ProposerApiError,
+            PATH_GET_PAYLOAD, PATH_PROPOSER_API, PATH_PROPOSER_API_V2,
         },
         test_utils::proposer_api_app,
     };
 
+    use axum::{
+        body::{to_bytes, Body},
+        http::{self, HeaderMap, Request},
+        Extension,
+    };
     use ethereum_consensus::{
         bellatrix,
         builder::{SignedValidatorRegistration, ValidatorRegistration},
         capella::mainnet::{BlindedBeaconBlockBody, ExecutionPayloadHeader},
+        clock::from_system_time,
+        crypto::SecretKey,
         deneb::SyncAggregate,
-        phase0::Eth1Data,
+        domains::DomainType,
+        phase0::{mainnet::compute_domain, Eth1Data},
         primitives::{BlsPublicKey, BlsSignature},
+        signing::compute_signing_root,
         ssz::prelude::*,
     };
     use rand::Rng;
@@ -58,11 +71,14 @@ mod proposer_api_tests {
     use helix_beacon_client::mock_multi_beacon_client::MockMultiBeaconClient;
     use helix_common::{
         api::{
-            builder_api::BuilderGetValidatorsResponseEntry, proposer_api::ValidatorRegistrationInfo,
+            builder_api::BuilderGetValidatorsResponseEntry,
+            proposer_api::{GetPayloadResponse, ValidatorRegistrationInfo},
         },
+        bid_submission::BidTrace,
         capella::{self},
         chain_info::ChainInfo,
         deneb::{self},
+        try_execution_header_from_payload,
         versioned_payload::PayloadAndBlobs,
         SignedBuilderBid, ValidatorPreferences,
     };
@@ -79,6 +95,7 @@ mod proposer_api_tests {
         },
         time::sleep,
     };
+    use uuid::Uuid;
 
     // +++ HELPER VARIABLES +++
     const ADDRESS: &str = "0.0.0.0";
@@ -510,6 +527,52 @@ mod proposer_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_get_header_for_current_slot_below_min_bid_value() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, auctioneer) =
+            start_api_server().await;
+
+        // Set a SignedBuilderBid in the auctioneer
+        let builder_bid = get_signed_builder_bid(U256::from(10));
+        let _ = auctioneer.best_bid.lock().unwrap().insert(builder_bid.clone());
+
+        // Send a slot update whose proposer wants at least 20 wei
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        let mut slot_update = get_dummy_slot_update(None, None, None);
+        if let Some(next_duty) = slot_update.next_duty.as_mut() {
+            next_duty.entry.preferences.min_bid_value = Some(U256::from(20));
+        }
+        slot_update_sender.send(ChainUpdate::SlotUpdate(slot_update)).await.unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+
+        let current_slot = calculate_current_slot();
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}/header/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            current_slot + 1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_get_header_for_current_slot_auctioneer_error() {
@@ -598,6 +661,52 @@ mod proposer_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_get_header_for_current_slot_ok_ssz() {
+        // Start the server
+        let (tx, http_config, _api, mut slot_update_receiver, auctioneer) =
+            start_api_server().await;
+
+        // Set a SignedBuilderBid in the auctioneer
+        let builder_bid = get_signed_builder_bid(U256::from(10));
+        let _ = auctioneer.best_bid.lock().unwrap().insert(builder_bid.clone());
+
+        // Send slot & payload attributes updates
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        send_dummy_slot_update(slot_update_sender.clone(), None, None, None).await;
+
+        let current_slot = calculate_current_slot();
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}/header/{}/{}/{}",
+            http_config.base_url(),
+            PATH_PROPOSER_API,
+            current_slot + 1,
+            PARENT_HASH,
+            PUB_KEY,
+        );
+
+        // Request an SSZ encoded response
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/octet-stream;q=1.0,application/json;q=0.9")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(resp.headers().get("content-type").unwrap(), "application/octet-stream");
+        assert_eq!(resp.headers().get("eth-consensus-version").unwrap(), "capella");
+        let body = resp.bytes().await.unwrap();
+        let bid: capella::SignedBuilderBid = deserialize(&body).unwrap();
+        assert_eq!(bid.message.value, builder_bid.value());
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
     // GET_PAYLOAD
     #[tokio::test]
     #[serial]
@@ -635,6 +744,42 @@ mod proposer_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    #[ignore]
+    async fn test_get_payload_v2_no_proposer_duty() {
+        // Start the server
+        let (tx, http_config, _api, _slot_update_receiver, auctioneer) = start_api_server().await;
+
+        // Set a SignedBuilderBid in the auctioneer
+        let builder_bid = get_signed_builder_bid(U256::from(10));
+        let _ = auctioneer.best_bid.lock().unwrap().insert(builder_bid.clone());
+
+        let current_slot = calculate_current_slot();
+
+        // Prepare the request
+        let req_url =
+            format!("{}{}{}", http_config.base_url(), PATH_PROPOSER_API_V2, PATH_GET_PAYLOAD);
+
+        let signed_blinded_beacon_block = get_valid_signed_blinded_beacon_block(current_slot, 1);
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .post(req_url.as_str())
+            .header("accept", "*/*")
+            .header("Content-Type", "application/json")
+            .json(&signed_blinded_beacon_block)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "proposer not registered");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
     #[tokio::test]
     #[serial]
     #[ignore]
@@ -933,6 +1078,89 @@ mod proposer_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    async fn test_get_payload_v2_trusted_proposer_ok() {
+        // Chain whose slot `HEAD_SLOT + 1` starts now, so the request is neither early nor late
+        let mut context = ChainInfo::for_mainnet().context;
+        context.bellatrix_fork_epoch = 0;
+        context.capella_fork_epoch = 0;
+        context.deneb_fork_epoch = u64::MAX;
+        let genesis_time_in_secs =
+            get_nanos_timestamp().unwrap() / 1_000_000_000 - SUBMISSION_SLOT * 12;
+        let chain_info = ChainInfo {
+            clock: from_system_time(genesis_time_in_secs, 12, context.slots_per_epoch),
+            genesis_time_in_secs,
+            context,
+            ..ChainInfo::for_mainnet()
+        };
+
+        // The mock auctioneer treats every proposer as trusted
+        let auctioneer = Arc::new(MockAuctioneer::default());
+        let mut payload = PayloadAndBlobs::default();
+        let header = try_execution_header_from_payload(&mut payload.execution_payload).unwrap();
+        *auctioneer.versioned_execution_payload.lock().unwrap() = Some(payload);
+        *auctioneer.bid_trace.lock().unwrap() = Some(BidTrace::default());
+        let db = Arc::new(MockDatabaseService::default());
+
+        let (slot_update_sender, mut slot_update_receiver) = channel(32);
+        let (_gossip_sender, gossip_receiver) = channel::<GossipedMessage>(32);
+        let api = Arc::new(ProposerApi::new(
+            auctioneer,
+            db.clone(),
+            Arc::new(MockGossiper::new().unwrap()),
+            vec![],
+            Arc::new(MockMultiBeaconClient::default()),
+            Arc::new(chain_info.clone()),
+            slot_update_sender,
+            Arc::new(ValidatorPreferences::default()),
+            0,
+            gossip_receiver,
+        ));
+
+        // Register a proposer we hold the key of for the next slot
+        let secret_key = SecretKey::random(&mut rand::thread_rng()).unwrap();
+        let mut duty = get_valid_payload_register_validator(None, None);
+        duty.entry.registration.message.public_key = secret_key.public_key();
+        let slot_update = SlotUpdate {
+            slot: HEAD_SLOT,
+            next_duty: Some(duty.clone()),
+            new_duties: Some(vec![duty]),
+        };
+        let slot_update_sender = slot_update_receiver.recv().await.unwrap();
+        slot_update_sender.send(ChainUpdate::SlotUpdate(slot_update)).await.unwrap();
+        sleep(Duration::from_millis(100)).await;
+
+        let mut block = get_blinded_beacon_block(SUBMISSION_SLOT, VALIDATOR_INDEX);
+        block.body.execution_payload_header = header.capella().unwrap().clone();
+        let domain = compute_domain(
+            DomainType::BeaconProposer,
+            Some(chain_info.context.capella_fork_version),
+            Some(chain_info.genesis_validators_root),
+            &chain_info.context,
+        )
+        .unwrap();
+        let signing_root = compute_signing_root(&mut block, domain).unwrap();
+        let signed_blinded_block =
+            SignedBlindedBeaconBlock::Capella(capella::SignedBlindedBeaconBlock {
+                message: block,
+                signature: secret_key.sign(signing_root.as_ref()),
+            });
+
+        let req = Request::builder()
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&signed_blinded_block).unwrap()))
+            .unwrap();
+        let status = ProposerApi::get_payload_v2(Extension(api), req).await.unwrap();
+        assert_eq!(status, http::StatusCode::ACCEPTED);
+
+        // The delivered payload is saved in the background
+        sleep(Duration::from_millis(100)).await;
+        let traces = db.delivered_payload_traces();
+        assert_eq!(traces.len(), 1);
+        assert!(traces[0].v2);
+        assert!(traces[0].beacon_client_broadcast > 0);
+    }
+
     #[tokio::test(flavor = "multi_thread")]
     #[serial]
     #[ignore]
@@ -1018,6 +1246,161 @@ mod proposer_api_tests {
         prop_api.validate_registration(&mut x).unwrap();
     }
 
+    #[tokio::test]
+    async fn test_validate_registrations_batch_fallback() {
+        let (slot_update_sender, _slot_update_receiver) = channel::<Sender<ChainUpdate>>(32);
+        let (_gossip_sender, gossip_receiver) = channel::<GossipedMessage>(32);
+        let auctioneer = Arc::new(MockAuctioneer::default());
+
+        let prop_api = ProposerApi::<
+            MockAuctioneer,
+            MockDatabaseService,
+            MockMultiBeaconClient,
+            MockGossiper,
+        >::new(
+            auctioneer.clone(),
+            Arc::new(MockDatabaseService::default()),
+            Arc::new(MockGossiper::new().unwrap()),
+            vec![],
+            Arc::new(MockMultiBeaconClient::default()),
+            Arc::new(ChainInfo::for_mainnet()),
+            slot_update_sender.clone(),
+            Arc::new(ValidatorPreferences::default()),
+            0,
+            gossip_receiver,
+        );
+
+        let registrations: Vec<_> = (0..4).map(|_| gen_signed_vr()).collect();
+        let valid = prop_api.validate_registrations(registrations.clone(), Uuid::new_v4());
+        assert_eq!(valid.len(), 4);
+
+        // A signature from another registration fails the batch, the rest are still accepted
+        let mut registrations = registrations;
+        registrations[2].signature = registrations[1].signature.clone();
+        let valid = prop_api.validate_registrations(registrations.clone(), Uuid::new_v4());
+        assert_eq!(valid.len(), 3);
+        assert!(!valid.contains(&registrations[2]));
+    }
+
+    #[test]
+    fn test_accepts_ssz() {
+        let accepts = |accept: Option<&str>| {
+            let mut headers = HeaderMap::new();
+            if let Some(accept) = accept {
+                headers.insert("accept", accept.parse().unwrap());
+            }
+            accepts_ssz(&headers)
+        };
+
+        assert!(!accepts(None));
+        assert!(!accepts(Some("application/json")));
+        assert!(!accepts(Some("*/*")));
+        assert!(accepts(Some("application/octet-stream")));
+        assert!(accepts(Some("application/octet-stream;q=1.0,application/json;q=0.9")));
+        assert!(!accepts(Some("application/octet-stream;q=0.5,application/json")));
+        assert!(!accepts(Some("application/octet-stream;q=0")));
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_registrations_ssz() {
+        let registrations: Vec<_> = (0..3).map(|_| gen_signed_vr()).collect();
+        let mut body = Vec::new();
+        for registration in &registrations {
+            body.extend(serialize(registration).unwrap());
+        }
+
+        let req = Request::builder()
+            .header("content-type", "application/octet-stream")
+            .body(Body::from(body.clone()))
+            .unwrap();
+        assert_eq!(deserialize_registrations(req).await.unwrap(), registrations);
+
+        // Trailing bytes that don't make up a full registration are rejected
+        body.push(0);
+        let req = Request::builder()
+            .header("content-type", "application/octet-stream")
+            .body(Body::from(body))
+            .unwrap();
+        assert!(deserialize_registrations(req).await.is_err());
+
+        let req = Request::builder()
+            .header("content-type", "application/json")
+            .body(Body::from(serde_json::to_vec(&registrations).unwrap()))
+            .unwrap();
+        assert_eq!(deserialize_registrations(req).await.unwrap(), registrations);
+    }
+
+    #[tokio::test]
+    async fn test_deserialize_get_payload_bytes_ssz() {
+        let SignedBlindedBeaconBlock::Capella(block) = get_valid_signed_blinded_beacon_block(5, 1)
+        else {
+            unreachable!()
+        };
+        let body = serialize(&block).unwrap();
+
+        let request = |content_type: &str, version: Option<&str>, body: Vec<u8>| {
+            let mut builder = Request::builder().header("content-type", content_type);
+            if let Some(version) = version {
+                builder = builder.header("eth-consensus-version", version);
+            }
+            builder.body(Body::from(body)).unwrap()
+        };
+
+        // Decoded as the announced fork
+        let req = request("application/octet-stream", Some("capella"), body.clone());
+        let decoded = deserialize_get_payload_bytes(req).await.unwrap();
+        assert!(matches!(decoded, SignedBlindedBeaconBlock::Capella(_)));
+        assert_eq!(decoded.message().slot(), 5);
+        assert_eq!(decoded.message().proposer_index(), 1);
+
+        // The announced fork doesn't match the body
+        let req = request("application/octet-stream", Some("deneb"), body.clone());
+        assert!(deserialize_get_payload_bytes(req).await.is_err());
+
+        let req = request("application/octet-stream", Some("electra"), body);
+        assert!(matches!(
+            deserialize_get_payload_bytes(req).await,
+            Err(ProposerApiError::UnsupportedBeaconChainVersion)
+        ));
+
+        let req = request("application/json", None, serde_json::to_vec(&block).unwrap());
+        let decoded = deserialize_get_payload_bytes(req).await.unwrap();
+        assert_eq!(decoded.message().slot(), 5);
+    }
+
+    #[tokio::test]
+    async fn test_encode_get_payload_response() {
+        let execution_payload = capella::ExecutionPayload::default();
+        let get_payload_response =
+            GetPayloadResponse::Capella(ExecutionPayload::Capella(execution_payload.clone()));
+
+        let headers = |accept: &str| {
+            let mut headers = HeaderMap::new();
+            headers.insert("accept", accept.parse().unwrap());
+            headers
+        };
+
+        let response =
+            encode_get_payload_response(&headers("application/json"), &get_payload_response)
+                .unwrap();
+        assert_eq!(response.headers().get("content-type").unwrap(), "application/json");
+        assert_eq!(response.headers().get("eth-consensus-version").unwrap(), "capella");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let payload: ExecutionPayload = serde_json::from_slice(&body).unwrap();
+        assert_eq!(payload.block_hash(), &execution_payload.block_hash);
+
+        let response = encode_get_payload_response(
+            &headers("application/octet-stream;q=1.0,application/json;q=0.9"),
+            &get_payload_response,
+        )
+        .unwrap();
+        assert_eq!(response.headers().get("content-type").unwrap(), "application/octet-stream");
+        assert_eq!(response.headers().get("eth-consensus-version").unwrap(), "capella");
+        let body = to_bytes(response.into_body(), usize::MAX).await.unwrap();
+        let payload: capella::ExecutionPayload = deserialize(&body).unwrap();
+        assert_eq!(payload, execution_payload);
+    }
+
     #[test]
     fn test_verify_signed_blinded_block_signature_from_file_deneb() {
         let mut current_dir = std::env::current_dir().expect("Failed to get current directory");
@@ -1,6 +1,6 @@
 use ethereum_consensus::{
     bellatrix, capella, deneb,
-    primitives::{BlsPublicKey, Hash32},
+    primitives::{BlsPublicKey, Hash32, U256},
     types::mainnet::{SignedBeaconBlock, SignedBlindedBeaconBlock},
 };
 use helix_common::{
@@ -12,6 +12,7 @@ use serde::Deserialize;
 use crate::proposer::error::ProposerApiError;
 
 pub(crate) const PATH_PROPOSER_API: &str = "/eth/v1/builder";
+pub(crate) const PATH_PROPOSER_API_V2: &str = "/eth/v2/builder";
 
 pub(crate) const PATH_STATUS: &str = "/status";
 pub(crate) const PATH_REGISTER_VALIDATORS: &str = "/validators";
@@ -160,9 +161,15 @@ pub struct PreferencesHeader {
     /// This allows for limiting submissions to a trusted set of builders.
     pub trusted_builders: Option<Vec<String>>,
 
+    /// An optional list of BuilderIDs whose submissions the relay will reject for this proposer.
+    pub blocked_builders: Option<Vec<String>>,
+
     /// Allows validators to express a preference for whether a delay should be applied to get
     /// headers or not.
     pub header_delay: Option<bool>,
 
     pub gossip_blobs: Option<bool>,
+
+    /// Minimum bid value in wei below which the proposer would rather build locally.
+    pub min_bid_value: Option<U256>,
 }
@@ -1,7 +1,7 @@
-use std::sync::Arc;
+use std::{collections::BTreeMap, sync::Arc};
 
 use axum::{
-    extract::{Extension, Query},
+    extract::{Extension, Path, Query},
     response::IntoResponse,
     Json,
 };
@@ -10,8 +10,12 @@ use tracing::warn;
 
 use helix_common::{
     api::data_api::{
-        BuilderBlocksReceivedParams, DeliveredPayloadsResponse, ProposerPayloadDeliveredParams,
-        ReceivedBlocksResponse, ValidatorRegistrationParams,
+        BlockTraceParams, BlockTraceResponse, BuilderBlocksReceivedParams, BuilderStatsParams,
+        BuilderStatsResponse, DeliveredPayloadsResponse, FailedGetPayloadResponse,
+        GetHeaderCallResponse, OrderBy, ProposerCallsParams, ProposerPayloadDeliveredParams,
+        ReceivedBlocksResponse, TooLateGetPayloadResponse, TraceEvent,
+        ValidatorRegistrationHistoryParams, ValidatorRegistrationHistoryResponse,
+        ValidatorRegistrationParams,
     }, ValidatorPreferences,
 };
 use helix_database::DatabaseService;
@@ -23,6 +27,12 @@ pub(crate) const PATH_DATA_API: &str = "/relay/v1/data";
 pub(crate) const PATH_PROPOSER_PAYLOAD_DELIVERED: &str = "/bidtraces/proposer_payload_delivered";
 pub(crate) const PATH_BUILDER_BIDS_RECEIVED: &str = "/bidtraces/builder_blocks_received";
 pub(crate) const PATH_VALIDATOR_REGISTRATION: &str = "/validator_registration";
+pub(crate) const PATH_VALIDATOR_REGISTRATION_HISTORY: &str = "/validator_registration_history";
+pub(crate) const PATH_GET_HEADER_CALLS: &str = "/get_header_calls";
+pub(crate) const PATH_FAILED_GET_PAYLOADS: &str = "/get_payload_failures";
+pub(crate) const PATH_TOO_LATE_GET_PAYLOADS: &str = "/get_payload_too_late";
+pub(crate) const PATH_BLOCK_TRACE: &str = "/traces/:block_hash";
+pub(crate) const PATH_BUILDER_STATS: &str = "/builder_stats";
 
 pub(crate) type BidsCache = Cache<String, Vec<ReceivedBlocksResponse>>;
 pub(crate) type DeliveredPayloadsCache = Cache<String, Vec<DeliveredPayloadsResponse>>;
@@ -85,7 +95,8 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
         if params.slot.is_none() &&
             params.block_hash.is_none() &&
             params.block_number.is_none() &&
-            params.builder_pubkey.is_none()
+            params.builder_pubkey.is_none() &&
+            (params.received_at_from.is_none() || params.received_at_to.is_none())
         {
             return Err(DataApiError::MissingFilter);
         }
@@ -94,6 +105,20 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
             return Err(DataApiError::LimitReached);
         }
 
+        if params.slot.is_some() && params.cursor.is_some() {
+            return Err(DataApiError::SlotAndCursor);
+        }
+
+        if let Some(order_by) = &params.order_by {
+            if OrderBy::from_param(order_by).is_none() {
+                return Err(DataApiError::InvalidOrderBy);
+            }
+            // Submission id pagination is only stable for the default (slot, id) ordering.
+            if params.cursor_id.is_some() {
+                return Err(DataApiError::CursorIdAndOrderBy);
+            }
+        }
+
         let cache_key = format!("{:?}", params);
 
         if let Some(cached_result) = cache.get(&cache_key) {
@@ -129,4 +154,142 @@ impl<DB: DatabaseService + 'static> DataApi<DB> {
             }
         }
     }
+
+    /// Returns the registration changes seen for a validator, most recent first.
+    pub async fn validator_registration_history(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<ValidatorRegistrationHistoryParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        if params.limit.is_some() && params.limit.unwrap() > 500 {
+            return Err(DataApiError::LimitReached);
+        }
+
+        match data_api.db.get_validator_registration_history(params.pubkey, params.limit).await {
+            Ok(result) => Ok(Json(
+                result
+                    .into_iter()
+                    .map(|r| r.into())
+                    .collect::<Vec<ValidatorRegistrationHistoryResponse>>(),
+            )),
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch validator registration history");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+
+    /// Returns the get_header calls received for a slot or proposer.
+    pub async fn get_header_calls(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<ProposerCallsParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        validate_proposer_calls_params(&params)?;
+
+        match data_api.db.get_get_header_calls(&params.into()).await {
+            Ok(result) => Ok(Json(
+                result.into_iter().map(|c| c.into()).collect::<Vec<GetHeaderCallResponse>>(),
+            )),
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch get_header calls");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+
+    /// Returns the get_payload calls that failed for a slot or proposer.
+    pub async fn failed_get_payloads(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<ProposerCallsParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        validate_proposer_calls_params(&params)?;
+
+        match data_api.db.get_failed_get_payloads(&params.into()).await {
+            Ok(result) => Ok(Json(
+                result.into_iter().map(|c| c.into()).collect::<Vec<FailedGetPayloadResponse>>(),
+            )),
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch failed get_payload calls");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+
+    /// Returns the get_payload calls that were received too late for a slot or proposer.
+    pub async fn too_late_get_payloads(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<ProposerCallsParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        validate_proposer_calls_params(&params)?;
+
+        match data_api.db.get_too_late_get_payloads(&params.into()).await {
+            Ok(result) => Ok(Json(
+                result.into_iter().map(|c| c.into()).collect::<Vec<TooLateGetPayloadResponse>>(),
+            )),
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch too late get_payload calls");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+
+    /// Returns the latency timeline of a block hash, merged across all regions.
+    pub async fn block_trace(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Path(BlockTraceParams { block_hash }): Path<BlockTraceParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        let events = match data_api.db.get_block_trace(&block_hash).await {
+            Ok(events) => events,
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch block trace");
+                return Err(DataApiError::InternalServerError);
+            }
+        };
+
+        if events.is_empty() {
+            return Err(DataApiError::TraceNotFound);
+        }
+
+        let mut regions: BTreeMap<i16, Vec<TraceEvent>> = BTreeMap::new();
+        for event in events {
+            regions.entry(event.region_id).or_default().push(TraceEvent {
+                source: event.source,
+                stage: event.stage,
+                timestamp: event.timestamp,
+            });
+        }
+
+        Ok(Json(BlockTraceResponse { block_hash, regions }))
+    }
+
+    /// Returns the builder leaderboard, aggregated from the stats refreshed by the housekeeper.
+    pub async fn builder_stats(
+        Extension(data_api): Extension<Arc<DataApi<DB>>>,
+        Query(params): Query<BuilderStatsParams>,
+    ) -> Result<impl IntoResponse, DataApiError> {
+        if params.limit.is_some() && params.limit.unwrap() > 500 {
+            return Err(DataApiError::LimitReached);
+        }
+
+        match data_api.db.get_builder_stats(&params).await {
+            Ok(result) => Ok(Json(
+                result.into_iter().map(|s| s.into()).collect::<Vec<BuilderStatsResponse>>(),
+            )),
+            Err(err) => {
+                warn!(error=%err, "Failed to fetch builder stats");
+                Err(DataApiError::InternalServerError)
+            }
+        }
+    }
+}
+
+fn validate_proposer_calls_params(params: &ProposerCallsParams) -> Result<(), DataApiError> {
+    if params.slot.is_none() && params.proposer_pubkey.is_none() {
+        return Err(DataApiError::MissingSlotOrProposer);
+    }
+
+    if params.limit.is_some() && params.limit.unwrap() > 500 {
+        return Err(DataApiError::LimitReached);
+    }
+
+    Ok(())
 }
@@ -9,8 +9,16 @@ pub enum DataApiError {
     SlotAndCursor,
     #[error("need to query for specific slot or block_hash or block_number or builder_pubkey")]
     MissingFilter,
+    #[error("need to query for specific slot or proposer_pubkey")]
+    MissingSlotOrProposer,
     #[error("maximum limit is 500")]
     LimitReached,
+    #[error("cannot specify both cursor_id and order_by")]
+    CursorIdAndOrderBy,
+    #[error("invalid order_by, must be one of value, -value, timestamp, -timestamp")]
+    InvalidOrderBy,
+    #[error("no traces found for block hash")]
+    TraceNotFound,
     #[error("internal server error")]
     InternalServerError,
 }
@@ -26,9 +34,25 @@ impl IntoResponse for DataApiError {
                 "need to query for specific slot or block_hash or block_number or builder_pubkey",
             )
                 .into_response(),
+            DataApiError::MissingSlotOrProposer => {
+                (StatusCode::BAD_REQUEST, "need to query for specific slot or proposer_pubkey")
+                    .into_response()
+            }
             DataApiError::LimitReached => {
                 (StatusCode::BAD_REQUEST, "maximum limit is 500").into_response()
             }
+            DataApiError::CursorIdAndOrderBy => {
+                (StatusCode::BAD_REQUEST, "cannot specify both cursor_id and order_by")
+                    .into_response()
+            }
+            DataApiError::InvalidOrderBy => (
+                StatusCode::BAD_REQUEST,
+                "invalid order_by, must be one of value, -value, timestamp, -timestamp",
+            )
+                .into_response(),
+            DataApiError::TraceNotFound => {
+                (StatusCode::NOT_FOUND, "no traces found for block hash").into_response()
+            }
             DataApiError::InternalServerError => {
                 (StatusCode::INTERNAL_SERVER_ERROR, "internal server error").into_response()
             }
@@ -3,15 +3,18 @@ mod data_api_tests {
     // *** IMPORTS ***
     use crate::{
         relay_data::{
-            DataApi, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API, PATH_PROPOSER_PAYLOAD_DELIVERED,
-            PATH_VALIDATOR_REGISTRATION,
+            DataApi, PATH_BUILDER_BIDS_RECEIVED, PATH_BUILDER_STATS, PATH_DATA_API,
+            PATH_GET_HEADER_CALLS, PATH_PROPOSER_PAYLOAD_DELIVERED, PATH_VALIDATOR_REGISTRATION,
+            PATH_VALIDATOR_REGISTRATION_HISTORY,
         },
         test_utils::data_api_app,
     };
     use ethereum_consensus::{builder::SignedValidatorRegistration, primitives::BlsPublicKey};
     use helix_common::api::data_api::{
-        BuilderBlocksReceivedParams, DeliveredPayloadsResponse, ProposerPayloadDeliveredParams,
-        ReceivedBlocksResponse, ValidatorRegistrationParams,
+        BlockTraceResponse, BuilderBlocksReceivedParams, BuilderStatsParams, BuilderStatsResponse,
+        DeliveredPayloadsResponse, GetHeaderCallResponse, ProposerCallsParams,
+        ProposerPayloadDeliveredParams, ReceivedBlocksResponse, ValidatorRegistrationHistoryParams,
+        ValidatorRegistrationHistoryResponse, ValidatorRegistrationParams,
     };
     use helix_database::MockDatabaseService;
     use helix_utils::request_encoding::Encoding;
@@ -103,9 +106,18 @@ mod data_api_tests {
             block_number: None,
             builder_pubkey: None,
             limit: None,
+            cursor: None,
+            cursor_id: None,
+            order_by: None,
+            received_at_from: None,
+            received_at_to: None,
         }
     }
 
+    fn get_test_proposer_calls_params() -> ProposerCallsParams {
+        ProposerCallsParams { slot: Some(HEAD_SLOT), proposer_pubkey: None, limit: None }
+    }
+
     fn get_test_validator_registration_params() -> ValidatorRegistrationParams {
         ValidatorRegistrationParams { pubkey: BlsPublicKey::default() }
     }
@@ -241,6 +253,36 @@ mod data_api_tests {
         let _ = tx.send(());
     }
 
+    #[tokio::test]
+    #[serial]
+    async fn test_builder_bids_cursor_id_and_order_by() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url =
+            format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_BUILDER_BIDS_RECEIVED,);
+
+        let mut query_params = get_test_builder_blocks_received_params();
+        query_params.cursor_id = Some(100);
+        query_params.order_by = Some("-value".to_string());
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "cannot specify both cursor_id and order_by");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
     #[tokio::test]
     #[serial]
     async fn test_builder_bids_ok() {
@@ -300,4 +342,214 @@ mod data_api_tests {
         // Shut down the server
         let _ = tx.send(());
     }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_header_calls_missing_filter() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url =
+            format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_GET_HEADER_CALLS);
+
+        let mut query_params = get_test_proposer_calls_params();
+        query_params.slot = None;
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(
+            resp.text().await.unwrap(),
+            "need to query for specific slot or proposer_pubkey"
+        );
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_get_header_calls_ok() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url =
+            format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_GET_HEADER_CALLS);
+
+        let query_params = get_test_proposer_calls_params();
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let _response: Vec<GetHeaderCallResponse> = serde_json::from_str(&text).unwrap();
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_block_trace_ok() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let block_hash = format!("0x{}", "ab".repeat(32));
+        let req_url = format!("{}{}/traces/{}", http_config.base_url(), PATH_DATA_API, block_hash);
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let response: BlockTraceResponse = serde_json::from_str(&text).unwrap();
+        assert_eq!(response.regions.get(&0).unwrap()[0].stage, "receive");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_builder_stats_limit_reached() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url = format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_BUILDER_STATS);
+
+        let query_params = BuilderStatsParams { limit: Some(501), ..Default::default() };
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "maximum limit is 500");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_builder_stats_ok() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url = format!("{}{}{}", http_config.base_url(), PATH_DATA_API, PATH_BUILDER_STATS);
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let _response: Vec<BuilderStatsResponse> = serde_json::from_str(&text).unwrap();
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_validator_registration_history_limit_reached() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}{}",
+            http_config.base_url(),
+            PATH_DATA_API,
+            PATH_VALIDATOR_REGISTRATION_HISTORY
+        );
+
+        let query_params = ValidatorRegistrationHistoryParams {
+            pubkey: BlsPublicKey::default(),
+            limit: Some(501),
+        };
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        assert_eq!(resp.text().await.unwrap(), "maximum limit is 500");
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
+
+    #[tokio::test]
+    #[serial]
+    async fn test_validator_registration_history_ok() {
+        // Start the server
+        let (tx, http_config, _api, _database) = start_api_server().await;
+
+        // Prepare the request
+        let req_url = format!(
+            "{}{}{}",
+            http_config.base_url(),
+            PATH_DATA_API,
+            PATH_VALIDATOR_REGISTRATION_HISTORY
+        );
+
+        let query_params =
+            ValidatorRegistrationHistoryParams { pubkey: BlsPublicKey::default(), limit: None };
+
+        // Send JSON encoded request
+        let resp = reqwest::Client::new()
+            .get(req_url.as_str())
+            .header("accept", "application/json")
+            .query(&query_params)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let text = resp.text().await.unwrap();
+        let _response: Vec<ValidatorRegistrationHistoryResponse> =
+            serde_json::from_str(&text).unwrap();
+
+        // Shut down the server
+        let _ = tx.send(());
+    }
 }
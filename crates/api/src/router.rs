@@ -7,13 +7,14 @@ use axum::{
 };
 use helix_beacon_client::{beacon_client::BeaconClient, multi_beacon_client::MultiBeaconClient};
 use helix_common::{Route, RouterConfig};
-use helix_database::postgres::postgres_db_service::PostgresDatabaseService;
+use helix_database::{postgres::postgres_db_service::PostgresDatabaseService, DatabaseService};
 use helix_datastore::redis::redis_cache::RedisCache;
 use std::{collections::HashMap, sync::Arc, time::Duration};
 use tower::{timeout::TimeoutLayer, BoxError, ServiceBuilder};
 use tower_http::limit::RequestBodyLimitLayer;
 
 use crate::{
+    admin::AdminApi,
     builder::{
         api::{BuilderApi, MAX_PAYLOAD_LENGTH},
         optimistic_simulator::OptimisticSimulator,
@@ -29,27 +30,23 @@ use crate::{
     service::API_REQUEST_TIMEOUT,
 };
 
-pub type BuilderApiProd = BuilderApi<
-    RedisCache,
-    PostgresDatabaseService,
-    OptimisticSimulator<RedisCache, PostgresDatabaseService>,
-    GrpcGossiperClientManager,
->;
+pub type BuilderApiProd<DB = PostgresDatabaseService> =
+    BuilderApi<RedisCache, DB, OptimisticSimulator<RedisCache, DB>, GrpcGossiperClientManager>;
 
-pub type ProposerApiProd = ProposerApi<
-    RedisCache,
-    PostgresDatabaseService,
-    MultiBeaconClient<BeaconClient>,
-    GrpcGossiperClientManager,
->;
+pub type ProposerApiProd<DB = PostgresDatabaseService> =
+    ProposerApi<RedisCache, DB, MultiBeaconClient<BeaconClient>, GrpcGossiperClientManager>;
 
-pub type DataApiProd = DataApi<PostgresDatabaseService>;
+pub type DataApiProd<DB = PostgresDatabaseService> = DataApi<DB>;
 
-pub fn build_router(
+pub type AdminApiProd<DB = PostgresDatabaseService> =
+    AdminApi<RedisCache, DB, GrpcGossiperClientManager>;
+
+pub fn build_router<DB: DatabaseService + 'static>(
     router_config: &mut RouterConfig,
-    builder_api: Arc<BuilderApiProd>,
-    proposer_api: Arc<ProposerApiProd>,
-    data_api: Arc<DataApiProd>,
+    builder_api: Arc<BuilderApiProd<DB>>,
+    proposer_api: Arc<ProposerApiProd<DB>>,
+    data_api: Arc<DataApiProd<DB>>,
+    admin_api: Arc<AdminApiProd<DB>>,
     bids_cache: Arc<BidsCache>,
     delivered_payloads_cache: Arc<DeliveredPayloadsCache>,
 ) -> Router {
@@ -73,46 +70,86 @@ pub fn build_router(
     for route in router_config.enabled_routes.iter().map(|route_info| route_info.route) {
         match route {
             Route::GetValidators => {
-                router = router.route(&route.path(), get(BuilderApiProd::get_validators));
+                router = router.route(&route.path(), get(BuilderApiProd::<DB>::get_validators));
             }
             Route::SubmitBlock => {
-                router = router.route(&route.path(), post(BuilderApiProd::submit_block));
+                router = router.route(&route.path(), post(BuilderApiProd::<DB>::submit_block));
             }
             Route::SubmitBlockOptimistic => {
-                router = router.route(&route.path(), post(BuilderApiProd::submit_block_v2));
+                router = router.route(&route.path(), post(BuilderApiProd::<DB>::submit_block_v2));
             }
             Route::SubmitHeader => {
-                router = router.route(&route.path(), post(BuilderApiProd::submit_header));
+                router = router.route(&route.path(), post(BuilderApiProd::<DB>::submit_header));
             }
             Route::CancelBid => {
-                router = router.route(&route.path(), post(BuilderApiProd::cancel_bid));
+                router = router.route(&route.path(), post(BuilderApiProd::<DB>::cancel_bid));
             }
             Route::GetTopBid => {
-                router = router.route(&route.path(), get(BuilderApiProd::get_top_bid));
+                router = router.route(&route.path(), get(BuilderApiProd::<DB>::get_top_bid));
+            }
+            Route::SubmitBlockStream => {
+                router =
+                    router.route(&route.path(), get(BuilderApiProd::<DB>::submit_block_stream));
             }
             Route::Status => {
-                router = router.route(&route.path(), get(ProposerApiProd::status));
+                router = router.route(&route.path(), get(ProposerApiProd::<DB>::status));
             }
             Route::RegisterValidators => {
-                router = router.route(&route.path(), post(ProposerApiProd::register_validators));
+                router =
+                    router.route(&route.path(), post(ProposerApiProd::<DB>::register_validators));
             }
             Route::GetHeader => {
-                router = router.route(&route.path(), get(ProposerApiProd::get_header));
+                router = router.route(&route.path(), get(ProposerApiProd::<DB>::get_header));
             }
             Route::GetPayload => {
-                router = router.route(&route.path(), post(ProposerApiProd::get_payload));
+                router = router.route(&route.path(), post(ProposerApiProd::<DB>::get_payload));
+            }
+            Route::GetPayloadV2 => {
+                router = router.route(&route.path(), post(ProposerApiProd::<DB>::get_payload_v2));
             }
             Route::ProposerPayloadDelivered => {
-                router = router.route(&route.path(), get(DataApiProd::proposer_payload_delivered));
+                router =
+                    router.route(&route.path(), get(DataApiProd::<DB>::proposer_payload_delivered));
             }
             Route::BuilderBidsReceived => {
                 router = router.route(
                     &format!("{PATH_DATA_API}{PATH_BUILDER_BIDS_RECEIVED}"),
-                    get(DataApiProd::builder_bids_received),
+                    get(DataApiProd::<DB>::builder_bids_received),
                 );
             }
             Route::ValidatorRegistration => {
-                router = router.route(&route.path(), get(DataApiProd::validator_registration));
+                router =
+                    router.route(&route.path(), get(DataApiProd::<DB>::validator_registration));
+            }
+            Route::ValidatorRegistrationHistory => {
+                router = router
+                    .route(&route.path(), get(DataApiProd::<DB>::validator_registration_history));
+            }
+            Route::GetHeaderCalls => {
+                router = router.route(&route.path(), get(DataApiProd::<DB>::get_header_calls));
+            }
+            Route::FailedGetPayloads => {
+                router = router.route(&route.path(), get(DataApiProd::<DB>::failed_get_payloads));
+            }
+            Route::TooLateGetPayloads => {
+                router = router.route(&route.path(), get(DataApiProd::<DB>::too_late_get_payloads));
+            }
+            Route::BlockTrace => {
+                router = router.route(&route.path(), get(DataApiProd::<DB>::block_trace));
+            }
+            Route::BuilderStats => {
+                router = router.route(&route.path(), get(DataApiProd::<DB>::builder_stats));
+            }
+            Route::KillSwitches => {
+                router = router.route(
+                    &route.path(),
+                    get(AdminApiProd::<DB>::get_kill_switches)
+                        .post(AdminApiProd::<DB>::update_kill_switch),
+                );
+            }
+            Route::SubmissionWriterStats => {
+                router = router
+                    .route(&route.path(), get(AdminApiProd::<DB>::get_submission_writer_stats));
             }
             _ => {
                 panic!("Route not implemented: {:?}, please add handling if there are new routes or resolve condensed routes before!", route);
@@ -140,6 +177,7 @@ pub fn build_router(
         .layer(Extension(builder_api))
         .layer(Extension(proposer_api))
         .layer(Extension(data_api))
+        .layer(Extension(admin_api))
         .layer(Extension(bids_cache))
         .layer(Extension(delivered_payloads_cache));
 
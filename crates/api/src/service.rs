@@ -12,17 +12,20 @@ use crate::{
     builder::optimistic_simulator::OptimisticSimulator,
     gossiper::grpc_gossiper::GrpcGossiperClientManager,
     relay_data::{BidsCache, DeliveredPayloadsCache},
-    router::{build_router, BuilderApiProd, DataApiProd, ProposerApiProd},
+    router::{build_router, AdminApiProd, BuilderApiProd, DataApiProd, ProposerApiProd},
 };
 use helix_beacon_client::{
     beacon_client::BeaconClient, fiber_broadcaster::FiberBroadcaster,
     multi_beacon_client::MultiBeaconClient, BlockBroadcaster, MultiBeaconClientTrait,
 };
 use helix_common::{
-    chain_info::ChainInfo, signing::RelaySigningContext, BroadcasterConfig, NetworkConfig,
-    RelayConfig,
+    chain_info::ChainInfo, signing::RelaySigningContext, BroadcasterConfig, DatabaseConfig,
+    NetworkConfig, RelayConfig,
+};
+use helix_database::{
+    postgres::postgres_db_service::PostgresDatabaseService,
+    sqlite::sqlite_db_service::SqliteDatabaseService, DatabaseService,
 };
-use helix_database::{postgres::postgres_db_service::PostgresDatabaseService, DatabaseService};
 use helix_datastore::redis::redis_cache::RedisCache;
 use helix_housekeeper::{ChainEventUpdater, Housekeeper};
 
@@ -36,18 +39,32 @@ const PAYLOAD_ATTRIBUTE_CHANNEL_SIZE: usize = 300;
 pub struct ApiService {}
 
 impl ApiService {
-    pub async fn run(mut config: RelayConfig) {
-        let postgres_db = PostgresDatabaseService::from_relay_config(&config).unwrap();
-        postgres_db.run_migrations().await;
-        postgres_db.init_region(&config).await;
-        postgres_db.load_known_validators().await;
-        postgres_db.start_registration_processor().await;
+    pub async fn run(config: RelayConfig) {
+        match config.database {
+            DatabaseConfig::Postgres => {
+                let postgres_db = PostgresDatabaseService::from_relay_config(&config).unwrap();
+                postgres_db.run_migrations().await;
+                postgres_db.init_region(&config).await;
+                postgres_db.load_known_validators().await;
+                postgres_db.start_registration_processor().await;
+                postgres_db
+                    .start_submission_writer(config.postgres.submission_writer.clone())
+                    .await;
+
+                Self::run_with_db(config, Arc::new(postgres_db)).await
+            }
+            DatabaseConfig::Sqlite { .. } => {
+                let sqlite_db = SqliteDatabaseService::from_relay_config(&config).unwrap();
 
-        let db = Arc::new(postgres_db);
+                Self::run_with_db(config, Arc::new(sqlite_db)).await
+            }
+        }
+    }
 
+    async fn run_with_db<DB: DatabaseService + 'static>(mut config: RelayConfig, db: Arc<DB>) {
         let builder_infos = db.get_all_builder_infos().await.expect("failed to load builder infos");
 
-        let auctioneer = Arc::new(RedisCache::new(&config.redis.url, builder_infos).await.unwrap());
+        let auctioneer = Arc::new(RedisCache::from_config(&config.redis, builder_infos).await.unwrap());
 
         let auctioneer_clone = auctioneer.clone();
         tokio::spawn(async move {
@@ -126,7 +143,7 @@ impl ApiService {
         let client =
             reqwest::ClientBuilder::new().timeout(SIMULATOR_REQUEST_TIMEOUT).build().unwrap();
 
-        let simulator = OptimisticSimulator::<RedisCache, PostgresDatabaseService>::new(
+        let simulator = OptimisticSimulator::<RedisCache, DB>::new(
             auctioneer.clone(),
             db.clone(),
             client,
@@ -157,7 +174,7 @@ impl ApiService {
         let (builder_gossip_sender, builder_gossip_receiver) = tokio::sync::mpsc::channel(10_000);
         let (proposer_gossip_sender, proposer_gossip_receiver) = tokio::sync::mpsc::channel(10_000);
 
-        let builder_api = Arc::new(BuilderApiProd::new(
+        let builder_api = Arc::new(BuilderApiProd::<DB>::new(
             auctioneer.clone(),
             db.clone(),
             chain_info.clone(),
@@ -172,7 +189,7 @@ impl ApiService {
 
         gossiper.start_server(builder_gossip_sender, proposer_gossip_sender).await;
 
-        let proposer_api = Arc::new(ProposerApiProd::new(
+        let proposer_api = Arc::new(ProposerApiProd::<DB>::new(
             auctioneer.clone(),
             db.clone(),
             gossiper.clone(),
@@ -185,7 +202,14 @@ impl ApiService {
             proposer_gossip_receiver,
         ));
 
-        let data_api = Arc::new(DataApiProd::new(validator_preferences.clone(), db.clone()));
+        let data_api = Arc::new(DataApiProd::<DB>::new(validator_preferences.clone(), db.clone()));
+
+        let admin_api = Arc::new(AdminApiProd::<DB>::new(
+            auctioneer.clone(),
+            db.clone(),
+            gossiper.clone(),
+            config.admin_api_key.clone(),
+        ));
 
         let bids_cache: Arc<BidsCache> = Arc::new(
             Cache::builder()
@@ -206,6 +230,7 @@ impl ApiService {
             builder_api,
             proposer_api,
             data_api,
+            admin_api,
             bids_cache,
             delivered_payloads_cache,
         );
@@ -28,12 +28,14 @@ use crate::{
     gossiper::{mock_gossiper::MockGossiper, types::GossipedMessage},
     proposer::{
         api::{ProposerApi, MAX_BLINDED_BLOCK_LENGTH, MAX_VAL_REGISTRATIONS_LENGTH},
-        PATH_GET_HEADER, PATH_GET_PAYLOAD, PATH_PROPOSER_API, PATH_REGISTER_VALIDATORS,
-        PATH_STATUS,
+        PATH_GET_HEADER, PATH_GET_PAYLOAD, PATH_PROPOSER_API, PATH_PROPOSER_API_V2,
+        PATH_REGISTER_VALIDATORS, PATH_STATUS,
     },
     relay_data::{
-        DataApi, PATH_BUILDER_BIDS_RECEIVED, PATH_DATA_API, PATH_PROPOSER_PAYLOAD_DELIVERED,
-        PATH_VALIDATOR_REGISTRATION,
+        BidsCache, DataApi, DeliveredPayloadsCache, PATH_BLOCK_TRACE, PATH_BUILDER_BIDS_RECEIVED,
+        PATH_BUILDER_STATS, PATH_DATA_API, PATH_FAILED_GET_PAYLOADS, PATH_GET_HEADER_CALLS,
+        PATH_PROPOSER_PAYLOAD_DELIVERED, PATH_TOO_LATE_GET_PAYLOADS, PATH_VALIDATOR_REGISTRATION,
+        PATH_VALIDATOR_REGISTRATION_HISTORY,
     },
 };
 
@@ -226,6 +228,17 @@ pub fn proposer_api_app() -> (
                 >::get_payload,
             ),
         )
+        .route(
+            &format!("{PATH_PROPOSER_API_V2}{PATH_GET_PAYLOAD}"),
+            post(
+                ProposerApi::<
+                    MockAuctioneer,
+                    MockDatabaseService,
+                    MockMultiBeaconClient,
+                    MockGossiper,
+                >::get_payload_v2,
+            ),
+        )
         .layer(RequestBodyLimitLayer::new(MAX_BLINDED_BLOCK_LENGTH))
         .route(
             &format!("{PATH_PROPOSER_API}{PATH_REGISTER_VALIDATORS}"),
@@ -264,7 +277,33 @@ pub fn data_api_app() -> (Router, Arc<DataApi<MockDatabaseService>>, Arc<MockDat
             &format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION}"),
             get(DataApi::<MockDatabaseService>::validator_registration),
         )
-        .layer(Extension(proposer_api_service.clone()));
+        .route(
+            &format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION_HISTORY}"),
+            get(DataApi::<MockDatabaseService>::validator_registration_history),
+        )
+        .route(
+            &format!("{PATH_DATA_API}{PATH_GET_HEADER_CALLS}"),
+            get(DataApi::<MockDatabaseService>::get_header_calls),
+        )
+        .route(
+            &format!("{PATH_DATA_API}{PATH_FAILED_GET_PAYLOADS}"),
+            get(DataApi::<MockDatabaseService>::failed_get_payloads),
+        )
+        .route(
+            &format!("{PATH_DATA_API}{PATH_TOO_LATE_GET_PAYLOADS}"),
+            get(DataApi::<MockDatabaseService>::too_late_get_payloads),
+        )
+        .route(
+            &format!("{PATH_DATA_API}{PATH_BLOCK_TRACE}"),
+            get(DataApi::<MockDatabaseService>::block_trace),
+        )
+        .route(
+            &format!("{PATH_DATA_API}{PATH_BUILDER_STATS}"),
+            get(DataApi::<MockDatabaseService>::builder_stats),
+        )
+        .layer(Extension(proposer_api_service.clone()))
+        .layer(Extension(Arc::new(BidsCache::new(100))))
+        .layer(Extension(Arc::new(DeliveredPayloadsCache::new(100))));
 
     (router, proposer_api_service, mock_database)
 }
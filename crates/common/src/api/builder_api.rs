@@ -1,9 +1,12 @@
+use std::sync::Arc;
+
 use ethereum_consensus::{
     builder::SignedValidatorRegistration,
     primitives::{BlsPublicKey, ExecutionAddress, Hash32, Slot, U256},
     serde::{as_str},
     ssz::prelude::*,
 };
+use tokio::sync::OnceCell;
 
 use crate::{
     api::proposer_api::ValidatorRegistrationInfo, BuilderValidatorPreferences,
@@ -39,14 +42,153 @@ impl From<BuilderGetValidatorsResponseEntry> for BuilderGetValidatorsResponse {
     }
 }
 
-#[derive(Clone, Default, Debug, SimpleSerialize)]
+#[derive(Clone, Default, Debug, SimpleSerialize, serde::Serialize, serde::Deserialize)]
 pub struct TopBidUpdate {
+    #[serde(with = "as_str")]
     pub timestamp: u64,
+    #[serde(with = "as_str")]
     pub slot: u64,
+    #[serde(with = "as_str")]
     pub block_number: u64,
     pub block_hash: Hash32,
     pub parent_hash: Hash32,
     pub builder_pubkey: BlsPublicKey,
     pub fee_recipient: ExecutionAddress,
+    #[serde(with = "as_str")]
     pub value: U256,
 }
+
+/// A new top bid as published to local subscribers, with the proposer of the auction it is for.
+#[derive(Clone, Debug)]
+pub struct TopBidEvent {
+    pub update: TopBidUpdate,
+    pub proposer_pubkey: BlsPublicKey,
+    /// Latest bid value of every builder in the auction. Fetched by the first subscriber that
+    /// needs it and shared with the others.
+    pub latest_bid_values: Arc<OnceCell<Vec<(BlsPublicKey, U256)>>>,
+}
+
+impl TopBidEvent {
+    pub fn new(update: TopBidUpdate, proposer_pubkey: BlsPublicKey) -> Self {
+        Self { update, proposer_pubkey, latest_bid_values: Default::default() }
+    }
+}
+
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum TopBidEncoding {
+    #[default]
+    Ssz,
+    Json,
+}
+
+/// Sent by a top bid subscriber to choose which auctions it hears about and how updates are
+/// encoded. Filters that are `None` match every auction.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+pub struct TopBidSubscription {
+    #[serde(default)]
+    pub slot: Option<u64>,
+    #[serde(default)]
+    pub parent_hash: Option<Hash32>,
+    #[serde(default)]
+    pub proposer_pubkey: Option<BlsPublicKey>,
+    #[serde(default)]
+    pub encoding: TopBidEncoding,
+}
+
+impl TopBidSubscription {
+    pub fn matches(&self, event: &TopBidEvent) -> bool {
+        self.slot.map_or(true, |slot| slot == event.update.slot) &&
+            self.parent_hash.as_ref().map_or(true, |hash| *hash == event.update.parent_hash) &&
+            self.proposer_pubkey.as_ref().map_or(true, |pubkey| *pubkey == event.proposer_pubkey)
+    }
+}
+
+/// Pushed to subscribers that sent a `TopBidSubscription`. Besides the top bid it carries the
+/// subscriber's own standing, taken over the builder pubkeys registered to its API key.
+#[derive(Clone, Default, Debug, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct TopBidStreamUpdate {
+    pub top_bid: TopBidUpdate,
+    pub proposer_pubkey: BlsPublicKey,
+    /// The subscriber's highest latest bid in the auction, zero if it hasn't bid.
+    #[serde(with = "as_str")]
+    pub own_bid_value: U256,
+    /// 1-based rank of `own_bid_value` among every builder's latest bid, zero if it hasn't bid.
+    #[serde(with = "as_str")]
+    pub own_rank: u64,
+}
+
+/// Kind of submission carried by a frame on the builder submission stream.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SubmissionStreamKind {
+    /// A `SignedBidSubmission`, handled like `submit_block`.
+    SubmitBlock = 0,
+    /// A `SignedHeaderSubmission`, handled like `submit_header`.
+    SubmitHeader = 1,
+    /// A `SignedBidSubmission`, handled like `submit_block_v2`.
+    SubmitBlockOptimisticV2 = 2,
+}
+
+impl TryFrom<u8> for SubmissionStreamKind {
+    type Error = u8;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(Self::SubmitBlock),
+            1 => Ok(Self::SubmitHeader),
+            2 => Ok(Self::SubmitBlockOptimisticV2),
+            other => Err(other),
+        }
+    }
+}
+
+/// Header prefixed to every binary frame on the builder submission stream, followed by the SSZ
+/// encoded submission.
+///
+/// Layout: `kind: u8 | flags: u8 | id: u64 (little endian)`. Bit 0 of `flags` enables
+/// cancellations, like the `cancellations=1` query parameter of the HTTP routes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SubmissionStreamFrameHeader {
+    pub kind: SubmissionStreamKind,
+    pub cancellations_enabled: bool,
+    /// Chosen by the builder and echoed in the ack for this frame.
+    pub id: u64,
+}
+
+impl SubmissionStreamFrameHeader {
+    pub const LEN: usize = 10;
+
+    const FLAG_CANCELLATIONS: u8 = 1;
+
+    /// Splits a frame into its header and SSZ body. Returns `None` if the frame is too short or
+    /// the kind is unknown.
+    pub fn decode(frame: &[u8]) -> Option<(Self, &[u8])> {
+        if frame.len() < Self::LEN {
+            return None;
+        }
+        let kind = SubmissionStreamKind::try_from(frame[0]).ok()?;
+        let cancellations_enabled = frame[1] & Self::FLAG_CANCELLATIONS != 0;
+        let id = u64::from_le_bytes(frame[2..Self::LEN].try_into().ok()?);
+        Some((Self { kind, cancellations_enabled, id }, &frame[Self::LEN..]))
+    }
+
+    pub fn encode(&self) -> [u8; Self::LEN] {
+        let mut header = [0u8; Self::LEN];
+        header[0] = self.kind as u8;
+        if self.cancellations_enabled {
+            header[1] = Self::FLAG_CANCELLATIONS;
+        }
+        header[2..].copy_from_slice(&self.id.to_le_bytes());
+        header
+    }
+}
+
+/// Sent back for every frame on the builder submission stream. `status` is the HTTP status code
+/// the equivalent POST would have returned and `error` its error message, if any.
+#[derive(serde::Serialize, serde::Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct SubmissionStreamAck {
+    pub id: u64,
+    pub status: u16,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
@@ -3,17 +3,47 @@ use ethereum_consensus::{
     serde::as_str,
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+use crate::ValidatorPreferences;
 
 #[derive(Debug, Deserialize, Serialize, Default, Clone)]
 pub struct BidFilters {
     pub slot: Option<u64>,
     pub cursor: Option<u64>,
+    /// Stable submission id of the last bid seen. Only bids with a lower id are returned.
+    pub cursor_id: Option<u64>,
     pub limit: Option<u64>,
     pub block_hash: Option<Hash32>,
     pub block_number: Option<u64>,
     pub proposer_pubkey: Option<BlsPublicKey>,
     pub builder_pubkey: Option<BlsPublicKey>,
-    pub order_by: Option<i8>,
+    pub order_by: Option<OrderBy>,
+    /// Lower bound (inclusive) of the time the bid was first received, in milliseconds.
+    pub received_at_from: Option<u64>,
+    /// Upper bound (exclusive) of the time the bid was first received, in milliseconds.
+    pub received_at_to: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize, Clone, Copy, PartialEq, Eq)]
+pub enum OrderBy {
+    ValueAsc,
+    ValueDesc,
+    TimestampAsc,
+    TimestampDesc,
+}
+
+impl OrderBy {
+    /// Parses the `order_by` query param. A leading `-` sorts in descending order.
+    pub fn from_param(param: &str) -> Option<Self> {
+        match param {
+            "value" => Some(OrderBy::ValueAsc),
+            "-value" => Some(OrderBy::ValueDesc),
+            "timestamp" => Some(OrderBy::TimestampAsc),
+            "-timestamp" => Some(OrderBy::TimestampDesc),
+            _ => None,
+        }
+    }
 }
 
 #[derive(Debug, Deserialize, Serialize)]
@@ -33,19 +63,18 @@ impl From<ProposerPayloadDeliveredParams> for BidFilters {
         BidFilters {
             slot: value.slot,
             cursor: value.cursor,
+            cursor_id: None,
             limit: value.limit,
             block_hash: value.block_hash,
             block_number: value.block_number,
             proposer_pubkey: value.proposer_pubkey,
             builder_pubkey: value.builder_pubkey,
-            order_by: match value.order_by.as_ref() {
-                Some(s) => match s.as_str() {
-                    "value" => Some(1),
-                    "-value" => Some(-1),
-                    _ => None,
-                },
-                None => None,
+            order_by: match value.order_by.as_deref().and_then(OrderBy::from_param) {
+                Some(order_by @ (OrderBy::ValueAsc | OrderBy::ValueDesc)) => Some(order_by),
+                _ => None,
             },
+            received_at_from: None,
+            received_at_to: None,
         }
     }
 }
@@ -78,19 +107,33 @@ pub struct BuilderBlocksReceivedParams {
     pub block_number: Option<u64>,
     pub builder_pubkey: Option<BlsPublicKey>,
     pub limit: Option<u64>,
+    /// Only return bids for slots up to and including this slot.
+    pub cursor: Option<u64>,
+    /// Only return bids with a lower `submission_id` than this. Combined with `cursor` this pages
+    /// through bids in (slot, submission_id) descending order.
+    pub cursor_id: Option<u64>,
+    /// One of `value`, `-value`, `timestamp` or `-timestamp`.
+    pub order_by: Option<String>,
+    /// Only return bids received at or after this unix timestamp in milliseconds.
+    pub received_at_from: Option<u64>,
+    /// Only return bids received before this unix timestamp in milliseconds.
+    pub received_at_to: Option<u64>,
 }
 
 impl From<BuilderBlocksReceivedParams> for BidFilters {
     fn from(value: BuilderBlocksReceivedParams) -> Self {
         BidFilters {
             slot: value.slot,
-            cursor: None,
+            cursor: value.cursor,
+            cursor_id: value.cursor_id,
             limit: value.limit,
             block_hash: value.block_hash,
             block_number: value.block_number,
             proposer_pubkey: None,
             builder_pubkey: value.builder_pubkey,
-            order_by: None,
+            order_by: value.order_by.as_deref().and_then(OrderBy::from_param),
+            received_at_from: value.received_at_from,
+            received_at_to: value.received_at_to,
         }
     }
 }
@@ -118,9 +161,156 @@ pub struct ReceivedBlocksResponse {
     pub timestamp: u64,
     #[serde(with = "as_str")]
     pub timestamp_ms: u64,
+    /// Stable id of this submission, used as `cursor_id` to fetch the next page.
+    #[serde(with = "as_str")]
+    pub submission_id: u64,
 }
 
 #[derive(Debug, Deserialize, Serialize)]
 pub struct ValidatorRegistrationParams {
     pub pubkey: BlsPublicKey,
 }
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ValidatorRegistrationHistoryParams {
+    pub pubkey: BlsPublicKey,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct ProposerCallsParams {
+    pub slot: Option<u64>,
+    pub proposer_pubkey: Option<BlsPublicKey>,
+    pub limit: Option<u64>,
+}
+
+impl From<ProposerCallsParams> for BidFilters {
+    fn from(value: ProposerCallsParams) -> Self {
+        BidFilters {
+            slot: value.slot,
+            proposer_pubkey: value.proposer_pubkey,
+            limit: value.limit,
+            ..Default::default()
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GetHeaderCallResponse {
+    #[serde(with = "as_str")]
+    pub slot: u64,
+    pub region_id: i16,
+    pub parent_hash: Hash32,
+    pub proposer_pubkey: BlsPublicKey,
+    /// Block hash of the best bid returned to the proposer.
+    pub block_hash: Hash32,
+    pub user_agent: Option<String>,
+    #[serde(with = "as_str")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FailedGetPayloadResponse {
+    #[serde(with = "as_str")]
+    pub slot: u64,
+    pub region_id: i16,
+    pub block_hash: Hash32,
+    /// Only known if the relay received a submission for `block_hash`.
+    pub proposer_pubkey: Option<BlsPublicKey>,
+    pub error: String,
+    #[serde(with = "as_str")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TooLateGetPayloadResponse {
+    #[serde(with = "as_str")]
+    pub slot: u64,
+    pub region_id: i16,
+    pub block_hash: Hash32,
+    /// Not recorded for late calls logged before the proposer pubkey was tracked.
+    pub proposer_pubkey: Option<BlsPublicKey>,
+    #[serde(with = "as_str")]
+    pub message_received: u64,
+    #[serde(with = "as_str")]
+    pub payload_fetched: u64,
+}
+
+#[derive(Debug, Deserialize, Serialize)]
+pub struct BlockTraceParams {
+    pub block_hash: Hash32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TraceEvent {
+    /// Trace table the event was recorded in, e.g. `submission` or `gossiped_header`.
+    pub source: String,
+    /// Stage within the trace, e.g. `receive` or `simulation`.
+    pub stage: String,
+    /// Unix timestamp in nanoseconds.
+    #[serde(with = "as_str")]
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BlockTraceResponse {
+    pub block_hash: Hash32,
+    /// Timeline of each region that saw the block, sorted by timestamp.
+    pub regions: BTreeMap<i16, Vec<TraceEvent>>,
+}
+
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct BuilderStatsParams {
+    pub builder_pubkey: Option<BlsPublicKey>,
+    pub builder_id: Option<String>,
+    /// Only include slots first seen at or after this unix timestamp in milliseconds.
+    pub received_at_from: Option<u64>,
+    /// Only include slots first seen before this unix timestamp in milliseconds.
+    pub received_at_to: Option<u64>,
+    pub limit: Option<u64>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BuilderStatsResponse {
+    pub builder_pubkey: BlsPublicKey,
+    pub builder_id: Option<String>,
+    #[serde(with = "as_str")]
+    pub submissions: u64,
+    /// Number of slots the builder submitted at least one bid for.
+    #[serde(with = "as_str")]
+    pub slots: u64,
+    #[serde(with = "as_str")]
+    pub slots_won: u64,
+    pub submissions_per_slot: f64,
+    pub win_rate: f64,
+    #[serde(with = "as_str")]
+    pub avg_value: U256,
+    #[serde(with = "as_str")]
+    pub max_value: U256,
+    pub simulation_failure_rate: f64,
+    /// Share of submissions that went through an optimistic submission path.
+    pub optimistic_share: f64,
+    #[serde(with = "as_str")]
+    pub failed_payloads: u64,
+    #[serde(with = "as_str")]
+    pub demotions: u64,
+}
+
+/// A stored change of a validator's registration or preferences.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ValidatorRegistrationHistoryResponse {
+    pub pubkey: BlsPublicKey,
+    pub fee_recipient: ExecutionAddress,
+    /// Fee recipient before this change, `None` for the first registration seen.
+    pub previous_fee_recipient: Option<ExecutionAddress>,
+    #[serde(with = "as_str")]
+    pub gas_limit: u64,
+    /// Timestamp signed in the registration message.
+    #[serde(with = "as_str")]
+    pub timestamp: u64,
+    pub preferences: ValidatorPreferences,
+    pub pool_name: Option<String>,
+    /// Unix timestamp in seconds at which the change was stored.
+    #[serde(with = "as_str")]
+    pub inserted_at: u64,
+}
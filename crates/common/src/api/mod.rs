@@ -10,8 +10,10 @@ pub(crate) const PATH_SUBMIT_BLOCK_OPTIMISTIC_V2: &str = "/blocks_optimistic_v2"
 pub(crate) const PATH_CANCEL_BID: &str = "/cancel_bid";
 pub(crate) const PATH_SUBMIT_HEADER: &str = "/headers";
 pub(crate) const PATH_GET_TOP_BID: &str = "/top_bid";
+pub(crate) const PATH_SUBMIT_BLOCK_STREAM: &str = "/blocks_stream";
 
 pub(crate) const PATH_PROPOSER_API: &str = "/eth/v1/builder";
+pub(crate) const PATH_PROPOSER_API_V2: &str = "/eth/v2/builder";
 
 pub(crate) const PATH_STATUS: &str = "/status";
 pub(crate) const PATH_REGISTER_VALIDATORS: &str = "/validators";
@@ -23,3 +25,14 @@ pub(crate) const PATH_DATA_API: &str = "/relay/v1/data";
 pub(crate) const PATH_PROPOSER_PAYLOAD_DELIVERED: &str = "/bidtraces/proposer_payload_delivered";
 pub(crate) const PATH_BUILDER_BIDS_RECEIVED: &str = "/bidtraces/builder_blocks_received";
 pub(crate) const PATH_VALIDATOR_REGISTRATION: &str = "/validator_registration";
+pub(crate) const PATH_VALIDATOR_REGISTRATION_HISTORY: &str = "/validator_registration_history";
+pub(crate) const PATH_GET_HEADER_CALLS: &str = "/get_header_calls";
+pub(crate) const PATH_FAILED_GET_PAYLOADS: &str = "/get_payload_failures";
+pub(crate) const PATH_TOO_LATE_GET_PAYLOADS: &str = "/get_payload_too_late";
+pub(crate) const PATH_BLOCK_TRACE: &str = "/traces/:block_hash";
+pub(crate) const PATH_BUILDER_STATS: &str = "/builder_stats";
+
+pub(crate) const PATH_ADMIN_API: &str = "/relay/v1/admin";
+
+pub(crate) const PATH_KILL_SWITCHES: &str = "/kill_switches";
+pub(crate) const PATH_SUBMISSION_WRITER_STATS: &str = "/submission_writer_stats";
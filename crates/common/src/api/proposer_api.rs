@@ -1,8 +1,14 @@
 use ethereum_consensus::{
-    builder::SignedValidatorRegistration, types::mainnet::ExecutionPayload, Fork,
+    builder::SignedValidatorRegistration,
+    ssz::prelude::{serialize, SerializeError},
+    types::mainnet::ExecutionPayload,
+    Fork,
 };
 
-use crate::{validator_preferences::ValidatorPreferences, versioned_payload::PayloadAndBlobs};
+use crate::{
+    deneb::ExecutionPayloadAndBlobsBundle, validator_preferences::ValidatorPreferences,
+    versioned_payload::PayloadAndBlobs,
+};
 
 #[derive(Debug, serde::Serialize)]
 #[serde(tag = "version", content = "data")]
@@ -28,6 +34,35 @@ impl GetPayloadResponse {
             _ => None,
         }
     }
+
+    pub fn version(&self) -> Fork {
+        match self {
+            GetPayloadResponse::Bellatrix(_) => Fork::Bellatrix,
+            GetPayloadResponse::Capella(_) => Fork::Capella,
+            GetPayloadResponse::Deneb(_) => Fork::Deneb,
+        }
+    }
+
+    /// SSZ encoding of the payload as described by the builder-specs. Deneb payloads are encoded
+    /// together with their blobs bundle, which is empty if the block has no blobs. Returns `None`
+    /// if the payload does not match the response fork.
+    pub fn ssz_bytes(&self) -> Result<Option<Vec<u8>>, SerializeError> {
+        match self {
+            GetPayloadResponse::Bellatrix(payload) | GetPayloadResponse::Capella(payload) => {
+                serialize(payload).map(Some)
+            }
+            GetPayloadResponse::Deneb(payload_and_blobs) => {
+                let Some(execution_payload) = payload_and_blobs.execution_payload.deneb() else {
+                    return Ok(None);
+                };
+                let response = ExecutionPayloadAndBlobsBundle {
+                    execution_payload: execution_payload.clone(),
+                    blobs_bundle: payload_and_blobs.blobs_bundle.clone().unwrap_or_default(),
+                };
+                serialize(&response).map(Some)
+            }
+        }
+    }
 }
 
 impl<'de> serde::Deserialize<'de> for GetPayloadResponse {
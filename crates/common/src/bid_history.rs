@@ -0,0 +1,12 @@
+use ethereum_consensus::primitives::{BlsPublicKey, Hash32, U256};
+
+/// A bid received for an auction. Bids are kept in the order they were received, including
+/// ones that were later replaced by the same builder.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Eq, PartialEq)]
+pub struct BidHistoryEntry {
+    pub builder_pubkey: BlsPublicKey,
+    pub block_hash: Hash32,
+    pub value: U256,
+    /// Nanoseconds since the unix epoch.
+    pub received_at: u64,
+}
@@ -1,4 +1,5 @@
 use ethereum_consensus::{primitives::U256, serde::as_str};
+use reth_primitives::{keccak256, B256};
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize, Default, Eq, PartialEq)]
 pub struct BuilderInfo {
@@ -6,4 +7,33 @@ pub struct BuilderInfo {
     pub collateral: U256,
     pub is_optimistic: bool,
     pub builder_id: Option<String>,
+    /// Hash of the key the builder authenticates with in the `x-api-key` header. The key itself
+    /// is never kept, so it doesn't end up in Redis or in logs.
+    #[serde(default)]
+    pub api_key_hash: Option<B256>,
+    /// Reject submissions and cancellations for this builder that don't carry its api key.
+    #[serde(default)]
+    pub api_key_required: bool,
+    /// Submissions accepted per second, summed over all relay instances.
+    #[serde(default)]
+    pub max_submissions_per_second: Option<u64>,
+    /// Submitted bytes accepted per slot, summed over all relay instances.
+    #[serde(default)]
+    pub max_submission_bytes_per_slot: Option<u64>,
+}
+
+impl BuilderInfo {
+    /// Whether `api_key` is the builder's key. Hashes are compared in constant time.
+    pub fn is_api_key(&self, api_key: &str) -> bool {
+        let Some(expected) = &self.api_key_hash else {
+            return false;
+        };
+        let hash = hash_api_key(api_key);
+        hash.iter().zip(expected.iter()).fold(0, |diff, (a, b)| diff | (a ^ b)) == 0
+    }
+}
+
+/// Hashes a builder api key for `BuilderInfo::api_key_hash`.
+pub fn hash_api_key(api_key: &str) -> B256 {
+    keccak256(api_key.as_bytes())
 }
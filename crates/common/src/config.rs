@@ -2,16 +2,23 @@ use crate::{api::*, ValidatorPreferences};
 use clap::Parser;
 use ethereum_consensus::ssz::prelude::Node;
 use helix_utils::{
-    request_encoding::Encoding,
+    request_encoding::{Encoding, ZstdDictionary},
     serde::{default_bool, deserialize_url, serialize_url},
 };
 use reqwest::Url;
 use serde::{Deserialize, Serialize};
-use std::{collections::HashSet, fs::File};
+use std::{
+    collections::HashSet,
+    fs::File,
+    time::{Duration, SystemTime},
+};
 use ethereum_consensus::deneb::BlsPublicKey;
 
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct RelayConfig {
+    #[serde(default)]
+    pub database: DatabaseConfig,
+    #[serde(default)]
     pub postgres: PostgresConfig,
     pub redis: RedisConfig,
     #[serde(default)]
@@ -39,17 +46,50 @@ pub struct RelayConfig {
     pub skip_floor_bid_builder_pubkeys: Vec<BlsPublicKey>,
     #[serde(default)]
     pub discord_webhook_url: Option<String>,
+    /// Alert `discord_webhook_url` when a validator registered through a trusted pool changes
+    /// its fee recipient.
+    #[serde(default)]
+    pub alert_pool_fee_recipient_changes: bool,
+    #[serde(default)]
+    pub retention: RetentionConfig,
+    /// Required in the `x-api-key` header of admin routes. Admin routes reject every request when
+    /// unset.
+    #[serde(default)]
+    pub admin_api_key: Option<String>,
+    /// Zstd dictionary used to decompress builder submissions sent with `Content-Encoding: zstd`.
+    /// Builders must compress with the same dictionary.
+    #[serde(default)]
+    pub zstd_dictionary_path: Option<String>,
+    /// Loaded from `zstd_dictionary_path` by `RelayConfig::load`.
+    #[serde(skip)]
+    pub zstd_dictionary: Option<ZstdDictionary>,
 }
 
 impl RelayConfig {
     pub fn load() -> Result<Self, Box<dyn std::error::Error>> {
         let start_config = StartConfig::parse();
         let file = File::open(start_config.config)?;
-        let config: RelayConfig = serde_yaml::from_reader(file)?;
+        let mut config: RelayConfig = serde_yaml::from_reader(file)?;
+        config.retention.validate()?;
+        if let Some(path) = &config.zstd_dictionary_path {
+            let dictionary = ZstdDictionary::load(path)
+                .map_err(|err| format!("failed to load zstd dictionary {path}: {err}"))?;
+            config.zstd_dictionary = Some(dictionary);
+        }
         Ok(config)
     }
 }
 
+/// Selects the `DatabaseService` backend the relay runs on.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+pub enum DatabaseConfig {
+    /// Uses the connection settings in `RelayConfig::postgres`.
+    #[default]
+    Postgres,
+    /// Embedded single-node store for local development. `path` may be `:memory:`.
+    Sqlite { path: String },
+}
+
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct PostgresConfig {
     pub hostname: String,
@@ -60,15 +100,77 @@ pub struct PostgresConfig {
     pub password: String,
     pub region: i16,
     pub region_name: String,
+    #[serde(default)]
+    pub submission_writer: SubmissionWriterConfig,
+    /// Read replicas serving data API queries. They share the primary's database name and
+    /// credentials.
+    #[serde(default)]
+    pub read_replicas: Vec<PostgresReplicaConfig>,
 }
 
 fn default_port() -> u16 {
     5432
 }
 
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct PostgresReplicaConfig {
+    pub hostname: String,
+    #[serde(default = "default_port")]
+    pub port: u16,
+}
+
+/// Configuration for the buffered writer that batches block submissions into bulk inserts.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(default)]
+pub struct SubmissionWriterConfig {
+    pub flush_interval_ms: u64,
+    pub max_batch_size: usize,
+    /// Once this many submissions are queued, queued raw transactions are dropped and new
+    /// submissions are queued without them until the queue drains.
+    pub queue_capacity: usize,
+    /// Submissions arriving while this many are queued are dropped.
+    pub max_queue_len: usize,
+    /// Also persist the raw transactions of every submission, not only of delivered payloads.
+    pub store_transactions: bool,
+}
+
+impl Default for SubmissionWriterConfig {
+    fn default() -> Self {
+        Self {
+            flush_interval_ms: 200,
+            max_batch_size: 500,
+            queue_capacity: 10_000,
+            max_queue_len: 50_000,
+            store_transactions: false,
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct RedisConfig {
+    /// Single node url. Unused when `sentinel` or `cluster_nodes` is set.
+    #[serde(default)]
     pub url: String,
+    /// Follow the primary of a Sentinel-monitored master across failovers.
+    #[serde(default)]
+    pub sentinel: Option<RedisSentinelConfig>,
+    /// Seed node urls of a Redis Cluster.
+    #[serde(default)]
+    pub cluster_nodes: Option<Vec<String>>,
+    /// Write bids and payloads as zstd-compressed JSON. Both formats are always readable, so only
+    /// enable this once every relay reading the same Redis has been upgraded.
+    #[serde(default)]
+    pub compress_values: bool,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RedisSentinelConfig {
+    /// Sentinel urls, queried in order for the current primary.
+    pub urls: Vec<String>,
+    pub master_name: String,
+    /// Password of the primary, if it requires auth.
+    #[serde(default)]
+    pub password: Option<String>,
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -126,6 +228,80 @@ pub struct PrimevConfig {
     pub validator_contract: String,
 }
 
+/// Retention windows in hours for the high-volume tables. Tables without a window are never
+/// pruned. Rows belonging to a delivered payload are always kept.
+#[derive(Serialize, Deserialize, Clone, Debug, Default)]
+pub struct RetentionConfig {
+    pub block_submission: Option<u64>,
+    /// Transactions of pruned block submissions are always pruned with them.
+    pub transaction: Option<u64>,
+    pub submission_trace: Option<u64>,
+    pub header_submission: Option<u64>,
+    /// Also prunes the bid history snapshotted on each get_header call.
+    pub get_header: Option<u64>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RetentionTable {
+    Transaction,
+    BlockSubmission,
+    SubmissionTrace,
+    HeaderSubmission,
+    /// The bid history snapshotted on each get_header call shares its retention window.
+    GetHeader,
+}
+
+impl RetentionConfig {
+    /// Rejects windows of zero hours, which would prune every row, and windows too large to be
+    /// subtracted from the current time.
+    pub fn validate(&self) -> Result<(), String> {
+        let windows = [
+            ("block_submission", self.block_submission),
+            ("transaction", self.transaction),
+            ("submission_trace", self.submission_trace),
+            ("header_submission", self.header_submission),
+            ("get_header", self.get_header),
+        ];
+
+        for (table, hours) in windows {
+            let Some(hours) = hours else { continue };
+            let is_valid = hours > 0 &&
+                retention_window(hours)
+                    .and_then(|window| SystemTime::now().checked_sub(window))
+                    .is_some();
+            if !is_valid {
+                return Err(format!("invalid retention window for {table}: {hours} hours"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Returns the configured retention windows in the order tables should be pruned.
+    pub fn windows(&self) -> Vec<(RetentionTable, Duration)> {
+        // `transaction` has no timestamp and is pruned through its block submission, so it must go
+        // first and can never outlive it.
+        let transaction = match (self.transaction, self.block_submission) {
+            (Some(t), Some(b)) => Some(t.min(b)),
+            (t, b) => t.or(b),
+        };
+
+        [
+            (RetentionTable::Transaction, transaction),
+            (RetentionTable::BlockSubmission, self.block_submission),
+            (RetentionTable::SubmissionTrace, self.submission_trace),
+            (RetentionTable::HeaderSubmission, self.header_submission),
+            (RetentionTable::GetHeader, self.get_header),
+        ]
+        .into_iter()
+        .filter_map(|(table, window)| Some((table, retention_window(window?)?)))
+        .collect()
+    }
+}
+
+fn retention_window(hours: u64) -> Option<Duration> {
+    hours.checked_mul(60 * 60).map(Duration::from_secs)
+}
+
 #[derive(Default, Serialize, Deserialize, Clone)]
 pub enum LoggingConfig {
     #[default]
@@ -158,31 +334,9 @@ impl RouterConfig {
         }
 
         // Replace BuilderApi, ProposerApi, DataApi with their real routes
-        self.replace_condensed_with_real(
-            Route::BuilderApi,
-            &[
-                Route::GetValidators,
-                Route::SubmitBlock,
-                Route::SubmitBlockOptimistic,
-                Route::SubmitHeader,
-                Route::CancelBid,
-                Route::GetTopBid,
-            ],
-        );
-
-        self.replace_condensed_with_real(
-            Route::ProposerApi,
-            &[Route::Status, Route::RegisterValidators, Route::GetHeader, Route::GetPayload],
-        );
-
-        self.replace_condensed_with_real(
-            Route::DataApi,
-            &[
-                Route::ProposerPayloadDelivered,
-                Route::BuilderBidsReceived,
-                Route::ValidatorRegistration,
-            ],
-        );
+        self.replace_condensed_with_real(Route::BuilderApi, &BUILDER_API_ROUTES);
+        self.replace_condensed_with_real(Route::ProposerApi, &PROPOSER_API_ROUTES);
+        self.replace_condensed_with_real(Route::DataApi, &DATA_API_ROUTES);
     }
 
     fn contains(&self, route: Route) -> bool {
@@ -235,16 +389,69 @@ pub enum Route {
     SubmitHeader,
     CancelBid,
     GetTopBid,
+    SubmitBlockStream,
     Status,
     RegisterValidators,
     GetHeader,
     GetPayload,
+    GetPayloadV2,
     ProposerPayloadDelivered,
     BuilderBidsReceived,
     ValidatorRegistration,
+    ValidatorRegistrationHistory,
+    GetHeaderCalls,
+    FailedGetPayloads,
+    TooLateGetPayloads,
+    BlockTrace,
+    BuilderStats,
+    /// Admin route, never enabled through `All`.
+    KillSwitches,
+    /// Admin route, never enabled through `All`.
+    SubmissionWriterStats,
 }
 
+const BUILDER_API_ROUTES: [Route; 7] = [
+    Route::GetValidators,
+    Route::SubmitBlock,
+    Route::SubmitBlockOptimistic,
+    Route::SubmitHeader,
+    Route::CancelBid,
+    Route::GetTopBid,
+    Route::SubmitBlockStream,
+];
+
+const PROPOSER_API_ROUTES: [Route; 5] = [
+    Route::Status,
+    Route::RegisterValidators,
+    Route::GetHeader,
+    Route::GetPayload,
+    Route::GetPayloadV2,
+];
+
+const DATA_API_ROUTES: [Route; 9] = [
+    Route::ProposerPayloadDelivered,
+    Route::BuilderBidsReceived,
+    Route::ValidatorRegistration,
+    Route::ValidatorRegistrationHistory,
+    Route::GetHeaderCalls,
+    Route::FailedGetPayloads,
+    Route::TooLateGetPayloads,
+    Route::BlockTrace,
+    Route::BuilderStats,
+];
+
 impl Route {
+    /// Whether `route` is this route or, for condensed routes, one of the routes it stands for.
+    pub fn includes(&self, route: Route) -> bool {
+        match self {
+            Route::All => true,
+            Route::BuilderApi => BUILDER_API_ROUTES.contains(&route),
+            Route::ProposerApi => PROPOSER_API_ROUTES.contains(&route),
+            Route::DataApi => DATA_API_ROUTES.contains(&route),
+            _ => *self == route,
+        }
+    }
+
     pub fn path(&self) -> String {
         match self {
             Route::GetValidators => format!("{PATH_BUILDER_API}{PATH_GET_VALIDATORS}"),
@@ -255,15 +462,29 @@ impl Route {
             Route::SubmitHeader => format!("{PATH_BUILDER_API}{PATH_SUBMIT_HEADER}"),
             Route::CancelBid => format!("{PATH_BUILDER_API}{PATH_CANCEL_BID}"),
             Route::GetTopBid => format!("{PATH_BUILDER_API}{PATH_GET_TOP_BID}"),
+            Route::SubmitBlockStream => format!("{PATH_BUILDER_API}{PATH_SUBMIT_BLOCK_STREAM}"),
             Route::Status => format!("{PATH_PROPOSER_API}{PATH_STATUS}"),
             Route::RegisterValidators => format!("{PATH_PROPOSER_API}{PATH_REGISTER_VALIDATORS}"),
             Route::GetHeader => format!("{PATH_PROPOSER_API}{PATH_GET_HEADER}"),
             Route::GetPayload => format!("{PATH_PROPOSER_API}{PATH_GET_PAYLOAD}"),
+            Route::GetPayloadV2 => format!("{PATH_PROPOSER_API_V2}{PATH_GET_PAYLOAD}"),
             Route::ProposerPayloadDelivered => {
                 format!("{PATH_DATA_API}{PATH_PROPOSER_PAYLOAD_DELIVERED}")
             }
             Route::BuilderBidsReceived => format!("{PATH_DATA_API}{PATH_BUILDER_BIDS_RECEIVED}"),
             Route::ValidatorRegistration => format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION}"),
+            Route::ValidatorRegistrationHistory => {
+                format!("{PATH_DATA_API}{PATH_VALIDATOR_REGISTRATION_HISTORY}")
+            }
+            Route::GetHeaderCalls => format!("{PATH_DATA_API}{PATH_GET_HEADER_CALLS}"),
+            Route::FailedGetPayloads => format!("{PATH_DATA_API}{PATH_FAILED_GET_PAYLOADS}"),
+            Route::TooLateGetPayloads => format!("{PATH_DATA_API}{PATH_TOO_LATE_GET_PAYLOADS}"),
+            Route::BlockTrace => format!("{PATH_DATA_API}{PATH_BLOCK_TRACE}"),
+            Route::BuilderStats => format!("{PATH_DATA_API}{PATH_BUILDER_STATS}"),
+            Route::KillSwitches => format!("{PATH_ADMIN_API}{PATH_KILL_SWITCHES}"),
+            Route::SubmissionWriterStats => {
+                format!("{PATH_ADMIN_API}{PATH_SUBMISSION_WRITER_STATS}")
+            }
             Route::All => panic!("All is not a real route"),
             Route::BuilderApi => panic!("BuilderApi is not a real route"),
             Route::ProposerApi => panic!("ProposerApi is not a real route"),
@@ -302,8 +523,10 @@ fn test_config() {
     config.validator_preferences = ValidatorPreferences {
         filtering: Filtering::Regional,
         trusted_builders: None,
+        blocked_builders: None,
         header_delay: true,
-        gossip_blobs: false
+        gossip_blobs: false,
+        min_bid_value: None,
     };
     config.router_config = RouterConfig {
         enabled_routes: vec![
@@ -323,3 +546,106 @@ fn test_config() {
     };
     println!("{}", serde_yaml::to_string(&config).unwrap());
 }
+
+#[cfg(test)]
+#[test]
+fn test_retention_windows() {
+    let config = RetentionConfig {
+        block_submission: Some(24),
+        transaction: Some(48),
+        get_header: Some(1),
+        ..Default::default()
+    };
+
+    let windows = config.windows();
+    assert_eq!(
+        windows,
+        vec![
+            (RetentionTable::Transaction, Duration::from_secs(24 * 60 * 60)),
+            (RetentionTable::BlockSubmission, Duration::from_secs(24 * 60 * 60)),
+            (RetentionTable::GetHeader, Duration::from_secs(60 * 60)),
+        ]
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn test_retention_validation() {
+    let config = RetentionConfig { block_submission: Some(24), ..Default::default() };
+    assert!(config.validate().is_ok());
+
+    let config = RetentionConfig { get_header: Some(0), ..Default::default() };
+    assert!(config.validate().is_err());
+
+    let config = RetentionConfig { transaction: Some(u64::MAX), ..Default::default() };
+    assert!(config.validate().is_err());
+    assert!(config.windows().is_empty());
+}
+
+#[cfg(test)]
+#[test]
+fn test_postgres_read_replicas() {
+    let config: PostgresConfig = serde_yaml::from_str(
+        "
+        hostname: primary
+        db_name: helix
+        user: helix
+        password: password
+        region: 1
+        region_name: test
+        read_replicas:
+          - hostname: replica-1
+          - hostname: replica-2
+            port: 5433
+        ",
+    )
+    .unwrap();
+
+    let replicas: Vec<_> = config
+        .read_replicas
+        .iter()
+        .map(|replica| (replica.hostname.as_str(), replica.port))
+        .collect();
+    assert_eq!(replicas, vec![("replica-1", 5432), ("replica-2", 5433)]);
+}
+
+#[cfg(test)]
+#[test]
+fn test_redis_config() {
+    let config: RedisConfig = serde_yaml::from_str("url: redis://localhost:6379").unwrap();
+    assert_eq!(config.url, "redis://localhost:6379");
+    assert!(config.sentinel.is_none());
+    assert!(config.cluster_nodes.is_none());
+    assert!(!config.compress_values);
+
+    let config: RedisConfig = serde_yaml::from_str(
+        "
+        sentinel:
+          urls:
+            - redis://sentinel-1:26379
+            - redis://sentinel-2:26379
+          master_name: mymaster
+          password: p@ss
+        ",
+    )
+    .unwrap();
+    let sentinel = config.sentinel.unwrap();
+    assert_eq!(sentinel.urls.len(), 2);
+    assert_eq!(sentinel.master_name, "mymaster");
+    assert_eq!(sentinel.password.as_deref(), Some("p@ss"));
+    assert!(config.url.is_empty());
+
+    let config: RedisConfig = serde_yaml::from_str(
+        "
+        cluster_nodes:
+          - redis://node-1:6379
+          - redis://node-2:6379
+        ",
+    )
+    .unwrap();
+    assert_eq!(config.cluster_nodes.unwrap().len(), 2);
+
+    let config: RedisConfig =
+        serde_yaml::from_str("url: redis://localhost:6379\ncompress_values: true").unwrap();
+    assert!(config.compress_values);
+}
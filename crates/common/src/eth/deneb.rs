@@ -66,6 +66,13 @@ pub struct BlobsBundle {
     pub blobs: List<Blob, MAX_BLOB_COMMITMENTS_PER_BLOCK>,
 }
 
+/// SSZ layout of a deneb `getPayload` response.
+#[derive(Debug, Default, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize)]
+pub struct ExecutionPayloadAndBlobsBundle {
+    pub execution_payload: ExecutionPayload,
+    pub blobs_bundle: BlobsBundle,
+}
+
 #[derive(Debug, Clone, SimpleSerialize, serde::Serialize, serde::Deserialize, PartialEq, Eq)]
 pub struct SignedBlockContents {
     pub signed_block: SignedBeaconBlock,
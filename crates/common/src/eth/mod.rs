@@ -11,7 +11,7 @@ use ethereum_consensus::{
     ssz::prelude::*,
     state_transition::Context,
     types::mainnet::{ExecutionPayload, ExecutionPayloadHeader},
-    Error,
+    Error, Fork,
 };
 
 use helix_utils::signing::sign_builder_message;
@@ -153,6 +153,23 @@ impl SignedBuilderBid {
         }
     }
 
+    pub fn version(&self) -> Fork {
+        match self {
+            Self::Bellatrix(_) => Fork::Bellatrix,
+            Self::Capella(_) => Fork::Capella,
+            Self::Deneb(_) => Fork::Deneb,
+        }
+    }
+
+    /// SSZ encoding of the inner bid. The fork is not part of the encoding.
+    pub fn ssz_bytes(&self) -> Result<Vec<u8>, SerializeError> {
+        match self {
+            Self::Bellatrix(bid) => serialize(bid),
+            Self::Capella(bid) => serialize(bid),
+            Self::Deneb(bid) => serialize(bid),
+        }
+    }
+
     pub fn value(&self) -> U256 {
         match self {
             Self::Bellatrix(bid) => bid.message.value,
@@ -0,0 +1,136 @@
+use ethereum_consensus::primitives::BlsPublicKey;
+use serde::{Deserialize, Serialize};
+
+use crate::Route;
+
+/// What a kill switch turns off.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "type", content = "value", rename_all = "snake_case")]
+pub enum KillSwitchScope {
+    /// Every builder and proposer route.
+    Relay,
+    /// A single route, or every route in a condensed route such as `BuilderApi`.
+    Route(Route),
+    Builder(BlsPublicKey),
+    BuilderId(String),
+    Proposer(BlsPublicKey),
+    /// Proposers registered through the named pool.
+    ProposerPool(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KillSwitch {
+    pub scope: KillSwitchScope,
+    pub reason: String,
+    /// Unix timestamp in milliseconds after which the switch no longer applies.
+    #[serde(default)]
+    pub expires_at_ms: Option<u64>,
+}
+
+impl KillSwitch {
+    pub fn is_expired(&self, now_ms: u64) -> bool {
+        self.expires_at_ms.is_some_and(|expires_at_ms| expires_at_ms <= now_ms)
+    }
+}
+
+/// A change to the kill switches. Recorded in the database and gossiped so every region applies
+/// it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum KillSwitchUpdate {
+    Enable(KillSwitch),
+    Disable { scope: KillSwitchScope },
+}
+
+impl KillSwitchUpdate {
+    pub fn scope(&self) -> &KillSwitchScope {
+        match self {
+            KillSwitchUpdate::Enable(kill_switch) => &kill_switch.scope,
+            KillSwitchUpdate::Disable { scope } => scope,
+        }
+    }
+}
+
+/// The request a kill switch is checked against. Fields that aren't known for the route are
+/// `None` and never match a scope.
+#[derive(Debug, Clone, Copy)]
+pub struct KillSwitchTarget<'a> {
+    pub route: Route,
+    pub builder_pubkey: Option<&'a BlsPublicKey>,
+    pub builder_id: Option<&'a str>,
+    pub proposer_pubkey: Option<&'a BlsPublicKey>,
+    pub proposer_pool: Option<&'a str>,
+}
+
+impl<'a> KillSwitchTarget<'a> {
+    pub fn new(route: Route) -> Self {
+        Self {
+            route,
+            builder_pubkey: None,
+            builder_id: None,
+            proposer_pubkey: None,
+            proposer_pool: None,
+        }
+    }
+}
+
+impl KillSwitchScope {
+    pub fn matches(&self, target: &KillSwitchTarget) -> bool {
+        match self {
+            KillSwitchScope::Relay => true,
+            KillSwitchScope::Route(route) => route.includes(target.route),
+            KillSwitchScope::Builder(pubkey) => target.builder_pubkey == Some(pubkey),
+            KillSwitchScope::BuilderId(builder_id) => target.builder_id == Some(builder_id.as_str()),
+            KillSwitchScope::Proposer(pubkey) => target.proposer_pubkey == Some(pubkey),
+            KillSwitchScope::ProposerPool(pool) => target.proposer_pool == Some(pool.as_str()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_matches() {
+        let builder = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        let mut target = KillSwitchTarget::new(Route::SubmitBlock);
+        target.builder_pubkey = Some(&builder);
+        target.builder_id = Some("builder");
+
+        assert!(KillSwitchScope::Relay.matches(&target));
+        assert!(KillSwitchScope::Route(Route::SubmitBlock).matches(&target));
+        assert!(KillSwitchScope::Route(Route::BuilderApi).matches(&target));
+        assert!(!KillSwitchScope::Route(Route::ProposerApi).matches(&target));
+        assert!(KillSwitchScope::Builder(builder).matches(&target));
+        assert!(!KillSwitchScope::Builder(BlsPublicKey::default()).matches(&target));
+        assert!(KillSwitchScope::BuilderId("builder".to_string()).matches(&target));
+        assert!(!KillSwitchScope::Proposer(BlsPublicKey::default()).matches(&target));
+        assert!(!KillSwitchScope::ProposerPool("pool".to_string()).matches(&target));
+    }
+
+    #[test]
+    fn test_update_serde() {
+        let update = KillSwitchUpdate::Enable(KillSwitch {
+            scope: KillSwitchScope::BuilderId("builder".to_string()),
+            reason: "invalid blocks".to_string(),
+            expires_at_ms: Some(1_000),
+        });
+        let json = serde_json::to_value(&update).unwrap();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "action": "enable",
+                "scope": { "type": "builder_id", "value": "builder" },
+                "reason": "invalid blocks",
+                "expires_at_ms": 1000,
+            })
+        );
+        assert_eq!(serde_json::from_value::<KillSwitchUpdate>(json).unwrap(), update);
+
+        let update = KillSwitchUpdate::Disable { scope: KillSwitchScope::Relay };
+        let json = serde_json::to_string(&update).unwrap();
+        assert_eq!(json, r#"{"action":"disable","scope":{"type":"relay"}}"#);
+        assert_eq!(serde_json::from_str::<KillSwitchUpdate>(&json).unwrap(), update);
+    }
+}
@@ -1,9 +1,11 @@
 pub mod api;
+pub mod bid_history;
 pub mod bid_submission;
 pub mod builder_info;
 pub mod chain_info;
 pub mod config;
 pub mod eth;
+pub mod kill_switch;
 pub mod pending_block;
 pub mod proposer;
 pub mod signing;
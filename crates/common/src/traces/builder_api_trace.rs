@@ -1,6 +1,8 @@
 #[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
 pub struct SubmissionTrace {
     pub receive: u64,
+    /// Size of the request body as received, before decompression.
+    pub received_bytes: u64,
     pub decode: u64,
     pub floor_bid_checks: u64,
     pub pre_checks: u64,
@@ -13,6 +15,8 @@ pub struct SubmissionTrace {
 #[derive(Clone, Default, Debug, serde::Serialize, serde::Deserialize)]
 pub struct HeaderSubmissionTrace {
     pub receive: u64,
+    /// Size of the request body as received, before decompression.
+    pub received_bytes: u64,
     pub decode: u64,
     pub pre_checks: u64,
     pub signature: u64,
@@ -21,4 +21,7 @@ pub struct GetPayloadTrace {
     pub beacon_client_broadcast: u64,
     pub broadcaster_block_broadcast: u64,
     pub on_deliver_payload: u64,
+    /// Builder-specs v2 request, answered without the unblinded payload.
+    #[serde(default)]
+    pub v2: bool,
 }
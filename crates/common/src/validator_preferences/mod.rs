@@ -1,3 +1,5 @@
+use ethereum_consensus::primitives::U256;
+
 #[derive(Debug, Clone, Default, serde::Serialize, serde::Deserialize)]
 pub struct ValidatorPreferences {
     /// Apply filtering to the beacon block submissions.
@@ -9,6 +11,11 @@ pub struct ValidatorPreferences {
     #[serde(default)]
     pub trusted_builders: Option<Vec<String>>,
 
+    /// An optional list of BuilderIDs whose submissions the relay will reject for this proposer,
+    /// while accepting all other builders.
+    #[serde(default)]
+    pub blocked_builders: Option<Vec<String>>,
+
     /// Allows validators to express a preference for whether a delay should be applied to get
     /// headers or not.
     #[serde(default = "default_header_delay")]
@@ -16,6 +23,11 @@ pub struct ValidatorPreferences {
 
     #[serde(default)]
     pub gossip_blobs: bool,
+
+    /// Minimum bid value in wei. `get_header` returns no bid while the best bid is below it, so
+    /// the proposer builds locally.
+    #[serde(default)]
+    pub min_bid_value: Option<U256>,
 }
 
 fn default_filtering() -> Filtering {
@@ -58,6 +70,10 @@ pub struct BuilderValidatorPreferences {
     pub censoring: bool,
     pub filtering: Filtering,
     pub trusted_builders: Option<Vec<String>>,
+    #[serde(default)]
+    pub blocked_builders: Option<Vec<String>>,
+    #[serde(default)]
+    pub min_bid_value: Option<U256>,
 }
 
 impl From<ValidatorPreferences> for BuilderValidatorPreferences {
@@ -66,6 +82,8 @@ impl From<ValidatorPreferences> for BuilderValidatorPreferences {
             censoring: preferences.filtering.is_regional(),
             filtering: preferences.filtering,
             trusted_builders: preferences.trusted_builders.clone(),
+            blocked_builders: preferences.blocked_builders.clone(),
+            min_bid_value: preferences.min_bid_value,
         }
     }
 }
@@ -75,12 +93,16 @@ fn test_validator_preferences_serde() {
     let preferences = ValidatorPreferences {
         filtering: Filtering::Regional,
         trusted_builders: Some(vec!["builder1".to_string(), "builder2".to_string()]),
+        blocked_builders: Some(vec!["builder3".to_string()]),
         header_delay: false,
         gossip_blobs: true,
+        min_bid_value: Some(U256::from(1_000_000_000u64)),
     };
 
     let json = serde_json::to_string(&preferences).unwrap();
-    let _deserialized: ValidatorPreferences = serde_json::from_str(&json).unwrap();
+    let deserialized: ValidatorPreferences = serde_json::from_str(&json).unwrap();
+    assert_eq!(deserialized.blocked_builders, preferences.blocked_builders);
+    assert_eq!(deserialized.min_bid_value, preferences.min_bid_value);
 
     println!("{}", json);
 }
\ No newline at end of file
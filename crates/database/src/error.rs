@@ -10,6 +10,12 @@ pub enum DatabaseError {
     #[error("PostgresDB error: {0}")]
     Postgres(#[from] tokio_postgres::Error),
 
+    #[error("SQLite error: {0}")]
+    Sqlite(#[from] rusqlite::Error),
+
+    #[error("Database task failed: {0}")]
+    TaskJoin(#[from] tokio::task::JoinError),
+
     #[error("Validator registration not found")]
     ValidatorRegistrationNotFound,
 
@@ -1,6 +1,7 @@
 pub mod error;
 pub mod mock_database_service;
 pub mod postgres;
+pub mod sqlite;
 pub mod traits;
 pub mod types;
 
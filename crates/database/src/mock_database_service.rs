@@ -1,6 +1,7 @@
 use std::{
     collections::HashSet,
     sync::{Arc, Mutex},
+    time::SystemTime,
 };
 
 use async_trait::async_trait;
@@ -10,29 +11,35 @@ use ethereum_consensus::{
 };
 use helix_common::{
     api::{
-        builder_api::BuilderGetValidatorsResponseEntry, data_api::BidFilters,
+        builder_api::BuilderGetValidatorsResponseEntry,
+        data_api::{BidFilters, BuilderStatsParams},
         proposer_api::ValidatorRegistrationInfo,
     },
+    bid_history::BidHistoryEntry,
     bid_submission::{
         v2::header_submission::SignedHeaderSubmission, BidTrace, SignedBidSubmission,
     },
     deneb::SignedValidatorRegistration,
+    kill_switch::KillSwitchUpdate,
     simulator::BlockSimError,
     versioned_payload::PayloadAndBlobs,
     BuilderInfo, GetHeaderTrace, GetPayloadTrace, GossipedHeaderTrace, GossipedPayloadTrace,
-    HeaderSubmissionTrace, ProposerInfo, SignedValidatorRegistrationEntry, SubmissionTrace,
-    ValidatorPreferences, ValidatorSummary,
+    HeaderSubmissionTrace, ProposerInfo, RetentionTable, SignedValidatorRegistrationEntry,
+    SubmissionTrace, ValidatorPreferences, ValidatorSummary,
 };
 
 use crate::{
     error::DatabaseError, BidSubmissionDocument, BuilderInfoDocument, DatabaseService,
-    DeliveredPayloadDocument,
+    BlockTraceEventDocument, BuilderStatsDocument, DeliveredPayloadDocument,
+    FailedGetPayloadDocument, GetHeaderCallDocument, TooLateGetPayloadDocument,
+    ValidatorRegistrationHistoryDocument,
 };
 
 #[derive(Default, Clone)]
 pub struct MockDatabaseService {
     known_validators: Arc<Mutex<Vec<ValidatorSummary>>>,
     proposer_duties: Arc<Mutex<Vec<BuilderGetValidatorsResponseEntry>>>,
+    delivered_payload_traces: Arc<Mutex<Vec<GetPayloadTrace>>>,
 }
 
 impl MockDatabaseService {
@@ -40,7 +47,12 @@ impl MockDatabaseService {
         known_validators: Arc<Mutex<Vec<ValidatorSummary>>>,
         proposer_duties: Arc<Mutex<Vec<BuilderGetValidatorsResponseEntry>>>,
     ) -> Self {
-        Self { known_validators, proposer_duties }
+        Self { known_validators, proposer_duties, delivered_payload_traces: Default::default() }
+    }
+
+    /// Latency traces of the payloads saved through `save_delivered_payload`.
+    pub fn delivered_payload_traces(&self) -> Vec<GetPayloadTrace> {
+        self.delivered_payload_traces.lock().unwrap().clone()
     }
 }
 
@@ -90,6 +102,14 @@ impl DatabaseService for MockDatabaseService {
         Ok(0)
     }
 
+    async fn get_validator_registration_history(
+        &self,
+        _pub_key: BlsPublicKey,
+        _limit: Option<u64>,
+    ) -> Result<Vec<ValidatorRegistrationHistoryDocument>, DatabaseError> {
+        Ok(vec![])
+    }
+
     async fn set_proposer_duties(
         &self,
         proposer_duties: Vec<BuilderGetValidatorsResponseEntry>,
@@ -139,8 +159,9 @@ impl DatabaseService for MockDatabaseService {
         &self,
         _bid_trace: &BidTrace,
         _payload: Arc<PayloadAndBlobs>,
-        _latency_trace: &GetPayloadTrace,
+        latency_trace: &GetPayloadTrace,
     ) -> Result<(), DatabaseError> {
+        self.delivered_payload_traces.lock().unwrap().push(latency_trace.clone());
         Ok(())
     }
 
@@ -180,6 +201,20 @@ impl DatabaseService for MockDatabaseService {
         }
     }
 
+    async fn get_builder_infos_by_api_key(
+        &self,
+        api_key: &str,
+    ) -> Result<Vec<BuilderInfoDocument>, DatabaseError> {
+        if api_key == "valid" {
+            Ok(vec![BuilderInfoDocument {
+                pub_key: BlsPublicKey::default(),
+                builder_info: BuilderInfo::default(),
+            }])
+        } else {
+            Ok(vec![])
+        }
+    }
+
     async fn db_demote_builder(
         &self,
         _builder_pub_key: &BlsPublicKey,
@@ -225,6 +260,7 @@ impl DatabaseService for MockDatabaseService {
         _best_block_hash: ByteVector<32>,
         _trace: GetHeaderTrace,
         _user_agent: Option<String>,
+        _bid_history: Vec<BidHistoryEntry>,
     ) -> Result<(), DatabaseError> {
         Ok(())
     }
@@ -238,6 +274,57 @@ impl DatabaseService for MockDatabaseService {
     ) -> Result<(), DatabaseError> {
         Ok(())
     }
+    async fn save_kill_switch_update(
+        &self,
+        _update: &KillSwitchUpdate,
+    ) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+    async fn get_get_header_calls(
+        &self,
+        _filters: &BidFilters,
+    ) -> Result<Vec<GetHeaderCallDocument>, DatabaseError> {
+        Ok(vec![])
+    }
+    async fn get_failed_get_payloads(
+        &self,
+        _filters: &BidFilters,
+    ) -> Result<Vec<FailedGetPayloadDocument>, DatabaseError> {
+        Ok(vec![])
+    }
+    async fn get_too_late_get_payloads(
+        &self,
+        _filters: &BidFilters,
+    ) -> Result<Vec<TooLateGetPayloadDocument>, DatabaseError> {
+        Ok(vec![])
+    }
+    async fn get_block_trace(
+        &self,
+        _block_hash: &Hash32,
+    ) -> Result<Vec<BlockTraceEventDocument>, DatabaseError> {
+        Ok(vec![BlockTraceEventDocument {
+            region_id: 0,
+            source: "submission".to_string(),
+            stage: "receive".to_string(),
+            timestamp: 1,
+        }])
+    }
+    async fn get_builder_stats(
+        &self,
+        _filters: &BuilderStatsParams,
+    ) -> Result<Vec<BuilderStatsDocument>, DatabaseError> {
+        Ok(vec![])
+    }
+    async fn refresh_builder_stats(&self) -> Result<(), DatabaseError> {
+        Ok(())
+    }
+    async fn prune_table(
+        &self,
+        _table: RetentionTable,
+        _cutoff: SystemTime,
+    ) -> Result<u64, DatabaseError> {
+        Ok(0)
+    }
     async fn store_header_submission(
         &self,
         _submission: Arc<SignedHeaderSubmission>,
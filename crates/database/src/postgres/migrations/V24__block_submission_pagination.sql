@@ -0,0 +1,8 @@
+ALTER TABLE block_submission
+ADD COLUMN "id" bigserial;
+
+CREATE UNIQUE INDEX IF NOT EXISTS "block_submission_id" ON "block_submission" ("id");
+CREATE INDEX IF NOT EXISTS "block_submission_slot_number_id" ON "block_submission" ("slot_number" DESC, "id" DESC);
+CREATE INDEX IF NOT EXISTS "block_submission_slot_number_value" ON "block_submission" ("slot_number", "value");
+CREATE INDEX IF NOT EXISTS "block_submission_builder_pubkey" ON "block_submission" ("builder_pubkey");
+CREATE INDEX IF NOT EXISTS "block_submission_first_seen" ON "block_submission" ("first_seen");
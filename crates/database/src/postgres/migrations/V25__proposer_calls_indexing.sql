@@ -0,0 +1,7 @@
+CREATE INDEX IF NOT EXISTS "get_header_slot_number" ON "get_header" ("slot_number");
+CREATE INDEX IF NOT EXISTS "get_header_proposer_pubkey" ON "get_header" ("proposer_pubkey");
+
+CREATE INDEX IF NOT EXISTS "failed_payload_slot_number" ON "failed_payload" ("slot_number");
+CREATE INDEX IF NOT EXISTS "failed_payload_block_hash" ON "failed_payload" ("block_hash");
+
+CREATE INDEX IF NOT EXISTS "late_payload_proposer_pubkey" ON "late_payload" ("proposer_pubkey");
@@ -0,0 +1,5 @@
+CREATE INDEX IF NOT EXISTS "header_submission_trace_block_hash" ON "header_submission_trace" ("block_hash");
+CREATE INDEX IF NOT EXISTS "gossiped_header_trace_block_hash" ON "gossiped_header_trace" ("block_hash");
+CREATE INDEX IF NOT EXISTS "gossiped_payload_trace_block_hash" ON "gossiped_payload_trace" ("block_hash");
+CREATE INDEX IF NOT EXISTS "get_header_trace_block_hash" ON "get_header_trace" ("block_hash");
+CREATE INDEX IF NOT EXISTS "payload_trace_block_hash" ON "payload_trace" ("block_hash");
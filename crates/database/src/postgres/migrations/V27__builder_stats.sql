@@ -0,0 +1,48 @@
+-- Per builder and slot aggregates used by the builder stats data API.
+-- Created empty, the housekeeper populates and refreshes it.
+CREATE MATERIALIZED VIEW IF NOT EXISTS "builder_slot_stats" AS
+SELECT
+    block_submission.builder_pubkey,
+    block_submission.slot_number,
+    MIN(block_submission.first_seen) first_seen,
+    COUNT(*) submissions,
+    COUNT(*) FILTER (
+        WHERE EXISTS (
+            SELECT 1 FROM simulation_error
+            WHERE simulation_error.block_hash = block_submission.block_hash
+        )
+    ) simulation_failures,
+    COUNT(*) FILTER (
+        WHERE EXISTS (
+            SELECT 1 FROM submission_trace
+            WHERE submission_trace.block_hash = block_submission.block_hash
+            AND submission_trace.optimistic_version > 0
+        )
+    ) optimistic_submissions,
+    COUNT(*) FILTER (
+        WHERE EXISTS (
+            SELECT 1 FROM failed_payload
+            WHERE failed_payload.block_hash = block_submission.block_hash
+        )
+    ) failed_payloads,
+    BOOL_OR(
+        EXISTS (
+            SELECT 1 FROM delivered_payload
+            WHERE delivered_payload.block_hash = block_submission.block_hash
+        )
+    ) won,
+    COALESCE(SUM(block_submission.value), 0) total_value,
+    COALESCE(MAX(block_submission.value), 0) max_value
+FROM
+    block_submission
+WHERE
+    block_submission.builder_pubkey IS NOT NULL AND block_submission.slot_number IS NOT NULL
+GROUP BY
+    block_submission.builder_pubkey, block_submission.slot_number
+WITH NO DATA;
+
+-- Required to refresh the view concurrently.
+CREATE UNIQUE INDEX IF NOT EXISTS "builder_slot_stats_builder_pubkey_slot_number" ON "builder_slot_stats" ("builder_pubkey", "slot_number");
+CREATE INDEX IF NOT EXISTS "builder_slot_stats_first_seen" ON "builder_slot_stats" ("first_seen");
+
+CREATE INDEX IF NOT EXISTS "demotions_public_key" ON "demotions" ("public_key");
@@ -0,0 +1,3 @@
+CREATE INDEX IF NOT EXISTS "block_submission_created_at" ON "block_submission" ("created_at");
+CREATE INDEX IF NOT EXISTS "header_submission_created_at" ON "header_submission" ("created_at");
+CREATE INDEX IF NOT EXISTS "get_header_created_at" ON "get_header" ("created_at");
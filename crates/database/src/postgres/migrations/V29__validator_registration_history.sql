@@ -0,0 +1,19 @@
+-- Every change of a validator's fee recipient, gas limit or preferences, including the first
+-- registration seen. Written alongside the `validator_registrations` upsert.
+CREATE TABLE IF NOT EXISTS "validator_registration_history" (
+  "id" bigserial PRIMARY KEY,
+  "public_key" bytea NOT NULL,
+  "fee_recipient" bytea NOT NULL,
+  "previous_fee_recipient" bytea,
+  "gas_limit" integer NOT NULL,
+  "timestamp" bigint NOT NULL,
+  "filtering" smallint NOT NULL,
+  "trusted_builders" varchar[],
+  "header_delay" boolean NOT NULL,
+  "gossip_blobs" boolean NOT NULL,
+  "pool_name" varchar,
+  "inserted_at" timestamptz NOT NULL DEFAULT (now())
+);
+
+CREATE INDEX IF NOT EXISTS "validator_registration_history_public_key"
+ON "validator_registration_history" ("public_key", "inserted_at" DESC);
@@ -0,0 +1,2 @@
+-- Set for builder-specs v2 get_payload requests, which are answered without the payload.
+ALTER TABLE payload_trace ADD COLUMN v2 boolean NOT NULL DEFAULT false;
@@ -0,0 +1,5 @@
+ALTER TABLE validator_preferences
+ADD COLUMN "min_bid_value" numeric;
+
+ALTER TABLE validator_registration_history
+ADD COLUMN "min_bid_value" numeric;
@@ -0,0 +1,5 @@
+ALTER TABLE validator_preferences
+ADD COLUMN "blocked_builders" varchar[];
+
+ALTER TABLE validator_registration_history
+ADD COLUMN "blocked_builders" varchar[];
@@ -0,0 +1,16 @@
+CREATE TABLE "get_header_bid_history" (
+    "slot_number" integer,
+    "region_id" smallint,
+    "parent_hash" bytea,
+    "proposer_pubkey" bytea,
+    "served_block_hash" bytea,
+    "builder_pubkey" bytea,
+    "block_hash" bytea,
+    "value" numeric,
+    "received_at" bigint,
+    "created_at" timestamptz DEFAULT (now())
+);
+
+ALTER TABLE "get_header_bid_history" ADD FOREIGN KEY ("region_id") REFERENCES "region" ("id");
+
+CREATE INDEX "get_header_bid_history_slot_number" ON "get_header_bid_history" ("slot_number");
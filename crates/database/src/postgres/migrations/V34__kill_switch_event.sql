@@ -0,0 +1,10 @@
+CREATE TABLE "kill_switch_event" (
+    "region_id" smallint,
+    "action" varchar,
+    "scope" varchar,
+    "reason" varchar,
+    "expires_at_ms" bigint,
+    "created_at" timestamptz DEFAULT (now())
+);
+
+ALTER TABLE "kill_switch_event" ADD FOREIGN KEY ("region_id") REFERENCES "region" ("id");
@@ -0,0 +1,4 @@
+ALTER TABLE builder_info
+ADD COLUMN "api_key_required" boolean NOT NULL DEFAULT false,
+ADD COLUMN "max_submissions_per_second" bigint,
+ADD COLUMN "max_submission_bytes_per_slot" bigint;
@@ -0,0 +1 @@
+CREATE INDEX IF NOT EXISTS "get_header_bid_history_created_at" ON "get_header_bid_history" ("created_at");
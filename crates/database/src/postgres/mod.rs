@@ -1,7 +1,8 @@
-mod postgres_db_filters;
+pub(crate) mod postgres_db_filters;
 #[allow(unused)]
 mod postgres_db_init;
-mod postgres_db_row_parsing;
+pub(crate) mod postgres_db_row_parsing;
 pub mod postgres_db_service;
 mod postgres_db_service_tests;
+pub mod postgres_db_submission_writer;
 mod postgres_db_u256_parsing;
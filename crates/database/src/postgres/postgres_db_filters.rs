@@ -1,4 +1,4 @@
-use helix_common::api::data_api::BidFilters;
+use helix_common::api::data_api::{BidFilters, OrderBy};
 
 #[derive(Clone, Debug)]
 pub struct PgBidFilters(BidFilters);
@@ -40,8 +40,42 @@ impl PgBidFilters {
         self.0.block_hash.as_ref().map(|block_hash| block_hash.as_ref())
     }
 
-    pub fn order(&self) -> Option<i32> {
-        self.0.order_by.map(|order_by| order_by as i32)
+    pub fn cursor_id(&self) -> Option<i64> {
+        self.0.cursor_id.map(|cursor_id| cursor_id as i64)
+    }
+
+    /// Lower bound of `first_seen`, converted from milliseconds to nanoseconds.
+    pub fn received_at_from(&self) -> Option<i64> {
+        self.0.received_at_from.map(|ms| (ms as i64).saturating_mul(1_000_000))
+    }
+
+    /// Upper bound of `first_seen`, converted from milliseconds to nanoseconds.
+    pub fn received_at_to(&self) -> Option<i64> {
+        self.0.received_at_to.map(|ms| (ms as i64).saturating_mul(1_000_000))
+    }
+
+    pub fn order(&self) -> Option<OrderBy> {
+        self.0.order_by
+    }
+
+    /// `ORDER BY` clause for `block_submission` queries. Ties are broken on the submission id so
+    /// that results are deterministic across pages.
+    pub fn bids_order_clause(&self) -> &'static str {
+        match self.0.order_by {
+            Some(OrderBy::ValueAsc) => {
+                " ORDER BY block_submission.value ASC, block_submission.id DESC"
+            }
+            Some(OrderBy::ValueDesc) => {
+                " ORDER BY block_submission.value DESC, block_submission.id DESC"
+            }
+            Some(OrderBy::TimestampAsc) => {
+                " ORDER BY submission_timestamp ASC, block_submission.id ASC"
+            }
+            Some(OrderBy::TimestampDesc) => {
+                " ORDER BY submission_timestamp DESC, block_submission.id DESC"
+            }
+            None => " ORDER BY block_submission.slot_number DESC, block_submission.id DESC",
+        }
     }
 
     pub fn limit(&self) -> Option<i64> {
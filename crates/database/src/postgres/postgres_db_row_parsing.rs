@@ -8,14 +8,16 @@ use helix_common::{
     },
     bellatrix::{ByteList, ByteVector, List},
     bid_submission::BidTrace,
-    BuilderInfo, Filtering, GetPayloadTrace, ProposerInfo, SignedValidatorRegistrationEntry,
-    ValidatorPreferences,
+    hash_api_key, BuilderInfo, Filtering, GetPayloadTrace, ProposerInfo,
+    SignedValidatorRegistrationEntry, ValidatorPreferences,
 };
 use thiserror::Error;
 
 use crate::{
     error::DatabaseError, postgres::postgres_db_u256_parsing::PostgresNumeric,
-    BidSubmissionDocument, BuilderInfoDocument, DeliveredPayloadDocument,
+    BidSubmissionDocument, BlockTraceEventDocument, BuilderInfoDocument, BuilderStatsDocument,
+    DeliveredPayloadDocument, FailedGetPayloadDocument, GetHeaderCallDocument,
+    TooLateGetPayloadDocument, ValidatorRegistrationHistoryDocument,
 };
 
 #[derive(Debug, Error)]
@@ -79,6 +81,7 @@ impl FromRow for GetPayloadTrace {
                 row.get::<&str, i64>("broadcaster_block_broadcast"),
             )?,
             on_deliver_payload: parse_i64_to_u64(row.get::<&str, i64>("on_deliver_payload"))?,
+            v2: row.get::<&str, bool>("v2"),
         })
     }
 }
@@ -152,6 +155,88 @@ impl FromRow for BidSubmissionDocument {
             },
             num_txs: parse_i32_to_usize(row.get::<&str, i32>("num_txs"))?,
             timestamp: parse_i64_to_u64(row.get::<&str, i64>("submission_timestamp"))?,
+            submission_id: parse_i64_to_u64(row.get::<&str, i64>("submission_id"))?,
+        })
+    }
+}
+
+impl FromRow for GetHeaderCallDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(GetHeaderCallDocument {
+            slot: parse_i32_to_u64(row.get::<&str, i32>("slot_number"))?,
+            region_id: row.get::<&str, i16>("region_id"),
+            parent_hash: parse_bytes_to_hash::<32>(row.get::<&str, &[u8]>("parent_hash"))?,
+            proposer_pub_key: parse_bytes_to_pubkey(row.get::<&str, &[u8]>("proposer_pubkey"))?,
+            block_hash: parse_bytes_to_hash::<32>(row.get::<&str, &[u8]>("block_hash"))?,
+            user_agent: row.get::<&str, Option<&str>>("user_agent").map(|s| s.to_string()),
+            timestamp: parse_timestamptz_to_u64(
+                row.get::<&str, std::time::SystemTime>("created_at"),
+            )?,
+        })
+    }
+}
+
+impl FromRow for FailedGetPayloadDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(FailedGetPayloadDocument {
+            slot: parse_i32_to_u64(row.get::<&str, i32>("slot_number"))?,
+            region_id: row.get::<&str, i16>("region_id"),
+            block_hash: parse_bytes_to_hash::<32>(row.get::<&str, &[u8]>("block_hash"))?,
+            proposer_pub_key: row
+                .get::<&str, Option<&[u8]>>("proposer_pubkey")
+                .map(parse_bytes_to_pubkey)
+                .transpose()?,
+            error: row.get::<&str, Option<&str>>("error").unwrap_or_default().to_string(),
+            timestamp: parse_timestamptz_to_u64(
+                row.get::<&str, std::time::SystemTime>("created_at"),
+            )?,
+        })
+    }
+}
+
+impl FromRow for TooLateGetPayloadDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(TooLateGetPayloadDocument {
+            slot: parse_i32_to_u64(row.get::<&str, i32>("slot_number"))?,
+            region_id: row.get::<&str, i16>("region_id"),
+            proposer_pub_key: row
+                .get::<&str, Option<&[u8]>>("proposer_pubkey")
+                .map(parse_bytes_to_pubkey)
+                .transpose()?,
+            payload_hash: parse_bytes_to_hash::<32>(row.get::<&str, &[u8]>("block_hash"))?,
+            message_received: parse_i64_to_u64(row.get::<&str, i64>("message_received"))?,
+            payload_fetched: parse_i64_to_u64(row.get::<&str, i64>("payload_fetched"))?,
+        })
+    }
+}
+
+impl FromRow for BlockTraceEventDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(BlockTraceEventDocument {
+            region_id: row.get::<&str, i16>("region_id"),
+            source: row.get::<&str, &str>("source").to_string(),
+            stage: row.get::<&str, &str>("stage").to_string(),
+            timestamp: parse_i64_to_u64(row.get::<&str, i64>("timestamp"))?,
+        })
+    }
+}
+
+impl FromRow for BuilderStatsDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(BuilderStatsDocument {
+            builder_pubkey: parse_bytes_to_pubkey(row.get::<&str, &[u8]>("builder_pubkey"))?,
+            builder_id: row.get::<&str, Option<&str>>("builder_id").map(|s| s.to_string()),
+            submissions: parse_i64_to_u64(row.get::<&str, i64>("submissions"))?,
+            slots: parse_i64_to_u64(row.get::<&str, i64>("slots"))?,
+            slots_won: parse_i64_to_u64(row.get::<&str, i64>("slots_won"))?,
+            avg_value: parse_numeric_to_u256(row.get::<&str, PostgresNumeric>("avg_value")),
+            max_value: parse_numeric_to_u256(row.get::<&str, PostgresNumeric>("max_value")),
+            simulation_failures: parse_i64_to_u64(row.get::<&str, i64>("simulation_failures"))?,
+            optimistic_submissions: parse_i64_to_u64(
+                row.get::<&str, i64>("optimistic_submissions"),
+            )?,
+            failed_payloads: parse_i64_to_u64(row.get::<&str, i64>("failed_payloads"))?,
+            demotions: parse_i64_to_u64(row.get::<&str, i64>("demotions"))?,
         })
     }
 }
@@ -184,8 +269,12 @@ impl FromRow for BuilderGetValidatorsResponseEntry {
                                 .collect()
                         },
                     ),
+                    blocked_builders: row.get::<&str, Option<Vec<String>>>("blocked_builders"),
                     header_delay: row.get::<&str, bool>("header_delay"),
                     gossip_blobs: row.get::<&str, bool>("gossip_blobs"),
+                    min_bid_value: row
+                        .get::<&str, Option<PostgresNumeric>>("min_bid_value")
+                        .map(parse_numeric_to_u256),
                 },
             },
         })
@@ -213,6 +302,16 @@ impl FromRow for BuilderInfo {
             collateral: parse_numeric_to_u256(row.get::<&str, PostgresNumeric>("collateral")),
             is_optimistic: parse_bool_to_bool(row.get::<&str, bool>("is_optimistic"))?,
             builder_id: row.get::<&str, Option<&str>>("builder_id").map(|s| s.to_string()),
+            api_key_hash: row.get::<&str, Option<&str>>("api_key").map(hash_api_key),
+            api_key_required: parse_bool_to_bool(row.get::<&str, bool>("api_key_required"))?,
+            max_submissions_per_second: row
+                .get::<&str, Option<i64>>("max_submissions_per_second")
+                .map(parse_i64_to_u64)
+                .transpose()?,
+            max_submission_bytes_per_slot: row
+                .get::<&str, Option<i64>>("max_submission_bytes_per_slot")
+                .map(parse_i64_to_u64)
+                .transpose()?,
         })
     }
 }
@@ -253,8 +352,12 @@ impl FromRow for SignedValidatorRegistrationEntry {
                                 .collect()
                         },
                     ),
+                    blocked_builders: row.get::<&str, Option<Vec<String>>>("blocked_builders"),
                     header_delay: row.get::<&str, bool>("header_delay"),
                     gossip_blobs: row.get::<&str, bool>("gossip_blobs"),
+                    min_bid_value: row
+                        .get::<&str, Option<PostgresNumeric>>("min_bid_value")
+                        .map(parse_numeric_to_u256),
                 },
             },
             inserted_at: parse_timestamptz_to_u64(
@@ -265,6 +368,35 @@ impl FromRow for SignedValidatorRegistrationEntry {
     }
 }
 
+impl FromRow for ValidatorRegistrationHistoryDocument {
+    fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError> {
+        Ok(ValidatorRegistrationHistoryDocument {
+            public_key: parse_bytes_to_pubkey(row.get::<&str, &[u8]>("public_key"))?,
+            fee_recipient: parse_bytes_to_hash::<20>(row.get::<&str, &[u8]>("fee_recipient"))?,
+            previous_fee_recipient: row
+                .get::<&str, Option<&[u8]>>("previous_fee_recipient")
+                .map(parse_bytes_to_hash::<20>)
+                .transpose()?,
+            gas_limit: parse_i32_to_u64(row.get::<&str, i32>("gas_limit"))?,
+            timestamp: parse_i64_to_u64(row.get::<&str, i64>("timestamp"))?,
+            preferences: ValidatorPreferences {
+                filtering: parse_i16_to_filtering(row.get::<&str, i16>("filtering"))?,
+                trusted_builders: row.get::<&str, Option<Vec<String>>>("trusted_builders"),
+                blocked_builders: row.get::<&str, Option<Vec<String>>>("blocked_builders"),
+                header_delay: row.get::<&str, bool>("header_delay"),
+                gossip_blobs: row.get::<&str, bool>("gossip_blobs"),
+                min_bid_value: row
+                    .get::<&str, Option<PostgresNumeric>>("min_bid_value")
+                    .map(parse_numeric_to_u256),
+            },
+            pool_name: row.get::<&str, Option<String>>("pool_name"),
+            inserted_at: parse_timestamptz_to_u64(
+                row.get::<&str, std::time::SystemTime>("inserted_at"),
+            )?,
+        })
+    }
+}
+
 impl FromRow for ProposerInfo {
     fn from_row(row: &tokio_postgres::Row) -> Result<Self, DatabaseError>
     where
@@ -1,32 +1,42 @@
 use std::{
     collections::HashSet,
     ops::DerefMut,
-    sync::Arc,
-    time::{SystemTime, UNIX_EPOCH},
+    sync::{
+        atomic::{AtomicU64, AtomicUsize, Ordering},
+        Arc,
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
 };
 
 use async_trait::async_trait;
 use dashmap::{DashMap, DashSet};
-use deadpool_postgres::{Config, GenericClient, ManagerConfig, Pool, RecyclingMethod};
+use deadpool_postgres::{
+    Config, GenericClient, ManagerConfig, Pool, PoolConfig, RecyclingMethod, Runtime, Timeouts,
+};
 use ethereum_consensus::{altair::Hash32, primitives::BlsPublicKey, ssz::prelude::ByteVector};
 
 use helix_common::{
     api::{
-        builder_api::BuilderGetValidatorsResponseEntry, data_api::BidFilters,
+        builder_api::BuilderGetValidatorsResponseEntry,
+        data_api::{BidFilters, BuilderStatsParams, OrderBy},
         proposer_api::ValidatorRegistrationInfo,
     },
+    bid_history::BidHistoryEntry,
     bid_submission::{
         v2::header_submission::SignedHeaderSubmission, BidSubmission, BidTrace, SignedBidSubmission,
     },
     deneb::SignedValidatorRegistration,
+    kill_switch::KillSwitchUpdate,
     simulator::BlockSimError,
     versioned_payload::PayloadAndBlobs,
     BuilderInfo, Filtering, GetHeaderTrace, GetPayloadTrace, GossipedHeaderTrace,
-    GossipedPayloadTrace, HeaderSubmissionTrace, ProposerInfo, RelayConfig,
-    SignedValidatorRegistrationEntry, SubmissionTrace, ValidatorPreferences, ValidatorSummary,
+    GossipedPayloadTrace, HeaderSubmissionTrace, ProposerInfo, RelayConfig, RetentionTable,
+    SignedValidatorRegistrationEntry, SubmissionTrace, SubmissionWriterConfig,
+    ValidatorPreferences, ValidatorSummary,
 };
-use tokio_postgres::{types::ToSql, NoTls};
-use tracing::{error, info};
+use helix_utils::alert_discord;
+use tokio_postgres::{types::ToSql, NoTls, Row};
+use tracing::{error, info, warn};
 
 use crate::{
     error::DatabaseError,
@@ -34,12 +44,65 @@ use crate::{
         postgres_db_filters::PgBidFilters,
         postgres_db_init::run_migrations_async,
         postgres_db_row_parsing::{parse_bytes_to_pubkey, parse_row, parse_rows},
+        postgres_db_submission_writer::{
+            typed_values_clauses, values_clauses, PendingSubmission, SubmissionQueue,
+            MAX_QUERY_PARAMS,
+        },
         postgres_db_u256_parsing::PostgresNumeric,
     },
-    types::{BidSubmissionDocument, BuilderInfoDocument, DeliveredPayloadDocument},
+    types::{
+        BidSubmissionDocument, BlockTraceEventDocument, BuilderInfoDocument, BuilderStatsDocument,
+        DeliveredPayloadDocument, FailedGetPayloadDocument, GetHeaderCallDocument,
+        SubmissionWriterStats, TooLateGetPayloadDocument, ValidatorRegistrationHistoryDocument,
+    },
     DatabaseService,
 };
 
+/// Column types of the `incoming` VALUES list in the registration history insert.
+const REGISTRATION_HISTORY_TYPES: [&str; 11] = [
+    "bytea",
+    "bytea",
+    "integer",
+    "bigint",
+    "smallint",
+    "varchar[]",
+    "varchar[]",
+    "boolean",
+    "boolean",
+    "numeric",
+    "varchar",
+];
+
+/// Block hashes pruned per `DELETE`, so pruning never holds locks on a large table for long.
+const PRUNE_BATCH_SIZE: i64 = 1_000;
+
+/// Bounds waiting for, opening and recycling a read replica connection, so an unreachable
+/// replica falls back to the primary instead of hanging the request.
+const REPLICA_CONNECTION_TIMEOUT: Duration = Duration::from_secs(2);
+/// How long a replica that failed is skipped before it is tried again.
+const REPLICA_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Read replica pool that is skipped for `REPLICA_BACKOFF` after a failure.
+struct ReplicaPool {
+    pool: Pool,
+    unavailable_until_ms: AtomicU64,
+}
+
+impl ReplicaPool {
+    fn new(pool: Pool) -> Self {
+        Self { pool, unavailable_until_ms: AtomicU64::new(0) }
+    }
+
+    fn is_available(&self, now_ms: u64) -> bool {
+        self.unavailable_until_ms.load(Ordering::Relaxed) <= now_ms
+    }
+
+    fn mark_unavailable(&self, now_ms: u64) {
+        self.unavailable_until_ms
+            .store(now_ms + REPLICA_BACKOFF.as_millis() as u64, Ordering::Relaxed);
+    }
+}
+
 struct RegistrationParams<'a> {
     fee_recipient: &'a [u8],
     gas_limit: i32,
@@ -49,12 +112,43 @@ struct RegistrationParams<'a> {
     inserted_at: SystemTime,
 }
 
+struct BlockSubmissionParams<'a> {
+    block_number: i32,
+    slot_number: i32,
+    parent_hash: &'a [u8],
+    block_hash: &'a [u8],
+    builder_pubkey: &'a [u8],
+    proposer_pubkey: &'a [u8],
+    proposer_fee_recipient: &'a [u8],
+    gas_limit: i32,
+    gas_used: i32,
+    value: PostgresNumeric,
+    num_txs: i32,
+    timestamp: i64,
+    first_seen: i64,
+}
+
+struct SubmissionTraceParams<'a> {
+    block_hash: &'a [u8],
+    optimistic_version: i16,
+    receive: i64,
+    decode: i64,
+    pre_checks: i64,
+    signature: i64,
+    floor_bid_checks: i64,
+    simulation: i64,
+    auctioneer_update: i64,
+    request_finish: i64,
+}
+
 struct PreferenceParams<'a> {
     public_key: &'a [u8],
     filtering: i16,
     trusted_builders: Option<Vec<String>>,
+    blocked_builders: Option<Vec<String>>,
     header_delay: bool,
     gossip_blobs: bool,
+    min_bid_value: Option<PostgresNumeric>,
 }
 
 struct TrustedProposerParams<'a> {
@@ -68,8 +162,15 @@ pub struct PostgresDatabaseService {
     pending_validator_registrations: Arc<DashSet<BlsPublicKey>>,
     known_validators_cache: Arc<DashSet<BlsPublicKey>>,
     validator_pool_cache: Arc<DashMap<String, String>>,
+    submission_queue: Arc<SubmissionQueue>,
     region: i16,
+    region_name: String,
+    /// Discord webhook alerted when a trusted pool validator changes its fee recipient.
+    fee_recipient_alert_webhook: Option<String>,
     pool: Arc<Pool>,
+    /// Pools for data API and analytics reads. Empty if no read replicas are configured.
+    replica_pools: Arc<Vec<ReplicaPool>>,
+    next_replica: Arc<AtomicUsize>,
 }
 
 impl PostgresDatabaseService {
@@ -80,32 +181,101 @@ impl PostgresDatabaseService {
             pending_validator_registrations: Arc::new(DashSet::new()),
             known_validators_cache: Arc::new(DashSet::new()),
             validator_pool_cache: Arc::new(DashMap::new()),
+            submission_queue: Arc::new(SubmissionQueue::default()),
             region,
+            region_name: String::new(),
+            fee_recipient_alert_webhook: None,
             pool: Arc::new(pool),
+            replica_pools: Arc::new(Vec::new()),
+            next_replica: Arc::new(AtomicUsize::new(0)),
         })
     }
 
     pub fn from_relay_config(
         relay_config: &RelayConfig,
     ) -> Result<Self, Box<dyn std::error::Error>> {
-        let mut cfg = Config::new();
-        cfg.host = Some(relay_config.postgres.hostname.clone());
-        cfg.port = Some(relay_config.postgres.port);
-        cfg.dbname = Some(relay_config.postgres.db_name.clone());
-        cfg.user = Some(relay_config.postgres.user.clone());
-        cfg.password = Some(relay_config.postgres.password.clone());
-        cfg.manager = Some(ManagerConfig { recycling_method: RecyclingMethod::Fast });
-        let pool = cfg.create_pool(None, NoTls)?;
+        let pool_config = |hostname: &str, port: u16| {
+            let mut cfg = Config::new();
+            cfg.host = Some(hostname.to_string());
+            cfg.port = Some(port);
+            cfg.dbname = Some(relay_config.postgres.db_name.clone());
+            cfg.user = Some(relay_config.postgres.user.clone());
+            cfg.password = Some(relay_config.postgres.password.clone());
+            cfg.manager = Some(ManagerConfig { recycling_method: RecyclingMethod::Fast });
+            cfg
+        };
+
+        let pool = pool_config(&relay_config.postgres.hostname, relay_config.postgres.port)
+            .create_pool(None, NoTls)?;
+        let replica_pools = relay_config
+            .postgres
+            .read_replicas
+            .iter()
+            .map(|replica| {
+                let mut cfg = pool_config(&replica.hostname, replica.port);
+                cfg.pool = Some(PoolConfig {
+                    timeouts: Timeouts {
+                        wait: Some(REPLICA_CONNECTION_TIMEOUT),
+                        create: Some(REPLICA_CONNECTION_TIMEOUT),
+                        recycle: Some(REPLICA_CONNECTION_TIMEOUT),
+                    },
+                    ..Default::default()
+                });
+                // Timeouts need a runtime
+                cfg.create_pool(Some(Runtime::Tokio1), NoTls).map(ReplicaPool::new)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
         Ok(PostgresDatabaseService {
             validator_registration_cache: Arc::new(DashMap::new()),
             pending_validator_registrations: Arc::new(DashSet::new()),
             known_validators_cache: Arc::new(DashSet::new()),
             validator_pool_cache: Arc::new(DashMap::new()),
+            submission_queue: Arc::new(SubmissionQueue::default()),
             region: relay_config.postgres.region,
+            region_name: relay_config.postgres.region_name.clone(),
+            fee_recipient_alert_webhook: relay_config
+                .discord_webhook_url
+                .clone()
+                .filter(|_| relay_config.alert_pool_fee_recipient_changes),
             pool: Arc::new(pool),
+            replica_pools: Arc::new(replica_pools),
+            next_replica: Arc::new(AtomicUsize::new(0)),
         })
     }
 
+    /// Runs a read-only query on the next available read replica, falling back to the primary if
+    /// no replica is configured or available, or the replica query fails. Replicas that fail are
+    /// skipped for `REPLICA_BACKOFF`. Only for reads that tolerate replication lag.
+    async fn query_replica(
+        &self,
+        query: &str,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<Row>, DatabaseError> {
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as u64;
+        let num_replicas = self.replica_pools.len();
+        let start = self.next_replica.fetch_add(1, Ordering::Relaxed);
+        let next_available = (0..num_replicas)
+            .map(|offset| (start + offset) % num_replicas)
+            .find(|&index| self.replica_pools[index].is_available(now_ms));
+
+        if let Some(index) = next_available {
+            let replica = &self.replica_pools[index];
+            match replica.pool.get().await {
+                Ok(client) => match client.query(query, params).await {
+                    Ok(rows) => return Ok(rows),
+                    Err(err) => warn!(replica = index, %err, "Replica query failed, using primary"),
+                },
+                Err(err) => {
+                    warn!(replica = index, %err, "Replica unavailable, using primary");
+                    replica.mark_unavailable(now_ms);
+                }
+            }
+        }
+
+        Ok(self.pool.get().await?.query(query, params).await?)
+    }
+
     pub async fn run_migrations(&self) {
         let mut conn = self.pool.get().await.unwrap();
         let client = conn.deref_mut().deref_mut();
@@ -203,6 +373,208 @@ impl PostgresDatabaseService {
         });
     }
 
+    /// Starts a task that drains queued block submissions every `flush_interval_ms` and writes
+    /// them with multi-row inserts. Until this is called submissions are written one at a time.
+    /// Failed batches are requeued and retried on the next tick.
+    pub async fn start_submission_writer(&self, config: SubmissionWriterConfig) {
+        if !self.submission_queue.start(config.clone()) {
+            return;
+        }
+
+        let mut interval =
+            tokio::time::interval(std::time::Duration::from_millis(config.flush_interval_ms));
+        let self_clone = self.clone();
+        tokio::spawn(async move {
+            loop {
+                interval.tick().await;
+                loop {
+                    let batch = self_clone.submission_queue.drain(config.max_batch_size);
+                    if batch.is_empty() {
+                        break;
+                    }
+
+                    let start = std::time::Instant::now();
+                    match self_clone._save_block_submissions(&batch).await {
+                        Ok(_) => {
+                            self_clone.submission_queue.record_written(batch.len());
+                            info!(
+                                latency_ms = start.elapsed().as_millis(),
+                                stats = ?self_clone.submission_queue.stats(),
+                                "Saved {} block submissions",
+                                batch.len(),
+                            );
+                        }
+                        Err(e) => {
+                            let batch_len = batch.len();
+                            let requeued = self_clone.submission_queue.requeue_failed(batch);
+                            error!(
+                                requeued,
+                                discarded = batch_len - requeued,
+                                "Error saving block submissions: {}",
+                                e,
+                            );
+                            // Leave the retry for the next tick rather than hammering the db
+                            break;
+                        }
+                    };
+
+                    if batch.len() < config.max_batch_size {
+                        break;
+                    }
+                }
+            }
+        });
+    }
+
+    async fn _save_block_submissions(
+        &self,
+        entries: &[PendingSubmission],
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        let mut client = self.pool.get().await?;
+
+        // A block hash may only appear once per upsert, so keep the earliest sighting of each.
+        // Sorting also keeps the lock order stable across concurrent writers.
+        let mut submissions: Vec<&PendingSubmission> = entries.iter().collect();
+        submissions.sort_by(|a, b| {
+            a.submission
+                .block_hash()
+                .as_ref()
+                .cmp(b.submission.block_hash().as_ref())
+                .then(a.trace.receive.cmp(&b.trace.receive))
+        });
+        submissions.dedup_by(|a, b| a.submission.block_hash() == b.submission.block_hash());
+
+        let structured_params_for_submission: Vec<BlockSubmissionParams> = submissions
+            .iter()
+            .map(|entry| {
+                let submission = &entry.submission;
+                BlockSubmissionParams {
+                    block_number: submission.block_number() as i32,
+                    slot_number: submission.slot() as i32,
+                    parent_hash: submission.parent_hash().as_ref(),
+                    block_hash: submission.block_hash().as_ref(),
+                    builder_pubkey: submission.builder_public_key().as_ref(),
+                    proposer_pubkey: submission.proposer_public_key().as_ref(),
+                    proposer_fee_recipient: submission.proposer_fee_recipient().as_ref(),
+                    gas_limit: submission.gas_limit() as i32,
+                    gas_used: submission.gas_used() as i32,
+                    value: PostgresNumeric::from(submission.value()),
+                    num_txs: submission.transactions().len() as i32,
+                    timestamp: submission.timestamp() as i64,
+                    first_seen: entry.trace.receive as i64,
+                }
+            })
+            .collect();
+
+        let structured_params_for_trace: Vec<SubmissionTraceParams> = entries
+            .iter()
+            .map(|entry| SubmissionTraceParams {
+                block_hash: entry.submission.block_hash().as_ref(),
+                optimistic_version: entry.optimistic_version,
+                receive: entry.trace.receive as i64,
+                decode: entry.trace.decode as i64,
+                pre_checks: entry.trace.pre_checks as i64,
+                signature: entry.trace.signature as i64,
+                floor_bid_checks: entry.trace.floor_bid_checks as i64,
+                simulation: entry.trace.simulation as i64,
+                auctioneer_update: entry.trace.auctioneer_update as i64,
+                request_finish: entry.trace.request_finish as i64,
+            })
+            .collect();
+
+        let structured_params_for_tx: Vec<(&[u8], &[u8])> = entries
+            .iter()
+            .filter(|entry| entry.with_transactions)
+            .flat_map(|entry| {
+                let block_hash = entry.submission.block_hash().as_ref();
+                entry.submission.transactions().iter().map(move |tx| (block_hash, tx.as_ref()))
+            })
+            .collect();
+
+        // COPY can't resolve conflicts, so rows are written with multi-row inserts chunked to
+        // stay under the bind parameter limit.
+        let transaction = client.transaction().await?;
+
+        let num_params_per_row = 13;
+        for chunk in structured_params_for_submission.chunks(MAX_QUERY_PARAMS / num_params_per_row)
+        {
+            let params: Vec<&(dyn ToSql + Sync)> = chunk
+                .iter()
+                .flat_map(|tuple| {
+                    vec![
+                        &tuple.block_number as &(dyn ToSql + Sync),
+                        &tuple.slot_number,
+                        &tuple.parent_hash,
+                        &tuple.block_hash,
+                        &tuple.builder_pubkey,
+                        &tuple.proposer_pubkey,
+                        &tuple.proposer_fee_recipient,
+                        &tuple.gas_limit,
+                        &tuple.gas_used,
+                        &tuple.value,
+                        &tuple.num_txs,
+                        &tuple.timestamp,
+                        &tuple.first_seen,
+                    ]
+                })
+                .collect();
+
+            let sql = format!(
+                "INSERT INTO block_submission (block_number, slot_number, parent_hash, block_hash, builder_pubkey, proposer_pubkey, proposer_fee_recipient, gas_limit, gas_used, value, num_txs, timestamp, first_seen) VALUES {} ON CONFLICT (block_hash) DO UPDATE SET first_seen = LEAST(block_submission.first_seen, excluded.first_seen)",
+                values_clauses(chunk.len(), num_params_per_row),
+            );
+            transaction.execute(&sql, &params[..]).await?;
+        }
+
+        let num_params_per_row = 11;
+        for chunk in structured_params_for_trace.chunks(MAX_QUERY_PARAMS / num_params_per_row) {
+            let params: Vec<&(dyn ToSql + Sync)> = chunk
+                .iter()
+                .flat_map(|tuple| {
+                    vec![
+                        &tuple.block_hash as &(dyn ToSql + Sync),
+                        &region_id,
+                        &tuple.optimistic_version,
+                        &tuple.receive,
+                        &tuple.decode,
+                        &tuple.pre_checks,
+                        &tuple.signature,
+                        &tuple.floor_bid_checks,
+                        &tuple.simulation,
+                        &tuple.auctioneer_update,
+                        &tuple.request_finish,
+                    ]
+                })
+                .collect();
+
+            let sql = format!(
+                "INSERT INTO submission_trace (block_hash, region_id, optimistic_version, receive, decode, pre_checks, signature, floor_bid_checks, simulation, auctioneer_update, request_finish) VALUES {}",
+                values_clauses(chunk.len(), num_params_per_row),
+            );
+            transaction.execute(&sql, &params[..]).await?;
+        }
+
+        let num_params_per_row = 2;
+        for chunk in structured_params_for_tx.chunks(MAX_QUERY_PARAMS / num_params_per_row) {
+            let params: Vec<&(dyn ToSql + Sync)> = chunk
+                .iter()
+                .flat_map(|tuple| {
+                    vec![&tuple.0 as &(dyn ToSql + Sync), &tuple.1 as &(dyn ToSql + Sync)]
+                })
+                .collect();
+
+            let sql = format!(
+                "INSERT INTO transaction (block_hash, bytes) VALUES {} ON CONFLICT (md5(block_hash::text), md5(bytes::text)) DO NOTHING",
+                values_clauses(chunk.len(), num_params_per_row),
+            );
+            transaction.execute(&sql, &params[..]).await?;
+        }
+
+        transaction.commit().await?;
+        Ok(())
+    }
+
     async fn _save_validator_registrations(
         &self,
         entries: &[SignedValidatorRegistrationEntry],
@@ -212,6 +584,8 @@ impl PostgresDatabaseService {
         let mut sorted_entries = entries.to_vec();
         sorted_entries.sort_by(|a, b| a.registration_info.registration.message.public_key.cmp(&b.registration_info.registration.message.public_key));
 
+        let mut fee_recipient_changes = Vec::new();
+
         let batch_size = 10;
         for chunk in sorted_entries.chunks(batch_size) {
             let transaction = client.transaction().await?;
@@ -248,8 +622,14 @@ impl PostgresDatabaseService {
                     public_key: public_key.as_ref(),
                     filtering: entry.registration_info.preferences.filtering as i16,
                     trusted_builders: entry.registration_info.preferences.trusted_builders.clone(),
+                    blocked_builders: entry.registration_info.preferences.blocked_builders.clone(),
                     header_delay: entry.registration_info.preferences.header_delay,
                     gossip_blobs: entry.registration_info.preferences.gossip_blobs,
+                    min_bid_value: entry
+                        .registration_info
+                        .preferences
+                        .min_bid_value
+                        .map(PostgresNumeric::from),
                 });
 
                 if name.is_some() {
@@ -260,6 +640,81 @@ impl PostgresDatabaseService {
                 }
             }
 
+            // Record what changed before the stored registrations are overwritten
+            let params: Vec<&(dyn ToSql + Sync)> = structured_params_for_reg
+                .iter()
+                .zip(structured_params_for_pref.iter())
+                .zip(chunk.iter())
+                .flat_map(|((reg, pref), entry)| {
+                    vec![
+                        &reg.public_key as &(dyn ToSql + Sync),
+                        &reg.fee_recipient,
+                        &reg.gas_limit,
+                        &reg.timestamp,
+                        &pref.filtering,
+                        &pref.trusted_builders,
+                        &pref.blocked_builders,
+                        &pref.header_delay,
+                        &pref.gossip_blobs,
+                        &pref.min_bid_value,
+                        &entry.pool_name,
+                    ]
+                })
+                .collect();
+
+            let sql = format!(
+                "
+                INSERT INTO validator_registration_history
+                    (public_key, fee_recipient, previous_fee_recipient, gas_limit, timestamp, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value, pool_name)
+                SELECT
+                    incoming.public_key,
+                    incoming.fee_recipient,
+                    validator_registrations.fee_recipient,
+                    incoming.gas_limit,
+                    incoming.timestamp,
+                    incoming.filtering,
+                    incoming.trusted_builders,
+                    incoming.blocked_builders,
+                    incoming.header_delay,
+                    incoming.gossip_blobs,
+                    incoming.min_bid_value,
+                    incoming.pool_name
+                FROM (VALUES {}) AS incoming
+                    (public_key, fee_recipient, gas_limit, timestamp, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value, pool_name)
+                LEFT JOIN validator_registrations ON validator_registrations.public_key = incoming.public_key
+                LEFT JOIN validator_preferences ON validator_preferences.public_key = incoming.public_key
+                WHERE validator_registrations.public_key IS NULL
+                    OR validator_registrations.fee_recipient IS DISTINCT FROM incoming.fee_recipient
+                    OR validator_registrations.gas_limit IS DISTINCT FROM incoming.gas_limit
+                    OR validator_preferences.filtering IS DISTINCT FROM incoming.filtering
+                    OR validator_preferences.trusted_builders IS DISTINCT FROM incoming.trusted_builders
+                    OR validator_preferences.blocked_builders IS DISTINCT FROM incoming.blocked_builders
+                    OR validator_preferences.header_delay IS DISTINCT FROM incoming.header_delay
+                    OR validator_preferences.gossip_blobs IS DISTINCT FROM incoming.gossip_blobs
+                    OR validator_preferences.min_bid_value IS DISTINCT FROM incoming.min_bid_value
+                RETURNING public_key, fee_recipient, previous_fee_recipient, pool_name
+            ",
+                typed_values_clauses(chunk.len(), &REGISTRATION_HISTORY_TYPES)
+            );
+
+            for row in transaction.query(&sql, &params[..]).await? {
+                let previous_fee_recipient =
+                    row.get::<&str, Option<&[u8]>>("previous_fee_recipient");
+                let fee_recipient = row.get::<&str, &[u8]>("fee_recipient");
+                if let (Some(pool_name), Some(previous_fee_recipient)) =
+                    (row.get::<&str, Option<String>>("pool_name"), previous_fee_recipient)
+                {
+                    if previous_fee_recipient != fee_recipient {
+                        fee_recipient_changes.push((
+                            row.get::<&str, Vec<u8>>("public_key"),
+                            pool_name,
+                            previous_fee_recipient.to_vec(),
+                            fee_recipient.to_vec(),
+                        ));
+                    }
+                }
+            }
+
             // Prepare the params vector from the structured parameters
             let params: Vec<&(dyn ToSql + Sync)> = structured_params_for_reg
                 .iter()
@@ -301,16 +756,18 @@ impl PostgresDatabaseService {
                         &tuple.public_key as &(dyn ToSql + Sync),
                         &tuple.filtering,
                         &tuple.trusted_builders,
+                        &tuple.blocked_builders,
                         &tuple.header_delay,
                         &tuple.gossip_blobs,
+                        &tuple.min_bid_value,
                     ]
                 })
                 .collect();
 
             // Construct the SQL statement with multiple VALUES clauses
             let mut sql =
-                String::from("INSERT INTO validator_preferences (public_key, filtering, trusted_builders, header_delay, gossip_blobs) VALUES ");
-            let num_params_per_row = 5;
+                String::from("INSERT INTO validator_preferences (public_key, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value) VALUES ");
+            let num_params_per_row = 7;
             let values_clauses: Vec<String> = (0..params.len() / num_params_per_row)
                 .map(|row| {
                     let placeholders: Vec<String> = (1..=num_params_per_row)
@@ -322,7 +779,7 @@ impl PostgresDatabaseService {
 
             // Join the values clauses and append them to the SQL statement
             sql.push_str(&values_clauses.join(", "));
-            sql.push_str(" ON CONFLICT (public_key) DO UPDATE SET filtering = excluded.filtering, trusted_builders = excluded.trusted_builders, header_delay = excluded.header_delay, gossip_blobs = excluded.gossip_blobs");
+            sql.push_str(" ON CONFLICT (public_key) DO UPDATE SET filtering = excluded.filtering, trusted_builders = excluded.trusted_builders, blocked_builders = excluded.blocked_builders, header_delay = excluded.header_delay, gossip_blobs = excluded.gossip_blobs, min_bid_value = excluded.min_bid_value");
 
             // Execute the query
             transaction.execute(&sql, &params[..]).await?;
@@ -359,8 +816,34 @@ impl PostgresDatabaseService {
             transaction.commit().await?;
         }
 
+        for (public_key, pool_name, previous, current) in fee_recipient_changes {
+            self.alert_fee_recipient_change(&public_key, &pool_name, &previous, &current);
+        }
+
         Ok(())
     }
+
+    fn alert_fee_recipient_change(
+        &self,
+        public_key: &[u8],
+        pool_name: &str,
+        previous_fee_recipient: &[u8],
+        fee_recipient: &[u8],
+    ) {
+        let message = format!(
+            "Validator 0x{} of trusted pool {} changed fee recipient from 0x{} to 0x{}",
+            hex::encode(public_key),
+            pool_name,
+            hex::encode(previous_fee_recipient),
+            hex::encode(fee_recipient),
+        );
+        warn!("{message}");
+
+        if let Some(webhook_url) = self.fee_recipient_alert_webhook.clone() {
+            let region_name = self.region_name.clone();
+            tokio::task::spawn_blocking(move || alert_discord(webhook_url, &message, &region_name));
+        }
+    }
 }
 
 impl Default for PostgresDatabaseService {
@@ -380,8 +863,13 @@ impl Default for PostgresDatabaseService {
             pending_validator_registrations: Arc::new(DashSet::new()),
             known_validators_cache: Arc::new(DashSet::new()),
             validator_pool_cache: Arc::new(DashMap::new()),
+            submission_queue: Arc::new(SubmissionQueue::default()),
             region: 1,
+            region_name: String::new(),
+            fee_recipient_alert_webhook: None,
             pool: Arc::new(pool),
+            replica_pools: Arc::new(Vec::new()),
+            next_replica: Arc::new(AtomicUsize::new(0)),
         }
     }
 }
@@ -412,18 +900,20 @@ impl DatabaseService for PostgresDatabaseService {
 
         transaction
             .execute(
-                "INSERT INTO validator_preferences (public_key, filtering, trusted_builders, header_delay, gossip_blobs)
-            VALUES ($1, $2, $3, $4, $5)
+                "INSERT INTO validator_preferences (public_key, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value)
+            VALUES ($1, $2, $3, $4, $5, $6, $7)
             ON CONFLICT (public_key)
             DO UPDATE SET
-                filtering = excluded.filtering, trusted_builders = excluded.trusted_builders, header_delay = excluded.header_delay, gossip_blobs = excluded.gossip_blobs
+                filtering = excluded.filtering, trusted_builders = excluded.trusted_builders, blocked_builders = excluded.blocked_builders, header_delay = excluded.header_delay, gossip_blobs = excluded.gossip_blobs, min_bid_value = excluded.min_bid_value
             ",
                 &[
                     &public_key.as_ref(),
                     &(registration_info.preferences.filtering as i16),
                     &registration_info.preferences.trusted_builders,
+                    &registration_info.preferences.blocked_builders,
                     &registration_info.preferences.header_delay,
                     &registration_info.preferences.gossip_blobs,
+                    &registration_info.preferences.min_bid_value.map(PostgresNumeric::from),
                 ],
             )
             .await?;
@@ -552,8 +1042,10 @@ impl DatabaseService for PostgresDatabaseService {
                     validator_registrations.signature,
                     validator_preferences.filtering,
                     validator_preferences.trusted_builders,
+                    validator_preferences.blocked_builders,
                     validator_preferences.header_delay,
                     validator_preferences.gossip_blobs,
+                    validator_preferences.min_bid_value,
                     validator_registrations.inserted_at
                 FROM validator_registrations
                 INNER JOIN validator_preferences ON validator_registrations.public_key = validator_preferences.public_key
@@ -624,6 +1116,25 @@ impl DatabaseService for PostgresDatabaseService {
         self.get_validator_registration(pub_key).await.map(|entry| entry.inserted_at)
     }
 
+    async fn get_validator_registration_history(
+        &self,
+        pub_key: BlsPublicKey,
+        limit: Option<u64>,
+    ) -> Result<Vec<ValidatorRegistrationHistoryDocument>, DatabaseError> {
+        parse_rows(
+            self.query_replica(
+                "
+                SELECT * FROM validator_registration_history
+                WHERE public_key = $1
+                ORDER BY inserted_at DESC, id DESC
+                LIMIT $2
+            ",
+                &[&(pub_key.as_ref()), &(limit.map(|limit| limit as i64))],
+            )
+            .await?,
+        )
+    }
+
     async fn set_proposer_duties(
         &self,
         proposer_duties: Vec<BuilderGetValidatorsResponseEntry>,
@@ -937,9 +1448,9 @@ impl DatabaseService for PostgresDatabaseService {
         transaction.execute(
             "
                 INSERT INTO payload_trace
-                    (block_hash, region_id, receive, proposer_index_validated, signature_validated, payload_fetched, validation_complete, beacon_client_broadcast, broadcaster_block_broadcast, on_deliver_payload)
+                    (block_hash, region_id, receive, proposer_index_validated, signature_validated, payload_fetched, validation_complete, beacon_client_broadcast, broadcaster_block_broadcast, on_deliver_payload, v2)
                 VALUES
-                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ",
             &[
                 &(bid_trace.block_hash.as_ref()),
@@ -952,6 +1463,7 @@ impl DatabaseService for PostgresDatabaseService {
                 &(latency_trace.beacon_client_broadcast as i64),
                 &(latency_trace.broadcaster_block_broadcast as i64),
                 &(latency_trace.on_deliver_payload as i64),
+                &(latency_trace.v2),
             ],
         ).await?;
 
@@ -1050,6 +1562,13 @@ impl DatabaseService for PostgresDatabaseService {
         trace: Arc<SubmissionTrace>,
         optimistic_version: i16,
     ) -> Result<(), DatabaseError> {
+        // Only written directly while the submission writer isn't running
+        let (submission, trace) =
+            match self.submission_queue.push(submission, trace, optimistic_version) {
+                Ok(()) => return Ok(()),
+                Err(rejected) => rejected,
+            };
+
         let region_id = self.region;
         let mut client = self.pool.get().await?;
         let transaction = client.transaction().await?;
@@ -1108,6 +1627,10 @@ impl DatabaseService for PostgresDatabaseService {
         Ok(())
     }
 
+    fn submission_writer_stats(&self) -> SubmissionWriterStats {
+        self.submission_queue.stats()
+    }
+
     async fn store_builder_info(
         &self,
         builder_pub_key: &BlsPublicKey,
@@ -1173,6 +1696,16 @@ impl DatabaseService for PostgresDatabaseService {
         Ok(!rows.is_empty())
     }
 
+    async fn get_builder_infos_by_api_key(
+        &self,
+        api_key: &str,
+    ) -> Result<Vec<BuilderInfoDocument>, DatabaseError> {
+        let client = self.pool.get().await?;
+        parse_rows(
+            client.query("SELECT * FROM builder_info WHERE api_key = $1", &[&(api_key)]).await?,
+        )
+    }
+
     async fn db_demote_builder(
         &self,
         builder_pub_key: &BlsPublicKey,
@@ -1255,7 +1788,8 @@ impl DatabaseService for PostgresDatabaseService {
                 block_submission.gas_used gas_used,
                 block_submission.value submission_value,
                 block_submission.num_txs num_txs,
-                LEAST(block_submission.first_seen, header_submission.first_seen) submission_timestamp
+                LEAST(block_submission.first_seen, header_submission.first_seen) submission_timestamp,
+                block_submission.id submission_id
             FROM 
                 block_submission
             LEFT JOIN
@@ -1296,10 +1830,61 @@ impl DatabaseService for PostgresDatabaseService {
             param_index += 1;
         }
 
+        // Filter on the same timestamp the response reports as `submission_timestamp`.
+        if let Some(received_at_from) = filters.received_at_from() {
+            query.push_str(&format!(
+                " AND LEAST(block_submission.first_seen, header_submission.first_seen) >= ${}",
+                param_index
+            ));
+            params.push(Box::new(received_at_from));
+            param_index += 1;
+        }
+
+        if let Some(received_at_to) = filters.received_at_to() {
+            query.push_str(&format!(
+                " AND LEAST(block_submission.first_seen, header_submission.first_seen) < ${}",
+                param_index
+            ));
+            params.push(Box::new(received_at_to));
+            param_index += 1;
+        }
+
+        // Keyset pagination on (slot_number, id) so pages stay stable while new bids arrive.
+        match (filters.cursor(), filters.cursor_id()) {
+            (Some(cursor), Some(cursor_id)) => {
+                query.push_str(&format!(
+                    " AND (block_submission.slot_number, block_submission.id) < (${}, ${})",
+                    param_index,
+                    param_index + 1
+                ));
+                params.push(Box::new(cursor));
+                params.push(Box::new(cursor_id));
+                param_index += 2;
+            }
+            (Some(cursor), None) => {
+                query.push_str(&format!(" AND block_submission.slot_number <= ${}", param_index));
+                params.push(Box::new(cursor));
+                param_index += 1;
+            }
+            (None, Some(cursor_id)) => {
+                query.push_str(&format!(" AND block_submission.id < ${}", param_index));
+                params.push(Box::new(cursor_id));
+                param_index += 1;
+            }
+            (None, None) => {}
+        }
+
+        query.push_str(filters.bids_order_clause());
+
+        if let Some(limit) = filters.limit() {
+            query.push_str(&format!(" LIMIT ${}", param_index));
+            params.push(Box::new(limit));
+        }
+
         let params_refs: Vec<&(dyn ToSql + Sync)> =
             params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
 
-        parse_rows(self.pool.get().await?.query(&query, &params_refs[..]).await?)
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
     }
 
     async fn get_delivered_payloads(
@@ -1406,11 +1991,10 @@ impl DatabaseService for PostgresDatabaseService {
             param_index += 1;
         }
 
-        if let Some(order) = filters.order() {
-            query.push_str(" ORDER BY block_submission.value ");
-            query.push_str(if order >= 0 { "ASC" } else { "DESC" });
-        } else {
-            query.push_str(" ORDER BY block_submission.slot_number DESC");
+        match filters.order() {
+            Some(OrderBy::ValueAsc) => query.push_str(" ORDER BY block_submission.value ASC"),
+            Some(OrderBy::ValueDesc) => query.push_str(" ORDER BY block_submission.value DESC"),
+            _ => query.push_str(" ORDER BY block_submission.slot_number DESC"),
         }
 
         if let Some(limit) = filters.limit() {
@@ -1421,7 +2005,7 @@ impl DatabaseService for PostgresDatabaseService {
         let params_refs: Vec<&(dyn ToSql + Sync)> =
             params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
 
-        parse_rows(self.pool.get().await?.query(&query, &params_refs[..]).await?)
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
     }
 
     async fn save_get_header_call(
@@ -1432,6 +2016,7 @@ impl DatabaseService for PostgresDatabaseService {
         best_block_hash: ByteVector<32>,
         trace: GetHeaderTrace,
         user_agent: Option<String>,
+        bid_history: Vec<BidHistoryEntry>,
     ) -> Result<(), DatabaseError> {
         let region_id = self.region;
 
@@ -1475,6 +2060,48 @@ impl DatabaseService for PostgresDatabaseService {
             )
             .await?;
 
+        let slot_number = slot as i32;
+        let parent_hash: &[u8] = parent_hash.as_ref();
+        let proposer_pubkey: &[u8] = public_key.as_ref();
+        let served_block_hash: &[u8] = best_block_hash.as_ref();
+        let structured_params_for_history: Vec<(&[u8], &[u8], PostgresNumeric, i64)> = bid_history
+            .iter()
+            .map(|entry| {
+                (
+                    entry.builder_pubkey.as_ref(),
+                    entry.block_hash.as_ref(),
+                    PostgresNumeric::from(entry.value),
+                    entry.received_at as i64,
+                )
+            })
+            .collect();
+
+        let num_params_per_row = 9;
+        for chunk in structured_params_for_history.chunks(MAX_QUERY_PARAMS / num_params_per_row) {
+            let params: Vec<&(dyn ToSql + Sync)> = chunk
+                .iter()
+                .flat_map(|tuple| {
+                    vec![
+                        &slot_number as &(dyn ToSql + Sync),
+                        &region_id,
+                        &parent_hash,
+                        &proposer_pubkey,
+                        &served_block_hash,
+                        &tuple.0,
+                        &tuple.1,
+                        &tuple.2,
+                        &tuple.3,
+                    ]
+                })
+                .collect();
+
+            let sql = format!(
+                "INSERT INTO get_header_bid_history (slot_number, region_id, parent_hash, proposer_pubkey, served_block_hash, builder_pubkey, block_hash, value, received_at) VALUES {}",
+                values_clauses(chunk.len(), num_params_per_row),
+            );
+            transaction.execute(&sql, &params[..]).await?;
+        }
+
         transaction.commit().await?;
 
         Ok(())
@@ -1507,9 +2134,9 @@ impl DatabaseService for PostgresDatabaseService {
         transaction.execute(
             "
                 INSERT INTO payload_trace
-                    (block_hash, region_id, receive, proposer_index_validated, signature_validated, payload_fetched, validation_complete, beacon_client_broadcast, broadcaster_block_broadcast, on_deliver_payload)
+                    (block_hash, region_id, receive, proposer_index_validated, signature_validated, payload_fetched, validation_complete, beacon_client_broadcast, broadcaster_block_broadcast, on_deliver_payload, v2)
                 VALUES
-                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10)
+                    ($1, $2, $3, $4, $5, $6, $7, $8, $9, $10, $11)
             ",
             &[
                 &(block_hash.as_ref()),
@@ -1522,6 +2149,7 @@ impl DatabaseService for PostgresDatabaseService {
                 &(trace.beacon_client_broadcast as i64),
                 &(trace.broadcaster_block_broadcast as i64),
                 &(trace.on_deliver_payload as i64),
+                &(trace.v2),
             ],
         ).await?;
 
@@ -1530,6 +2158,488 @@ impl DatabaseService for PostgresDatabaseService {
         Ok(())
     }
 
+    async fn save_kill_switch_update(
+        &self,
+        update: &KillSwitchUpdate,
+    ) -> Result<(), DatabaseError> {
+        let (action, reason, expires_at_ms) = match update {
+            KillSwitchUpdate::Enable(kill_switch) => {
+                ("enable", Some(kill_switch.reason.clone()), kill_switch.expires_at_ms)
+            }
+            KillSwitchUpdate::Disable { .. } => ("disable", None, None),
+        };
+        let scope = serde_json::to_string(update.scope())?;
+
+        let client = self.pool.get().await?;
+        client
+            .execute(
+                "
+                    INSERT INTO kill_switch_event
+                        (region_id, action, scope, reason, expires_at_ms)
+                    VALUES
+                        ($1, $2, $3, $4, $5)
+                ",
+                &[
+                    &(self.region),
+                    &(action),
+                    &(scope),
+                    &(reason),
+                    &(expires_at_ms.map(|expires_at_ms| expires_at_ms as i64)),
+                ],
+            )
+            .await?;
+
+        Ok(())
+    }
+
+    async fn get_get_header_calls(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<GetHeaderCallDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+
+        let mut query = String::from(
+            "
+            SELECT
+                slot_number,
+                region_id,
+                parent_hash,
+                proposer_pubkey,
+                block_hash,
+                user_agent,
+                created_at
+            FROM
+                get_header
+            WHERE 1 = 1
+        ",
+        );
+
+        let mut param_index = 1;
+        let mut params: Vec<Box<dyn ToSql + Sync + Send>> = Vec::new();
+
+        if let Some(slot) = filters.slot() {
+            query.push_str(&format!(" AND slot_number = ${}", param_index));
+            params.push(Box::new(slot));
+            param_index += 1;
+        }
+
+        if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+            query.push_str(&format!(" AND proposer_pubkey = ${}", param_index));
+            params.push(Box::new(proposer_pubkey));
+            param_index += 1;
+        }
+
+        query.push_str(" ORDER BY slot_number DESC, created_at DESC");
+
+        if let Some(limit) = filters.limit() {
+            query.push_str(&format!(" LIMIT ${}", param_index));
+            params.push(Box::new(limit));
+        }
+
+        let params_refs: Vec<&(dyn ToSql + Sync)> =
+            params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
+    }
+
+    async fn get_failed_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<FailedGetPayloadDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+
+        // The proposer is only known through the block submission the payload was requested for.
+        let mut query = String::from(
+            "
+            SELECT
+                failed_payload.slot_number,
+                failed_payload.region_id,
+                failed_payload.block_hash,
+                block_submission.proposer_pubkey,
+                failed_payload.error,
+                failed_payload.created_at
+            FROM
+                failed_payload
+            LEFT JOIN
+                block_submission ON failed_payload.block_hash = block_submission.block_hash
+            WHERE failed_payload.slot_number IS NOT NULL
+        ",
+        );
+
+        let mut param_index = 1;
+        let mut params: Vec<Box<dyn ToSql + Sync + Send>> = Vec::new();
+
+        if let Some(slot) = filters.slot() {
+            query.push_str(&format!(" AND failed_payload.slot_number = ${}", param_index));
+            params.push(Box::new(slot));
+            param_index += 1;
+        }
+
+        if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+            query.push_str(&format!(" AND block_submission.proposer_pubkey = ${}", param_index));
+            params.push(Box::new(proposer_pubkey));
+            param_index += 1;
+        }
+
+        query.push_str(" ORDER BY failed_payload.slot_number DESC, failed_payload.created_at DESC");
+
+        if let Some(limit) = filters.limit() {
+            query.push_str(&format!(" LIMIT ${}", param_index));
+            params.push(Box::new(limit));
+        }
+
+        let params_refs: Vec<&(dyn ToSql + Sync)> =
+            params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
+    }
+
+    async fn get_too_late_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<TooLateGetPayloadDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+
+        let mut query = String::from(
+            "
+            SELECT
+                slot_number,
+                region_id,
+                block_hash,
+                proposer_pubkey,
+                message_received,
+                payload_fetched
+            FROM
+                late_payload
+            WHERE 1 = 1
+        ",
+        );
+
+        let mut param_index = 1;
+        let mut params: Vec<Box<dyn ToSql + Sync + Send>> = Vec::new();
+
+        if let Some(slot) = filters.slot() {
+            query.push_str(&format!(" AND slot_number = ${}", param_index));
+            params.push(Box::new(slot));
+            param_index += 1;
+        }
+
+        if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+            query.push_str(&format!(" AND proposer_pubkey = ${}", param_index));
+            params.push(Box::new(proposer_pubkey));
+            param_index += 1;
+        }
+
+        query.push_str(" ORDER BY slot_number DESC, message_received DESC");
+
+        if let Some(limit) = filters.limit() {
+            query.push_str(&format!(" LIMIT ${}", param_index));
+            params.push(Box::new(limit));
+        }
+
+        let params_refs: Vec<&(dyn ToSql + Sync)> =
+            params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
+    }
+
+    async fn get_block_trace(
+        &self,
+        block_hash: &Hash32,
+    ) -> Result<Vec<BlockTraceEventDocument>, DatabaseError> {
+        // Unpivot every trace table into (region_id, source, stage, timestamp) rows.
+        let query = "
+            SELECT region_id, source, stage, timestamp FROM (
+            SELECT region_id, 'submission' AS source, stage, timestamp
+            FROM submission_trace, LATERAL (VALUES
+                ('receive', receive), ('decode', decode), ('pre_checks', pre_checks),
+                ('signature', signature), ('floor_bid_checks', floor_bid_checks),
+                ('simulation', simulation), ('auctioneer_update', auctioneer_update),
+                ('request_finish', request_finish)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            UNION ALL
+            SELECT region_id, 'header_submission' AS source, stage, timestamp
+            FROM header_submission_trace, LATERAL (VALUES
+                ('receive', receive), ('decode', decode), ('pre_checks', pre_checks),
+                ('signature', signature), ('floor_bid_checks', floor_bid_checks),
+                ('auctioneer_update', auctioneer_update), ('request_finish', request_finish)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            UNION ALL
+            SELECT region_id, 'gossiped_header' AS source, stage, timestamp
+            FROM gossiped_header_trace, LATERAL (VALUES
+                ('on_receive', on_receive), ('on_gossip_receive', on_gossip_receive),
+                ('pre_checks', pre_checks), ('auctioneer_update', auctioneer_update)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            UNION ALL
+            SELECT region_id, 'gossiped_payload' AS source, stage, timestamp
+            FROM gossiped_payload_trace, LATERAL (VALUES
+                ('receive', receive), ('pre_checks', pre_checks),
+                ('auctioneer_update', auctioneer_update)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            UNION ALL
+            SELECT region_id, 'get_header' AS source, stage, timestamp
+            FROM get_header_trace, LATERAL (VALUES
+                ('receive', receive), ('validation_complete', validation_complete),
+                ('best_bid_fetched', best_bid_fetched)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            UNION ALL
+            SELECT region_id, 'get_payload' AS source, stage, timestamp
+            FROM payload_trace, LATERAL (VALUES
+                ('receive', receive), ('proposer_index_validated', proposer_index_validated),
+                ('signature_validated', signature_validated),
+                ('payload_fetched', payload_fetched),
+                ('validation_complete', validation_complete),
+                ('beacon_client_broadcast', beacon_client_broadcast),
+                ('broadcaster_block_broadcast', broadcaster_block_broadcast),
+                ('on_deliver_payload', on_deliver_payload)
+            ) AS stages(stage, timestamp)
+            WHERE block_hash = $1
+            ) AS traces
+            -- Stages that were never reached are stored as 0.
+            WHERE timestamp > 0
+            ORDER BY timestamp ASC
+        ";
+
+        parse_rows(self.query_replica(query, &[&(block_hash.as_ref())]).await?)
+    }
+
+    async fn get_builder_stats(
+        &self,
+        filters: &BuilderStatsParams,
+    ) -> Result<Vec<BuilderStatsDocument>, DatabaseError> {
+        let mut param_index = 1;
+        let mut params: Vec<Box<dyn ToSql + Sync + Send>> = Vec::new();
+
+        // Demotion times are stored in milliseconds, `first_seen` in nanoseconds.
+        let mut demotions_filter = String::new();
+        let mut stats_filter = String::new();
+
+        if let Some(received_at_from) = filters.received_at_from {
+            demotions_filter.push_str(&format!(" AND demotions.demotion_time >= ${}", param_index));
+            stats_filter.push_str(&format!(
+                " AND builder_slot_stats.first_seen >= ${}",
+                param_index + 1
+            ));
+            params.push(Box::new(received_at_from as i64));
+            params.push(Box::new((received_at_from as i64).saturating_mul(1_000_000)));
+            param_index += 2;
+        }
+
+        if let Some(received_at_to) = filters.received_at_to {
+            demotions_filter.push_str(&format!(" AND demotions.demotion_time < ${}", param_index));
+            stats_filter.push_str(&format!(
+                " AND builder_slot_stats.first_seen < ${}",
+                param_index + 1
+            ));
+            params.push(Box::new(received_at_to as i64));
+            params.push(Box::new((received_at_to as i64).saturating_mul(1_000_000)));
+            param_index += 2;
+        }
+
+        if let Some(builder_pubkey) = &filters.builder_pubkey {
+            stats_filter
+                .push_str(&format!(" AND builder_slot_stats.builder_pubkey = ${}", param_index));
+            params.push(Box::new(builder_pubkey.as_ref().to_vec()));
+            param_index += 1;
+        }
+
+        if let Some(builder_id) = &filters.builder_id {
+            stats_filter.push_str(&format!(" AND builder_info.builder_id = ${}", param_index));
+            params.push(Box::new(builder_id.clone()));
+            param_index += 1;
+        }
+
+        let mut query = format!(
+            "
+            SELECT
+                builder_slot_stats.builder_pubkey,
+                builder_info.builder_id,
+                SUM(builder_slot_stats.submissions)::bigint submissions,
+                COUNT(*) slots,
+                COUNT(*) FILTER (WHERE builder_slot_stats.won) slots_won,
+                ROUND(SUM(builder_slot_stats.total_value) / SUM(builder_slot_stats.submissions)) avg_value,
+                MAX(builder_slot_stats.max_value) max_value,
+                SUM(builder_slot_stats.simulation_failures)::bigint simulation_failures,
+                SUM(builder_slot_stats.optimistic_submissions)::bigint optimistic_submissions,
+                SUM(builder_slot_stats.failed_payloads)::bigint failed_payloads,
+                (
+                    SELECT COUNT(*) FROM demotions
+                    WHERE demotions.public_key = builder_slot_stats.builder_pubkey{demotions_filter}
+                ) demotions
+            FROM
+                builder_slot_stats
+            LEFT JOIN
+                builder_info ON builder_info.public_key = builder_slot_stats.builder_pubkey
+            WHERE 1 = 1{stats_filter}
+            GROUP BY
+                builder_slot_stats.builder_pubkey, builder_info.builder_id
+            ORDER BY
+                slots_won DESC, submissions DESC
+        "
+        );
+
+        if let Some(limit) = filters.limit {
+            query.push_str(&format!(" LIMIT ${}", param_index));
+            params.push(Box::new(limit as i64));
+        }
+
+        let params_refs: Vec<&(dyn ToSql + Sync)> =
+            params.iter().map(|p| &**p as &(dyn ToSql + Sync)).collect();
+
+        parse_rows(self.query_replica(&query, &params_refs[..]).await?)
+    }
+
+    async fn refresh_builder_stats(&self) -> Result<(), DatabaseError> {
+        let client = self.pool.get().await?;
+
+        // The view is created empty and can only be refreshed concurrently once populated.
+        let is_populated: bool = client
+            .query_one(
+                "SELECT ispopulated FROM pg_matviews WHERE matviewname = 'builder_slot_stats'",
+                &[],
+            )
+            .await?
+            .get("ispopulated");
+
+        if is_populated {
+            client.execute("REFRESH MATERIALIZED VIEW CONCURRENTLY builder_slot_stats", &[]).await?;
+        } else {
+            client.execute("REFRESH MATERIALIZED VIEW builder_slot_stats", &[]).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn prune_table(
+        &self,
+        table: RetentionTable,
+        cutoff: SystemTime,
+    ) -> Result<u64, DatabaseError> {
+        let client = self.pool.get().await?;
+
+        // Each statement deletes the rows of at most `PRUNE_BATCH_SIZE` block hashes.
+        let query = match table {
+            RetentionTable::Transaction => {
+                "
+                DELETE FROM transaction
+                WHERE transaction.block_hash IN (
+                    SELECT block_submission.block_hash FROM block_submission
+                    WHERE block_submission.created_at < $1
+                    AND EXISTS (
+                        SELECT 1 FROM transaction
+                        WHERE transaction.block_hash = block_submission.block_hash
+                    )
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = block_submission.block_hash
+                    )
+                    LIMIT $2
+                )
+            "
+            }
+            RetentionTable::BlockSubmission => {
+                "
+                DELETE FROM block_submission
+                WHERE block_submission.block_hash IN (
+                    SELECT block_submission.block_hash FROM block_submission
+                    WHERE block_submission.created_at < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = block_submission.block_hash
+                    )
+                    LIMIT $2
+                )
+            "
+            }
+            RetentionTable::SubmissionTrace => {
+                // Hypertable partitioned on `receive`, so the outer filter keeps the delete to the
+                // pruned chunks.
+                "
+                DELETE FROM submission_trace
+                WHERE submission_trace.receive < $1
+                AND submission_trace.block_hash IN (
+                    SELECT submission_trace.block_hash FROM submission_trace
+                    WHERE submission_trace.receive < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = submission_trace.block_hash
+                    )
+                    LIMIT $2
+                )
+            "
+            }
+            RetentionTable::HeaderSubmission => {
+                "
+                DELETE FROM header_submission
+                WHERE header_submission.block_hash IN (
+                    SELECT header_submission.block_hash FROM header_submission
+                    WHERE header_submission.created_at < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = header_submission.block_hash
+                    )
+                    LIMIT $2
+                )
+            "
+            }
+            RetentionTable::GetHeader => {
+                "
+                DELETE FROM get_header
+                WHERE get_header.created_at < $1
+                AND get_header.block_hash IN (
+                    SELECT get_header.block_hash FROM get_header
+                    WHERE get_header.created_at < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = get_header.block_hash
+                    )
+                    LIMIT $2
+                )
+            "
+            }
+        };
+
+        // `submission_trace.receive` is stored in nanoseconds.
+        let cutoff_ns = cutoff.duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as i64;
+        let cutoff: &(dyn ToSql + Sync) = match table {
+            RetentionTable::SubmissionTrace => &cutoff_ns,
+            _ => &cutoff,
+        };
+
+        let bid_history_query = matches!(table, RetentionTable::GetHeader).then_some(
+            "
+                DELETE FROM get_header_bid_history
+                WHERE get_header_bid_history.ctid IN (
+                    SELECT get_header_bid_history.ctid FROM get_header_bid_history
+                    WHERE get_header_bid_history.created_at < $1
+                    AND NOT EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = get_header_bid_history.served_block_hash
+                    )
+                    LIMIT $2
+                )
+            ",
+        );
+
+        let mut deleted = 0;
+        for query in std::iter::once(query).chain(bid_history_query) {
+            loop {
+                let batch_deleted = client.execute(query, &[cutoff, &PRUNE_BATCH_SIZE]).await?;
+                if batch_deleted == 0 {
+                    break;
+                }
+                deleted += batch_deleted;
+            }
+        }
+
+        Ok(deleted)
+    }
+
     async fn store_header_submission(
         &self,
         submission: Arc<SignedHeaderSubmission>,
@@ -9,6 +9,7 @@ mod tests {
     };
     use helix_common::{
         bellatrix::{ByteList, ByteVector, List},
+        bid_history::BidHistoryEntry,
         bid_submission::{
             v2::header_submission::{
                 HeaderSubmissionCapella, SignedHeaderSubmission, SignedHeaderSubmissionCapella,
@@ -16,7 +17,8 @@ mod tests {
             BidTrace, SignedBidSubmission,
         },
         versioned_payload::PayloadAndBlobs,
-        Filtering, GetPayloadTrace, HeaderSubmissionTrace, SubmissionTrace, ValidatorSummary,
+        Filtering, GetPayloadTrace, HeaderSubmissionTrace, PostgresConfig, PostgresReplicaConfig,
+        RelayConfig, SubmissionTrace, SubmissionWriterConfig, ValidatorSummary,
     };
     use rand::{seq::SliceRandom, thread_rng, Rng};
     use std::{
@@ -24,7 +26,7 @@ mod tests {
         ops::DerefMut,
         str::FromStr,
         sync::Arc,
-        time::{Duration, SystemTime, UNIX_EPOCH},
+        time::{Duration, Instant, SystemTime, UNIX_EPOCH},
     };
     use tokio::time::sleep;
 
@@ -110,8 +112,10 @@ mod tests {
             preferences: ValidatorPreferences {
                 filtering: Filtering::Global,
                 trusted_builders: Some(vec!["test".to_string(), "test2".to_string()]),
+                blocked_builders: None,
                 header_delay: true,
                 gossip_blobs: true,
+                min_bid_value: None,
             },
         }
     }
@@ -414,6 +418,7 @@ mod tests {
             collateral: U256::from_str("1000000000000000000000000000").unwrap(),
             is_optimistic: false,
             builder_id: None,
+            ..Default::default()
         };
 
         let result = db_service.store_builder_info(&public_key, builder_info).await;
@@ -438,6 +443,7 @@ mod tests {
             collateral: Default::default(),
             is_optimistic: false,
             builder_id: None,
+            ..Default::default()
         };
 
         let result = db_service.store_builder_info(&public_key, builder_info).await;
@@ -497,6 +503,65 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_submission_writer_batches_writes() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 1)?;
+        db_service
+            .start_submission_writer(SubmissionWriterConfig {
+                flush_interval_ms: 50,
+                max_batch_size: 2,
+                ..Default::default()
+            })
+            .await;
+
+        let mut rng = rand::thread_rng();
+        let mut block_hashes = Vec::new();
+        for _ in 0..5 {
+            let random_bytes: [u8; 32] = rng.gen();
+            let block_hash = ByteVector::<32>::try_from(random_bytes.as_slice()).unwrap();
+            let bid_trace =
+                BidTrace { slot: 1236, block_hash: block_hash.clone(), ..Default::default() };
+            let mut signed_bid_submission = SignedBidSubmission::default();
+            match &mut signed_bid_submission {
+                SignedBidSubmission::Deneb(submission) => submission.message = bid_trace,
+                SignedBidSubmission::Capella(submission) => submission.message = bid_trace,
+            }
+            let submission_trace = SubmissionTrace {
+                receive: get_current_unix_time_in_nanos() as u64,
+                ..Default::default()
+            };
+
+            db_service
+                .store_block_submission(
+                    Arc::new(signed_bid_submission),
+                    Arc::new(submission_trace),
+                    0,
+                )
+                .await?;
+            block_hashes.push(block_hash);
+        }
+
+        // Queued, not yet written
+        assert_eq!(db_service.submission_writer_stats().enqueued, 5);
+
+        sleep(Duration::from_millis(500)).await;
+
+        let stats = db_service.submission_writer_stats();
+        assert_eq!(stats.written, 5);
+        assert_eq!(stats.queue_depth, 0);
+        assert_eq!(stats.failed, 0);
+
+        for block_hash in block_hashes {
+            let filters = helix_common::api::data_api::BidFilters {
+                block_hash: Some(block_hash),
+                ..Default::default()
+            };
+            assert_eq!(db_service.get_bids(&filters).await?.len(), 1);
+        }
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_get_bids() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -504,18 +569,55 @@ mod tests {
         let filter = helix_common::api::data_api::BidFilters {
             slot: Some(1234),
             cursor: None,
+            cursor_id: None,
             limit: None,
             block_hash: None,
             block_number: None,
             proposer_pubkey: None,
             builder_pubkey: None,
             order_by: None,
+            received_at_from: None,
+            received_at_to: None,
         };
         let bids = db_service.get_bids(&filter).await?;
         println!("Bids: {:?}", bids);
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_unreachable_replica_uses_primary() -> Result<(), Box<dyn std::error::Error>> {
+        env_logger::builder().is_test(true).try_init()?;
+        let relay_config = RelayConfig {
+            postgres: PostgresConfig {
+                hostname: "localhost".to_string(),
+                port: 5433,
+                db_name: "postgres".to_string(),
+                user: "postgres".to_string(),
+                password: "password".to_string(),
+                // Non-routable, so connecting hangs until the pool's create timeout
+                read_replicas: vec![PostgresReplicaConfig {
+                    hostname: "10.255.255.1".to_string(),
+                    port: 5432,
+                }],
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+        let db_service = PostgresDatabaseService::from_relay_config(&relay_config)?;
+        let filter =
+            helix_common::api::data_api::BidFilters { slot: Some(1234), ..Default::default() };
+
+        let start = Instant::now();
+        db_service.get_bids(&filter).await?;
+        assert!(start.elapsed() < Duration::from_secs(5), "should time out and use the primary");
+
+        // The failed replica is skipped while it backs off
+        let start = Instant::now();
+        db_service.get_bids(&filter).await?;
+        assert!(start.elapsed() < Duration::from_secs(1));
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_save_delivered_payloads() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -593,12 +695,15 @@ mod tests {
         let filter = helix_common::api::data_api::BidFilters {
             slot: None,
             cursor: None,
+            cursor_id: None,
             limit: None,
             block_hash: None,
             block_number: None,
             proposer_pubkey: None,
             builder_pubkey: None,
             order_by: None,
+            received_at_from: None,
+            received_at_to: None,
         };
 
         let validator_preferences = ValidatorPreferences::default();
@@ -623,6 +728,35 @@ mod tests {
         Ok(())
     }
 
+    #[tokio::test]
+    async fn test_late_payloads_without_proposer_pubkey() -> Result<(), Box<dyn std::error::Error>>
+    {
+        env_logger::builder().is_test(true).try_init()?;
+        let db_service = PostgresDatabaseService::new(&test_config(), 0)?;
+        let client = setup_test_conn().await?;
+
+        // Rows logged before the proposer_pubkey column was added have it set to NULL
+        let slot = 123_456_789i32;
+        client
+            .execute(
+                "INSERT INTO late_payload
+                    (block_hash, slot_number, region_id, message_received, payload_fetched)
+                VALUES ($1, $2, 0, 0, 0)",
+                &[&[0u8; 32].as_ref(), &slot],
+            )
+            .await?;
+
+        let filters = helix_common::api::data_api::BidFilters {
+            slot: Some(slot as u64),
+            ..Default::default()
+        };
+        let late_payloads = db_service.get_too_late_get_payloads(&filters).await?;
+        assert!(!late_payloads.is_empty());
+        assert!(late_payloads.iter().all(|late_payload| late_payload.proposer_pub_key.is_none()));
+
+        Ok(())
+    }
+
     #[tokio::test]
     async fn test_get_header() -> Result<(), Box<dyn std::error::Error>> {
         env_logger::builder().is_test(true).try_init()?;
@@ -638,6 +772,7 @@ mod tests {
                 Default::default(),
                 Default::default(),
                 None,
+                vec![BidHistoryEntry { value: U256::from(1), ..Default::default() }],
             )
             .await?;
 
@@ -0,0 +1,261 @@
+use std::{
+    collections::VecDeque,
+    sync::{
+        atomic::{AtomicU64, Ordering},
+        Arc, Mutex, OnceLock,
+    },
+};
+
+use helix_common::{
+    bid_submission::{BidSubmission, SignedBidSubmission},
+    SubmissionTrace, SubmissionWriterConfig,
+};
+
+use crate::types::SubmissionWriterStats;
+
+/// Postgres rejects statements with more bind parameters than this.
+pub(crate) const MAX_QUERY_PARAMS: usize = u16::MAX as usize;
+
+/// A batch that fails this many times is discarded.
+pub(crate) const MAX_WRITE_ATTEMPTS: u8 = 3;
+
+pub(crate) struct PendingSubmission {
+    pub submission: Arc<SignedBidSubmission>,
+    pub trace: Arc<SubmissionTrace>,
+    pub optimistic_version: i16,
+    /// Raw transactions are low priority: the winning bid's are saved with the delivered payload.
+    pub with_transactions: bool,
+    /// Failed writes of the batches this submission was part of.
+    pub attempts: u8,
+}
+
+#[derive(Default)]
+struct SubmissionWriterMetrics {
+    enqueued: AtomicU64,
+    written: AtomicU64,
+    retried: AtomicU64,
+    failed: AtomicU64,
+    dropped: AtomicU64,
+    dropped_transactions: AtomicU64,
+}
+
+/// Queue shared between `store_block_submission` and the submission writer task.
+#[derive(Default)]
+pub(crate) struct SubmissionQueue {
+    config: OnceLock<SubmissionWriterConfig>,
+    pending: Mutex<VecDeque<PendingSubmission>>,
+    metrics: SubmissionWriterMetrics,
+}
+
+impl SubmissionQueue {
+    /// Returns false if the writer was already started.
+    pub fn start(&self, config: SubmissionWriterConfig) -> bool {
+        self.config.set(config).is_ok()
+    }
+
+    /// Queues a submission for the next batch. The submission is handed back if the writer isn't
+    /// running, in which case the caller should write it directly.
+    ///
+    /// Once `queue_capacity` is reached the raw transactions of queued submissions are dropped and
+    /// new submissions are queued without them. Submissions arriving while `max_queue_len` are
+    /// queued are dropped.
+    pub fn push(
+        &self,
+        submission: Arc<SignedBidSubmission>,
+        trace: Arc<SubmissionTrace>,
+        optimistic_version: i16,
+    ) -> Result<(), (Arc<SignedBidSubmission>, Arc<SubmissionTrace>)> {
+        let Some(config) = self.config.get() else { return Err((submission, trace)) };
+
+        let mut pending = self.pending.lock().unwrap();
+        if pending.len() >= config.max_queue_len {
+            self.metrics.dropped.fetch_add(1, Ordering::Relaxed);
+            return Ok(());
+        }
+
+        let mut with_transactions = config.store_transactions;
+        if pending.len() >= config.queue_capacity {
+            let mut dropped = 0;
+            for entry in pending.iter_mut().filter(|entry| entry.with_transactions) {
+                entry.with_transactions = false;
+                dropped += entry.submission.transactions().len() as u64;
+            }
+            if with_transactions {
+                dropped += submission.transactions().len() as u64;
+                with_transactions = false;
+            }
+            self.metrics.dropped_transactions.fetch_add(dropped, Ordering::Relaxed);
+        }
+
+        pending.push_back(PendingSubmission {
+            submission,
+            trace,
+            optimistic_version,
+            with_transactions,
+            attempts: 0,
+        });
+        self.metrics.enqueued.fetch_add(1, Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Takes up to `max` submissions from the front of the queue.
+    pub fn drain(&self, max: usize) -> Vec<PendingSubmission> {
+        let mut pending = self.pending.lock().unwrap();
+        let count = pending.len().min(max);
+        pending.drain(..count).collect()
+    }
+
+    pub fn record_written(&self, count: usize) {
+        self.metrics.written.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    /// Puts the submissions of a failed batch back at the front of the queue, unless they already
+    /// failed `MAX_WRITE_ATTEMPTS` times. Returns the number of submissions requeued.
+    pub fn requeue_failed(&self, batch: Vec<PendingSubmission>) -> usize {
+        let (retry, failed): (Vec<_>, Vec<_>) = batch
+            .into_iter()
+            .map(|entry| PendingSubmission { attempts: entry.attempts + 1, ..entry })
+            .partition(|entry| entry.attempts < MAX_WRITE_ATTEMPTS);
+
+        self.metrics.failed.fetch_add(failed.len() as u64, Ordering::Relaxed);
+        self.metrics.retried.fetch_add(retry.len() as u64, Ordering::Relaxed);
+
+        let requeued = retry.len();
+        let mut pending = self.pending.lock().unwrap();
+        for entry in retry.into_iter().rev() {
+            pending.push_front(entry);
+        }
+        requeued
+    }
+
+    pub fn stats(&self) -> SubmissionWriterStats {
+        SubmissionWriterStats {
+            queue_depth: self.pending.lock().unwrap().len() as u64,
+            enqueued: self.metrics.enqueued.load(Ordering::Relaxed),
+            written: self.metrics.written.load(Ordering::Relaxed),
+            retried: self.metrics.retried.load(Ordering::Relaxed),
+            failed: self.metrics.failed.load(Ordering::Relaxed),
+            dropped: self.metrics.dropped.load(Ordering::Relaxed),
+            dropped_transactions: self.metrics.dropped_transactions.load(Ordering::Relaxed),
+        }
+    }
+}
+
+/// Builds the `($1, $2), ($3, $4), ...` VALUES list for a multi-row insert.
+pub(crate) fn values_clauses(num_rows: usize, num_params_per_row: usize) -> String {
+    (0..num_rows)
+        .map(|row| {
+            let placeholders: Vec<String> = (1..=num_params_per_row)
+                .map(|n| format!("${}", row * num_params_per_row + n))
+                .collect();
+            format!("({})", placeholders.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Like `values_clauses`, with a cast on every placeholder. Needed when the VALUES list is not
+/// the direct source of an INSERT, as Postgres can't infer the parameter types there.
+pub(crate) fn typed_values_clauses(num_rows: usize, types: &[&str]) -> String {
+    (0..num_rows)
+        .map(|row| {
+            let placeholders: Vec<String> = types
+                .iter()
+                .enumerate()
+                .map(|(n, ty)| format!("${}::{}", row * types.len() + n + 1, ty))
+                .collect();
+            format!("({})", placeholders.join(", "))
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn queue(queue_capacity: usize, max_queue_len: usize) -> SubmissionQueue {
+        let queue = SubmissionQueue::default();
+        queue.start(SubmissionWriterConfig {
+            queue_capacity,
+            max_queue_len,
+            store_transactions: true,
+            ..Default::default()
+        });
+        queue
+    }
+
+    fn push(queue: &SubmissionQueue) -> bool {
+        queue.push(Arc::new(SignedBidSubmission::default()), Arc::default(), 0).is_ok()
+    }
+
+    #[test]
+    fn test_push_before_start_is_rejected() {
+        let queue = SubmissionQueue::default();
+        assert!(!push(&queue));
+        assert_eq!(queue.stats(), SubmissionWriterStats::default());
+    }
+
+    #[test]
+    fn test_saturated_queue_sheds_transactions() {
+        let queue = queue(2, 10);
+        assert!(push(&queue));
+        assert!(push(&queue));
+        // Still queued, without transactions, instead of being handed back for a direct write
+        assert!(push(&queue));
+
+        let stats = queue.stats();
+        assert_eq!(stats.queue_depth, 3);
+        assert_eq!(stats.enqueued, 3);
+        assert_eq!(stats.dropped, 0);
+
+        let batch = queue.drain(10);
+        assert_eq!(batch.len(), 3);
+        assert!(batch.iter().all(|entry| !entry.with_transactions));
+        assert_eq!(queue.stats().queue_depth, 0);
+    }
+
+    #[test]
+    fn test_full_queue_drops_submissions() {
+        let queue = queue(1, 2);
+        assert!(push(&queue));
+        assert!(push(&queue));
+        assert!(push(&queue));
+
+        let stats = queue.stats();
+        assert_eq!(stats.queue_depth, 2);
+        assert_eq!(stats.enqueued, 2);
+        assert_eq!(stats.dropped, 1);
+    }
+
+    #[test]
+    fn test_failed_batches_are_retried() {
+        let queue = queue(10, 10);
+        assert!(push(&queue));
+        assert!(push(&queue));
+
+        for attempt in 1..MAX_WRITE_ATTEMPTS {
+            let batch = queue.drain(10);
+            assert_eq!(queue.requeue_failed(batch), 2);
+            let stats = queue.stats();
+            assert_eq!(stats.queue_depth, 2);
+            assert_eq!(stats.retried, 2 * attempt as u64);
+            assert_eq!(stats.failed, 0);
+        }
+
+        let batch = queue.drain(10);
+        assert_eq!(queue.requeue_failed(batch), 0);
+        let stats = queue.stats();
+        assert_eq!(stats.queue_depth, 0);
+        assert_eq!(stats.failed, 2);
+    }
+
+    #[test]
+    fn test_values_clauses() {
+        assert_eq!(values_clauses(2, 3), "($1, $2, $3), ($4, $5, $6)");
+        assert_eq!(
+            typed_values_clauses(2, &["bytea", "integer"]),
+            "($1::bytea, $2::integer), ($3::bytea, $4::integer)"
+        );
+    }
+}
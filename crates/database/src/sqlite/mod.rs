@@ -0,0 +1,3 @@
+mod sqlite_db_row_parsing;
+pub mod sqlite_db_service;
+mod sqlite_db_service_tests;
@@ -0,0 +1,283 @@
+-- Single-node schema mirroring the Postgres migrations. U256 values are stored as 32-byte
+-- big-endian blobs so they compare numerically, timestamps without a unit suffix follow the
+-- Postgres tables and `created_at` columns are unix seconds.
+
+CREATE TABLE IF NOT EXISTS region (
+    id INTEGER PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS validator_registrations (
+    public_key BLOB PRIMARY KEY,
+    fee_recipient BLOB NOT NULL,
+    gas_limit INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    signature BLOB NOT NULL,
+    inserted_at INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS validator_preferences (
+    public_key BLOB PRIMARY KEY,
+    filtering INTEGER NOT NULL,
+    trusted_builders TEXT,
+    blocked_builders TEXT,
+    header_delay INTEGER NOT NULL,
+    gossip_blobs INTEGER NOT NULL,
+    min_bid_value BLOB
+);
+
+CREATE TABLE IF NOT EXISTS validator_registration_history (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    public_key BLOB NOT NULL,
+    fee_recipient BLOB NOT NULL,
+    previous_fee_recipient BLOB,
+    gas_limit INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    filtering INTEGER NOT NULL,
+    trusted_builders TEXT,
+    blocked_builders TEXT,
+    header_delay INTEGER NOT NULL,
+    gossip_blobs INTEGER NOT NULL,
+    min_bid_value BLOB,
+    pool_name TEXT,
+    inserted_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+CREATE INDEX IF NOT EXISTS validator_registration_history_public_key ON validator_registration_history (public_key, inserted_at);
+
+CREATE TABLE IF NOT EXISTS trusted_proposers (
+    pub_key BLOB PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS validator_pools (
+    api_key TEXT PRIMARY KEY,
+    name TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS proposer_duties (
+    slot_number INTEGER PRIMARY KEY,
+    validator_index INTEGER NOT NULL,
+    public_key BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS known_validators (
+    public_key BLOB PRIMARY KEY
+);
+
+CREATE TABLE IF NOT EXISTS block_submission (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    block_number INTEGER NOT NULL,
+    slot_number INTEGER NOT NULL,
+    parent_hash BLOB NOT NULL,
+    block_hash BLOB NOT NULL UNIQUE,
+    builder_pubkey BLOB NOT NULL,
+    proposer_pubkey BLOB NOT NULL,
+    proposer_fee_recipient BLOB NOT NULL,
+    gas_limit INTEGER NOT NULL,
+    gas_used INTEGER NOT NULL,
+    value BLOB NOT NULL,
+    num_txs INTEGER NOT NULL,
+    timestamp INTEGER NOT NULL,
+    first_seen INTEGER NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+CREATE INDEX IF NOT EXISTS block_submission_slot_number ON block_submission (slot_number, id);
+CREATE INDEX IF NOT EXISTS block_submission_builder_pubkey ON block_submission (builder_pubkey);
+CREATE INDEX IF NOT EXISTS block_submission_proposer_pubkey ON block_submission (proposer_pubkey);
+
+CREATE TABLE IF NOT EXISTS "transaction" (
+    block_hash BLOB NOT NULL,
+    bytes BLOB NOT NULL,
+    UNIQUE (block_hash, bytes)
+);
+
+CREATE TABLE IF NOT EXISTS submission_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    optimistic_version INTEGER NOT NULL,
+    receive INTEGER NOT NULL,
+    decode INTEGER NOT NULL,
+    pre_checks INTEGER NOT NULL,
+    signature INTEGER NOT NULL,
+    floor_bid_checks INTEGER NOT NULL,
+    simulation INTEGER NOT NULL,
+    auctioneer_update INTEGER NOT NULL,
+    request_finish INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS submission_trace_block_hash ON submission_trace (block_hash);
+
+CREATE TABLE IF NOT EXISTS header_submission (
+    block_number INTEGER NOT NULL,
+    slot_number INTEGER NOT NULL,
+    parent_hash BLOB NOT NULL,
+    block_hash BLOB NOT NULL UNIQUE,
+    builder_pubkey BLOB NOT NULL,
+    proposer_pubkey BLOB NOT NULL,
+    proposer_fee_recipient BLOB NOT NULL,
+    gas_limit INTEGER NOT NULL,
+    gas_used INTEGER NOT NULL,
+    value BLOB NOT NULL,
+    timestamp INTEGER NOT NULL,
+    first_seen INTEGER NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+
+CREATE TABLE IF NOT EXISTS header_submission_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    receive INTEGER NOT NULL,
+    decode INTEGER NOT NULL,
+    pre_checks INTEGER NOT NULL,
+    signature INTEGER NOT NULL,
+    floor_bid_checks INTEGER NOT NULL,
+    auctioneer_update INTEGER NOT NULL,
+    request_finish INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS header_submission_trace_block_hash ON header_submission_trace (block_hash);
+
+CREATE TABLE IF NOT EXISTS delivered_payload (
+    block_hash BLOB PRIMARY KEY,
+    payload_parent_hash BLOB NOT NULL,
+    fee_recipient BLOB NOT NULL,
+    state_root BLOB NOT NULL,
+    receipts_root BLOB NOT NULL,
+    logs_bloom BLOB NOT NULL,
+    prev_randao BLOB NOT NULL,
+    timestamp INTEGER NOT NULL,
+    block_number INTEGER NOT NULL,
+    gas_limit INTEGER NOT NULL,
+    gas_used INTEGER NOT NULL,
+    extra_data BLOB NOT NULL,
+    base_fee_per_gas BLOB NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS delivered_payload_preferences (
+    block_hash BLOB PRIMARY KEY,
+    filtering INTEGER NOT NULL,
+    trusted_builders TEXT
+);
+
+CREATE TABLE IF NOT EXISTS payload_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    receive INTEGER NOT NULL,
+    proposer_index_validated INTEGER NOT NULL,
+    signature_validated INTEGER NOT NULL,
+    payload_fetched INTEGER NOT NULL,
+    validation_complete INTEGER NOT NULL,
+    beacon_client_broadcast INTEGER NOT NULL,
+    broadcaster_block_broadcast INTEGER NOT NULL,
+    on_deliver_payload INTEGER NOT NULL,
+    v2 INTEGER NOT NULL DEFAULT 0
+);
+CREATE INDEX IF NOT EXISTS payload_trace_block_hash ON payload_trace (block_hash);
+
+CREATE TABLE IF NOT EXISTS withdrawal (
+    "index" INTEGER NOT NULL,
+    block_hash BLOB NOT NULL,
+    validator_index INTEGER NOT NULL,
+    address BLOB NOT NULL,
+    amount INTEGER NOT NULL,
+    PRIMARY KEY ("index", block_hash)
+);
+
+CREATE TABLE IF NOT EXISTS builder_info (
+    public_key BLOB PRIMARY KEY,
+    collateral BLOB NOT NULL,
+    is_optimistic INTEGER NOT NULL,
+    builder_id TEXT,
+    api_key TEXT,
+    api_key_required INTEGER NOT NULL DEFAULT 0,
+    max_submissions_per_second INTEGER,
+    max_submission_bytes_per_slot INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS demotions (
+    public_key BLOB NOT NULL,
+    block_hash BLOB NOT NULL,
+    demotion_time INTEGER NOT NULL,
+    reason TEXT
+);
+CREATE INDEX IF NOT EXISTS demotions_public_key ON demotions (public_key);
+
+CREATE TABLE IF NOT EXISTS simulation_error (
+    block_hash BLOB PRIMARY KEY,
+    error TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS late_payload (
+    block_hash BLOB NOT NULL,
+    slot_number INTEGER NOT NULL,
+    region_id INTEGER NOT NULL,
+    proposer_pubkey BLOB NOT NULL,
+    message_received INTEGER NOT NULL,
+    payload_fetched INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS get_header (
+    slot_number INTEGER NOT NULL,
+    region_id INTEGER NOT NULL,
+    parent_hash BLOB NOT NULL,
+    proposer_pubkey BLOB NOT NULL,
+    block_hash BLOB NOT NULL,
+    user_agent TEXT,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+CREATE INDEX IF NOT EXISTS get_header_slot_number ON get_header (slot_number);
+
+CREATE TABLE IF NOT EXISTS get_header_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    receive INTEGER NOT NULL,
+    validation_complete INTEGER NOT NULL,
+    best_bid_fetched INTEGER NOT NULL
+);
+CREATE INDEX IF NOT EXISTS get_header_trace_block_hash ON get_header_trace (block_hash);
+
+CREATE TABLE IF NOT EXISTS get_header_bid_history (
+    slot_number INTEGER NOT NULL,
+    region_id INTEGER NOT NULL,
+    parent_hash BLOB NOT NULL,
+    proposer_pubkey BLOB NOT NULL,
+    served_block_hash BLOB NOT NULL,
+    builder_pubkey BLOB NOT NULL,
+    block_hash BLOB NOT NULL,
+    value BLOB NOT NULL,
+    received_at INTEGER NOT NULL,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+CREATE INDEX IF NOT EXISTS get_header_bid_history_slot_number ON get_header_bid_history (slot_number);
+
+CREATE TABLE IF NOT EXISTS failed_payload (
+    region_id INTEGER NOT NULL,
+    slot_number INTEGER,
+    block_hash BLOB NOT NULL,
+    error TEXT,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
+
+CREATE TABLE IF NOT EXISTS gossiped_header_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    on_receive INTEGER NOT NULL,
+    on_gossip_receive INTEGER NOT NULL,
+    pre_checks INTEGER NOT NULL,
+    auctioneer_update INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS gossiped_payload_trace (
+    block_hash BLOB NOT NULL,
+    region_id INTEGER NOT NULL,
+    receive INTEGER NOT NULL,
+    pre_checks INTEGER NOT NULL,
+    auctioneer_update INTEGER NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS kill_switch_event (
+    region_id INTEGER NOT NULL,
+    action TEXT NOT NULL,
+    scope TEXT NOT NULL,
+    reason TEXT,
+    expires_at_ms INTEGER,
+    created_at INTEGER NOT NULL DEFAULT (unixepoch())
+);
@@ -0,0 +1,275 @@
+use ethereum_consensus::{
+    builder::{SignedValidatorRegistration, ValidatorRegistration},
+    primitives::U256,
+};
+use helix_common::{
+    api::{
+        builder_api::BuilderGetValidatorsResponseEntry, proposer_api::ValidatorRegistrationInfo,
+    },
+    bid_submission::BidTrace,
+    hash_api_key, BuilderInfo, ProposerInfo, SignedValidatorRegistrationEntry,
+    ValidatorPreferences,
+};
+use rusqlite::Row;
+
+use crate::{
+    error::DatabaseError,
+    postgres::postgres_db_row_parsing::{
+        parse_bytes_to_hash, parse_bytes_to_pubkey, parse_bytes_to_signature,
+        parse_i16_to_filtering, parse_i64_to_u64,
+    },
+    BidSubmissionDocument, BlockTraceEventDocument, BuilderInfoDocument, DeliveredPayloadDocument,
+    FailedGetPayloadDocument, GetHeaderCallDocument, TooLateGetPayloadDocument,
+    ValidatorRegistrationHistoryDocument,
+};
+
+pub trait FromRow {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError>
+    where
+        Self: Sized;
+}
+
+impl FromRow for BidTrace {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BidTrace {
+            slot: parse_i64_to_u64(row.get("slot_number")?)?,
+            parent_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("parent_hash")?)?,
+            block_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("block_hash")?)?,
+            builder_public_key: parse_bytes_to_pubkey(
+                &row.get::<_, Vec<u8>>("builder_public_key")?,
+            )?,
+            proposer_public_key: parse_bytes_to_pubkey(
+                &row.get::<_, Vec<u8>>("proposer_public_key")?,
+            )?,
+            proposer_fee_recipient: parse_bytes_to_hash::<20>(
+                &row.get::<_, Vec<u8>>("proposer_fee_recipient")?,
+            )?,
+            gas_limit: parse_i64_to_u64(row.get("gas_limit")?)?,
+            gas_used: parse_i64_to_u64(row.get("gas_used")?)?,
+            value: parse_blob_to_u256(&row.get::<_, Vec<u8>>("submission_value")?)?,
+        })
+    }
+}
+
+impl FromRow for DeliveredPayloadDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(DeliveredPayloadDocument {
+            bid_trace: BidTrace::from_row(row)?,
+            block_number: parse_i64_to_u64(row.get("block_number")?)?,
+            num_txs: parse_i64_to_u64(row.get("num_txs")?)? as usize,
+        })
+    }
+}
+
+impl FromRow for BidSubmissionDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BidSubmissionDocument {
+            block_number: parse_i64_to_u64(row.get("block_number")?)?,
+            bid_trace: BidTrace::from_row(row)?,
+            num_txs: parse_i64_to_u64(row.get("num_txs")?)? as usize,
+            timestamp: parse_i64_to_u64(row.get("submission_timestamp")?)?,
+            submission_id: parse_i64_to_u64(row.get("submission_id")?)?,
+        })
+    }
+}
+
+impl FromRow for GetHeaderCallDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(GetHeaderCallDocument {
+            slot: parse_i64_to_u64(row.get("slot_number")?)?,
+            region_id: row.get("region_id")?,
+            parent_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("parent_hash")?)?,
+            proposer_pub_key: parse_bytes_to_pubkey(&row.get::<_, Vec<u8>>("proposer_pubkey")?)?,
+            block_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("block_hash")?)?,
+            user_agent: row.get("user_agent")?,
+            timestamp: parse_i64_to_u64(row.get("created_at")?)?,
+        })
+    }
+}
+
+impl FromRow for FailedGetPayloadDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(FailedGetPayloadDocument {
+            slot: parse_i64_to_u64(row.get("slot_number")?)?,
+            region_id: row.get("region_id")?,
+            block_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("block_hash")?)?,
+            proposer_pub_key: row
+                .get::<_, Option<Vec<u8>>>("proposer_pubkey")?
+                .map(|pubkey| parse_bytes_to_pubkey(&pubkey))
+                .transpose()?,
+            error: row.get::<_, Option<String>>("error")?.unwrap_or_default(),
+            timestamp: parse_i64_to_u64(row.get("created_at")?)?,
+        })
+    }
+}
+
+impl FromRow for TooLateGetPayloadDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(TooLateGetPayloadDocument {
+            slot: parse_i64_to_u64(row.get("slot_number")?)?,
+            region_id: row.get("region_id")?,
+            proposer_pub_key: row
+                .get::<_, Option<Vec<u8>>>("proposer_pubkey")?
+                .map(|pubkey| parse_bytes_to_pubkey(&pubkey))
+                .transpose()?,
+            payload_hash: parse_bytes_to_hash::<32>(&row.get::<_, Vec<u8>>("block_hash")?)?,
+            message_received: parse_i64_to_u64(row.get("message_received")?)?,
+            payload_fetched: parse_i64_to_u64(row.get("payload_fetched")?)?,
+        })
+    }
+}
+
+impl FromRow for BlockTraceEventDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BlockTraceEventDocument {
+            region_id: row.get("region_id")?,
+            source: row.get("source")?,
+            stage: row.get("stage")?,
+            timestamp: parse_i64_to_u64(row.get("timestamp")?)?,
+        })
+    }
+}
+
+impl FromRow for BuilderInfo {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BuilderInfo {
+            collateral: parse_blob_to_u256(&row.get::<_, Vec<u8>>("collateral")?)?,
+            is_optimistic: row.get("is_optimistic")?,
+            builder_id: row.get("builder_id")?,
+            api_key_hash: row.get::<_, Option<String>>("api_key")?.map(|key| hash_api_key(&key)),
+            api_key_required: row.get("api_key_required")?,
+            max_submissions_per_second: row
+                .get::<_, Option<i64>>("max_submissions_per_second")?
+                .map(parse_i64_to_u64)
+                .transpose()?,
+            max_submission_bytes_per_slot: row
+                .get::<_, Option<i64>>("max_submission_bytes_per_slot")?
+                .map(parse_i64_to_u64)
+                .transpose()?,
+        })
+    }
+}
+
+impl FromRow for BuilderInfoDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BuilderInfoDocument {
+            pub_key: parse_bytes_to_pubkey(&row.get::<_, Vec<u8>>("public_key")?)?,
+            builder_info: BuilderInfo::from_row(row)?,
+        })
+    }
+}
+
+impl FromRow for ValidatorRegistrationInfo {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(ValidatorRegistrationInfo {
+            registration: SignedValidatorRegistration {
+                message: ValidatorRegistration {
+                    fee_recipient: parse_bytes_to_hash::<20>(
+                        &row.get::<_, Vec<u8>>("fee_recipient")?,
+                    )?,
+                    gas_limit: parse_i64_to_u64(row.get("gas_limit")?)?,
+                    timestamp: parse_i64_to_u64(row.get("timestamp")?)?,
+                    public_key: parse_bytes_to_pubkey(&row.get::<_, Vec<u8>>("public_key")?)?,
+                },
+                signature: parse_bytes_to_signature(&row.get::<_, Vec<u8>>("signature")?)?,
+            },
+            preferences: ValidatorPreferences {
+                filtering: parse_i16_to_filtering(row.get("filtering")?)?,
+                trusted_builders: row
+                    .get::<_, Option<String>>("trusted_builders")?
+                    .map(|builders| serde_json::from_str(&builders))
+                    .transpose()?,
+                blocked_builders: row
+                    .get::<_, Option<String>>("blocked_builders")?
+                    .map(|builders| serde_json::from_str(&builders))
+                    .transpose()?,
+                header_delay: row.get("header_delay")?,
+                gossip_blobs: row.get("gossip_blobs")?,
+                min_bid_value: row
+                    .get::<_, Option<Vec<u8>>>("min_bid_value")?
+                    .map(|value| parse_blob_to_u256(&value))
+                    .transpose()?,
+            },
+        })
+    }
+}
+
+impl FromRow for SignedValidatorRegistrationEntry {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(SignedValidatorRegistrationEntry {
+            registration_info: ValidatorRegistrationInfo::from_row(row)?,
+            inserted_at: parse_i64_to_u64(row.get("inserted_at")?)?,
+            pool_name: None,
+        })
+    }
+}
+
+impl FromRow for ValidatorRegistrationHistoryDocument {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(ValidatorRegistrationHistoryDocument {
+            public_key: parse_bytes_to_pubkey(&row.get::<_, Vec<u8>>("public_key")?)?,
+            fee_recipient: parse_bytes_to_hash::<20>(&row.get::<_, Vec<u8>>("fee_recipient")?)?,
+            previous_fee_recipient: row
+                .get::<_, Option<Vec<u8>>>("previous_fee_recipient")?
+                .map(|fee_recipient| parse_bytes_to_hash::<20>(&fee_recipient))
+                .transpose()?,
+            gas_limit: parse_i64_to_u64(row.get("gas_limit")?)?,
+            timestamp: parse_i64_to_u64(row.get("timestamp")?)?,
+            preferences: ValidatorPreferences {
+                filtering: parse_i16_to_filtering(row.get("filtering")?)?,
+                trusted_builders: row
+                    .get::<_, Option<String>>("trusted_builders")?
+                    .map(|builders| serde_json::from_str(&builders))
+                    .transpose()?,
+                blocked_builders: row
+                    .get::<_, Option<String>>("blocked_builders")?
+                    .map(|builders| serde_json::from_str(&builders))
+                    .transpose()?,
+                header_delay: row.get("header_delay")?,
+                gossip_blobs: row.get("gossip_blobs")?,
+                min_bid_value: row
+                    .get::<_, Option<Vec<u8>>>("min_bid_value")?
+                    .map(|value| parse_blob_to_u256(&value))
+                    .transpose()?,
+            },
+            pool_name: row.get("pool_name")?,
+            inserted_at: parse_i64_to_u64(row.get("inserted_at")?)?,
+        })
+    }
+}
+
+impl FromRow for BuilderGetValidatorsResponseEntry {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(BuilderGetValidatorsResponseEntry {
+            slot: parse_i64_to_u64(row.get("slot_number")?)?,
+            validator_index: parse_i64_to_u64(row.get("validator_index")?)? as usize,
+            entry: ValidatorRegistrationInfo::from_row(row)?,
+        })
+    }
+}
+
+impl FromRow for ProposerInfo {
+    fn from_row(row: &Row) -> Result<Self, DatabaseError> {
+        Ok(ProposerInfo {
+            name: row.get("name")?,
+            pub_key: parse_bytes_to_pubkey(&row.get::<_, Vec<u8>>("pub_key")?)?,
+        })
+    }
+}
+
+/// Values are stored as 32-byte big-endian blobs, which SQLite orders numerically.
+pub fn u256_to_blob(value: U256) -> Vec<u8> {
+    value.to_be_bytes::<32>().to_vec()
+}
+
+pub fn parse_blob_to_u256(bytes: &[u8]) -> Result<U256, DatabaseError> {
+    U256::try_from_be_slice(bytes).ok_or(DatabaseError::GeneralError)
+}
+
+pub fn parse_rows<T: FromRow>(rows: &mut rusqlite::Rows) -> Result<Vec<T>, DatabaseError> {
+    let mut parsed = Vec::new();
+    while let Some(row) = rows.next()? {
+        parsed.push(T::from_row(row)?);
+    }
+    Ok(parsed)
+}
@@ -0,0 +1,1758 @@
+use std::{
+    collections::{hash_map::Entry, HashMap, HashSet},
+    sync::{Arc, Mutex},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use async_trait::async_trait;
+use ethereum_consensus::{
+    primitives::{BlsPublicKey, Hash32, U256},
+    ssz::prelude::ByteVector,
+};
+use helix_common::{
+    api::{
+        builder_api::BuilderGetValidatorsResponseEntry,
+        data_api::{BidFilters, BuilderStatsParams, OrderBy},
+        proposer_api::ValidatorRegistrationInfo,
+    },
+    bid_history::BidHistoryEntry,
+    bid_submission::{
+        v2::header_submission::SignedHeaderSubmission, BidSubmission, BidTrace, SignedBidSubmission,
+    },
+    deneb::SignedValidatorRegistration,
+    kill_switch::KillSwitchUpdate,
+    simulator::BlockSimError,
+    versioned_payload::PayloadAndBlobs,
+    BuilderInfo, DatabaseConfig, Filtering, GetHeaderTrace, GetPayloadTrace, GossipedHeaderTrace,
+    GossipedPayloadTrace, HeaderSubmissionTrace, ProposerInfo, RelayConfig, RetentionTable,
+    SignedValidatorRegistrationEntry, SubmissionTrace, ValidatorPreferences, ValidatorSummary,
+};
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, ToSql};
+use tracing::info;
+
+use crate::{
+    error::DatabaseError,
+    postgres::{postgres_db_filters::PgBidFilters, postgres_db_row_parsing::parse_bytes_to_pubkey},
+    sqlite::sqlite_db_row_parsing::{parse_blob_to_u256, parse_rows, u256_to_blob, FromRow},
+    types::{
+        BidSubmissionDocument, BlockTraceEventDocument, BuilderInfoDocument, BuilderStatsDocument,
+        DeliveredPayloadDocument, FailedGetPayloadDocument, GetHeaderCallDocument,
+        TooLateGetPayloadDocument, ValidatorRegistrationHistoryDocument,
+    },
+    DatabaseService,
+};
+
+const SCHEMA: &str = include_str!("schema.sql");
+/// Rows deleted per statement when pruning.
+const PRUNE_BATCH_SIZE: i64 = 10_000;
+/// Earliest time a bid was seen, as a block or a header submission.
+const SUBMISSION_TIMESTAMP: &str = "MIN(block_submission.first_seen, COALESCE(header_submission.first_seen, block_submission.first_seen))";
+
+/// `DatabaseService` backed by an embedded SQLite database, so the relay can run without
+/// Postgres. Meant for local development and CI, not for production deployments.
+#[derive(Clone)]
+pub struct SqliteDatabaseService {
+    conn: Arc<Mutex<Connection>>,
+    region: i16,
+}
+
+impl SqliteDatabaseService {
+    /// Opens or creates the database at `path` and applies the schema.
+    pub fn new(path: &str, region: i16) -> Result<Self, DatabaseError> {
+        let conn = Connection::open(path)?;
+        conn.execute_batch("PRAGMA journal_mode = WAL;")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(SqliteDatabaseService { conn: Arc::new(Mutex::new(conn)), region })
+    }
+
+    pub fn from_relay_config(relay_config: &RelayConfig) -> Result<Self, DatabaseError> {
+        let DatabaseConfig::Sqlite { path } = &relay_config.database else {
+            return Err(DatabaseError::GeneralError);
+        };
+
+        let db = Self::new(path, relay_config.postgres.region)?;
+        db.conn.lock().unwrap().execute(
+            "INSERT OR IGNORE INTO region (id, name) VALUES (?, ?)",
+            params![relay_config.postgres.region, relay_config.postgres.region_name],
+        )?;
+        info!(path, "Opened SQLite database");
+        Ok(db)
+    }
+
+    /// Runs `f` against the connection on the blocking thread pool.
+    pub(crate) async fn with_conn<T, F>(&self, f: F) -> Result<T, DatabaseError>
+    where
+        T: Send + 'static,
+        F: FnOnce(&mut Connection) -> Result<T, DatabaseError> + Send + 'static,
+    {
+        let conn = self.conn.clone();
+        tokio::task::spawn_blocking(move || f(&mut conn.lock().unwrap())).await?
+    }
+}
+
+fn unix_millis() -> i64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis() as i64
+}
+
+fn upsert_registration(
+    conn: &Connection,
+    registration_info: &ValidatorRegistrationInfo,
+    pool_name: Option<&str>,
+    inserted_at: i64,
+) -> Result<(), DatabaseError> {
+    let registration = &registration_info.registration.message;
+    let preferences = &registration_info.preferences;
+    let trusted_builders =
+        preferences.trusted_builders.as_ref().map(serde_json::to_string).transpose()?;
+    let blocked_builders =
+        preferences.blocked_builders.as_ref().map(serde_json::to_string).transpose()?;
+    let min_bid_value = preferences.min_bid_value.map(u256_to_blob);
+
+    // Record the change before the stored registration is overwritten. Stale registrations are
+    // not applied below, so they are not recorded either.
+    conn.execute(
+        "
+            INSERT INTO validator_registration_history
+                (public_key, fee_recipient, previous_fee_recipient, gas_limit, timestamp, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value, pool_name)
+            SELECT ?1, ?2, validator_registrations.fee_recipient, ?3, ?4, ?5, ?6, ?11, ?7, ?8, ?10, ?9
+            FROM (SELECT ?1 AS public_key) incoming
+            LEFT JOIN validator_registrations ON validator_registrations.public_key = incoming.public_key
+            LEFT JOIN validator_preferences ON validator_preferences.public_key = incoming.public_key
+            WHERE validator_registrations.public_key IS NULL
+            OR (
+                validator_registrations.timestamp < ?4
+                AND (
+                    validator_registrations.fee_recipient IS NOT ?2
+                    OR validator_registrations.gas_limit IS NOT ?3
+                    OR validator_preferences.filtering IS NOT ?5
+                    OR validator_preferences.trusted_builders IS NOT ?6
+                    OR validator_preferences.blocked_builders IS NOT ?11
+                    OR validator_preferences.header_delay IS NOT ?7
+                    OR validator_preferences.gossip_blobs IS NOT ?8
+                    OR validator_preferences.min_bid_value IS NOT ?10
+                )
+            )
+        ",
+        params![
+            registration.public_key.as_ref(),
+            registration.fee_recipient.as_ref(),
+            registration.gas_limit as i64,
+            registration.timestamp as i64,
+            preferences.filtering as i16,
+            trusted_builders,
+            preferences.header_delay,
+            preferences.gossip_blobs,
+            pool_name,
+            min_bid_value,
+            blocked_builders,
+        ],
+    )?;
+
+    conn.execute(
+        "
+            INSERT INTO validator_preferences (public_key, filtering, trusted_builders, blocked_builders, header_delay, gossip_blobs, min_bid_value)
+            VALUES (?, ?, ?, ?, ?, ?, ?)
+            ON CONFLICT (public_key)
+            DO UPDATE SET
+                filtering = excluded.filtering, trusted_builders = excluded.trusted_builders, blocked_builders = excluded.blocked_builders, header_delay = excluded.header_delay, gossip_blobs = excluded.gossip_blobs, min_bid_value = excluded.min_bid_value
+        ",
+        params![
+            registration.public_key.as_ref(),
+            preferences.filtering as i16,
+            trusted_builders,
+            blocked_builders,
+            preferences.header_delay,
+            preferences.gossip_blobs,
+            min_bid_value,
+        ],
+    )?;
+
+    conn.execute(
+        "
+            INSERT INTO validator_registrations (fee_recipient, gas_limit, timestamp, public_key, signature, inserted_at)
+            VALUES (?, ?, ?, ?, ?, ?)
+            ON CONFLICT (public_key)
+            DO UPDATE SET
+                fee_recipient = excluded.fee_recipient,
+                gas_limit = excluded.gas_limit,
+                timestamp = excluded.timestamp,
+                signature = excluded.signature,
+                inserted_at = excluded.inserted_at
+            WHERE excluded.timestamp > validator_registrations.timestamp
+        ",
+        params![
+            registration.fee_recipient.as_ref(),
+            registration.gas_limit as i64,
+            registration.timestamp as i64,
+            registration.public_key.as_ref(),
+            registration_info.registration.signature.as_ref(),
+            inserted_at,
+        ],
+    )?;
+
+    Ok(())
+}
+
+fn insert_payload_trace(
+    conn: &Connection,
+    block_hash: &[u8],
+    region_id: i16,
+    trace: &GetPayloadTrace,
+) -> Result<(), DatabaseError> {
+    conn.execute(
+        "
+            INSERT INTO payload_trace
+                (block_hash, region_id, receive, proposer_index_validated, signature_validated, payload_fetched, validation_complete, beacon_client_broadcast, broadcaster_block_broadcast, on_deliver_payload, v2)
+            VALUES
+                (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+        ",
+        params![
+            block_hash,
+            region_id,
+            trace.receive as i64,
+            trace.proposer_index_validated as i64,
+            trace.signature_validated as i64,
+            trace.payload_fetched as i64,
+            trace.validation_complete as i64,
+            trace.beacon_client_broadcast as i64,
+            trace.broadcaster_block_broadcast as i64,
+            trace.on_deliver_payload as i64,
+            trace.v2,
+        ],
+    )?;
+    Ok(())
+}
+
+#[async_trait]
+impl DatabaseService for SqliteDatabaseService {
+    async fn save_validator_registration(
+        &self,
+        registration_info: ValidatorRegistrationInfo,
+        pool_name: Option<String>,
+    ) -> Result<(), DatabaseError> {
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            upsert_registration(
+                &transaction,
+                &registration_info,
+                pool_name.as_deref(),
+                unix_millis(),
+            )?;
+            if let Some(name) = pool_name {
+                transaction.execute(
+                    "INSERT OR IGNORE INTO trusted_proposers (pub_key, name) VALUES (?, ?)",
+                    params![registration_info.registration.message.public_key.as_ref(), name],
+                )?;
+            }
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_validator_registrations(
+        &self,
+        entries: Vec<ValidatorRegistrationInfo>,
+        pool_name: Option<String>,
+    ) -> Result<(), DatabaseError> {
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            let inserted_at = unix_millis();
+            for entry in entries.iter() {
+                upsert_registration(&transaction, entry, pool_name.as_deref(), inserted_at)?;
+                if let Some(name) = &pool_name {
+                    transaction.execute(
+                        "INSERT OR IGNORE INTO trusted_proposers (pub_key, name) VALUES (?, ?)",
+                        params![entry.registration.message.public_key.as_ref(), name],
+                    )?;
+                }
+            }
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn is_registration_update_required(
+        &self,
+        registration: &SignedValidatorRegistration,
+    ) -> Result<bool, DatabaseError> {
+        let public_key = registration.message.public_key.as_ref().to_vec();
+        let timestamp = registration.message.timestamp as i64;
+        self.with_conn(move |conn| {
+            let existing: Option<i64> = conn
+                .query_row(
+                    "SELECT timestamp FROM validator_registrations WHERE public_key = ?",
+                    params![public_key],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            Ok(existing.map_or(true, |existing| existing < timestamp))
+        })
+        .await
+    }
+
+    async fn get_validator_registration(
+        &self,
+        pub_key: BlsPublicKey,
+    ) -> Result<SignedValidatorRegistrationEntry, DatabaseError> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare(
+                "
+                SELECT * FROM validator_registrations
+                INNER JOIN validator_preferences ON validator_registrations.public_key = validator_preferences.public_key
+                WHERE validator_registrations.public_key = ?
+            ",
+            )?;
+            let mut rows = stmt.query(params![pub_key.as_ref()])?;
+            match rows.next()? {
+                Some(row) => SignedValidatorRegistrationEntry::from_row(row),
+                None => Err(DatabaseError::ValidatorRegistrationNotFound),
+            }
+        })
+        .await
+    }
+
+    async fn get_validator_registrations_for_pub_keys(
+        &self,
+        pub_keys: Vec<BlsPublicKey>,
+    ) -> Result<Vec<SignedValidatorRegistrationEntry>, DatabaseError> {
+        self.with_conn(move |conn| {
+            let placeholders = vec!["?"; pub_keys.len()].join(", ");
+            let mut stmt = conn.prepare(&format!(
+                "
+                SELECT * FROM validator_registrations
+                INNER JOIN validator_preferences ON validator_registrations.public_key = validator_preferences.public_key
+                WHERE validator_registrations.public_key IN ({placeholders})
+            "
+            ))?;
+            let mut rows = stmt.query(params_from_iter(pub_keys.iter().map(|key| key.as_ref())))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_validator_registration_timestamp(
+        &self,
+        pub_key: BlsPublicKey,
+    ) -> Result<u64, DatabaseError> {
+        self.get_validator_registration(pub_key).await.map(|entry| entry.inserted_at)
+    }
+
+    async fn get_validator_registration_history(
+        &self,
+        pub_key: BlsPublicKey,
+        limit: Option<u64>,
+    ) -> Result<Vec<ValidatorRegistrationHistoryDocument>, DatabaseError> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare(
+                "
+                SELECT * FROM validator_registration_history
+                WHERE public_key = ?
+                ORDER BY inserted_at DESC, id DESC
+                LIMIT ?
+            ",
+            )?;
+            // A negative limit means no limit.
+            let limit = limit.map_or(-1, |limit| limit as i64);
+            let mut rows = stmt.query(params![pub_key.as_ref(), limit])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn set_proposer_duties(
+        &self,
+        proposer_duties: Vec<BuilderGetValidatorsResponseEntry>,
+    ) -> Result<(), DatabaseError> {
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            transaction.execute("DELETE FROM proposer_duties", [])?;
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT OR IGNORE INTO proposer_duties (slot_number, validator_index, public_key) VALUES (?, ?, ?)",
+                )?;
+                for duty in proposer_duties.iter() {
+                    stmt.execute(params![
+                        duty.slot as i64,
+                        duty.validator_index as i64,
+                        duty.entry.registration.message.public_key.as_ref(),
+                    ])?;
+                }
+            }
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_proposer_duties(
+        &self,
+    ) -> Result<Vec<BuilderGetValidatorsResponseEntry>, DatabaseError> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                "
+                SELECT * FROM proposer_duties
+                INNER JOIN validator_registrations
+                ON proposer_duties.public_key = validator_registrations.public_key
+                INNER JOIN validator_preferences
+                ON proposer_duties.public_key = validator_preferences.public_key
+            ",
+            )?;
+            let mut rows = stmt.query([])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn set_known_validators(
+        &self,
+        known_validators: Vec<ValidatorSummary>,
+    ) -> Result<(), DatabaseError> {
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            transaction.execute("DELETE FROM known_validators", [])?;
+            {
+                let mut stmt = transaction
+                    .prepare("INSERT OR IGNORE INTO known_validators (public_key) VALUES (?)")?;
+                for validator in known_validators.iter() {
+                    stmt.execute(params![validator.validator.public_key.as_ref()])?;
+                }
+            }
+            transaction.commit()?;
+            info!("Known validators: updated count: {:?}", known_validators.len());
+            Ok(())
+        })
+        .await
+    }
+
+    async fn check_known_validators(
+        &self,
+        public_keys: Vec<BlsPublicKey>,
+    ) -> Result<HashSet<BlsPublicKey>, DatabaseError> {
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare("SELECT 1 FROM known_validators WHERE public_key = ?")?;
+            let mut known = HashSet::new();
+            for public_key in public_keys {
+                if stmt.exists(params![public_key.as_ref()])? {
+                    known.insert(public_key);
+                }
+            }
+            Ok(known)
+        })
+        .await
+    }
+
+    async fn save_too_late_get_payload(
+        &self,
+        slot: u64,
+        proposer_pub_key: &BlsPublicKey,
+        payload_hash: &Hash32,
+        message_received: u64,
+        payload_fetched: u64,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        let proposer_pub_key = proposer_pub_key.as_ref().to_vec();
+        let payload_hash = payload_hash.as_ref().to_vec();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "
+                    INSERT INTO late_payload
+                        (block_hash, slot_number, region_id, proposer_pubkey, message_received, payload_fetched)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    payload_hash,
+                    slot as i64,
+                    region_id,
+                    proposer_pub_key,
+                    message_received as i64,
+                    payload_fetched as i64,
+                ],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_delivered_payload(
+        &self,
+        bid_trace: &BidTrace,
+        payload: Arc<PayloadAndBlobs>,
+        latency_trace: &GetPayloadTrace,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        let bid_trace = bid_trace.clone();
+        let latency_trace = latency_trace.clone();
+        self.with_conn(move |conn| {
+            let payload = &payload.execution_payload;
+            let block_hash = bid_trace.block_hash.as_ref();
+            let transaction = conn.transaction()?;
+
+            transaction.execute(
+                "
+                    INSERT OR IGNORE INTO delivered_payload
+                        (block_hash, payload_parent_hash, fee_recipient, state_root, receipts_root, logs_bloom, prev_randao, timestamp, block_number, gas_limit, gas_used, extra_data, base_fee_per_gas)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    block_hash,
+                    payload.parent_hash().as_ref(),
+                    payload.fee_recipient().as_ref(),
+                    payload.state_root().as_ref(),
+                    payload.receipts_root().as_ref(),
+                    payload.logs_bloom().as_ref(),
+                    payload.prev_randao().as_ref(),
+                    payload.timestamp() as i64,
+                    payload.block_number() as i64,
+                    payload.gas_limit() as i64,
+                    payload.gas_used() as i64,
+                    payload.extra_data().as_ref(),
+                    u256_to_blob(*payload.base_fee_per_gas()),
+                ],
+            )?;
+
+            transaction.execute(
+                "
+                    INSERT OR IGNORE INTO delivered_payload_preferences (block_hash, filtering, trusted_builders)
+                    SELECT ?, filtering, trusted_builders
+                    FROM validator_preferences
+                    WHERE public_key = ?
+                ",
+                params![block_hash, bid_trace.proposer_public_key.as_ref()],
+            )?;
+
+            insert_payload_trace(&transaction, block_hash, region_id, &latency_trace)?;
+
+            {
+                let mut stmt = transaction.prepare(
+                    "INSERT OR IGNORE INTO \"transaction\" (block_hash, bytes) VALUES (?, ?)",
+                )?;
+                for tx in payload.transactions().iter() {
+                    stmt.execute(params![payload.block_hash().as_ref(), tx.as_ref()])?;
+                }
+            }
+
+            if let Some(withdrawals) = payload.withdrawals() {
+                let mut stmt = transaction.prepare(
+                    "INSERT OR IGNORE INTO withdrawal (\"index\", block_hash, validator_index, address, amount) VALUES (?, ?, ?, ?, ?)",
+                )?;
+                for withdrawal in withdrawals.iter() {
+                    stmt.execute(params![
+                        withdrawal.index as i64,
+                        payload.block_hash().as_ref(),
+                        withdrawal.validator_index as i64,
+                        withdrawal.address.as_ref(),
+                        withdrawal.amount as i64,
+                    ])?;
+                }
+            }
+
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn store_block_submission(
+        &self,
+        submission: Arc<SignedBidSubmission>,
+        trace: Arc<SubmissionTrace>,
+        optimistic_version: i16,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+
+            transaction.execute(
+                "
+                    INSERT INTO
+                        block_submission (block_number, slot_number, parent_hash, block_hash, builder_pubkey, proposer_pubkey, proposer_fee_recipient, gas_limit, gas_used, value, num_txs, timestamp, first_seen)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (block_hash)
+                    DO UPDATE SET
+                        first_seen = MIN(block_submission.first_seen, excluded.first_seen)
+                ",
+                params![
+                    submission.block_number() as i64,
+                    submission.slot() as i64,
+                    submission.parent_hash().as_ref(),
+                    submission.block_hash().as_ref(),
+                    submission.builder_public_key().as_ref(),
+                    submission.proposer_public_key().as_ref(),
+                    submission.proposer_fee_recipient().as_ref(),
+                    submission.gas_limit() as i64,
+                    submission.gas_used() as i64,
+                    u256_to_blob(submission.value()),
+                    submission.transactions().len() as i64,
+                    submission.timestamp() as i64,
+                    trace.receive as i64,
+                ],
+            )?;
+
+            transaction.execute(
+                "
+                    INSERT INTO
+                        submission_trace (block_hash, region_id, optimistic_version, receive, decode, pre_checks, signature, floor_bid_checks, simulation, auctioneer_update, request_finish)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    submission.block_hash().as_ref(),
+                    region_id,
+                    optimistic_version,
+                    trace.receive as i64,
+                    trace.decode as i64,
+                    trace.pre_checks as i64,
+                    trace.signature as i64,
+                    trace.floor_bid_checks as i64,
+                    trace.simulation as i64,
+                    trace.auctioneer_update as i64,
+                    trace.request_finish as i64,
+                ],
+            )?;
+
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn store_builder_info(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        builder_info: BuilderInfo,
+    ) -> Result<(), DatabaseError> {
+        let builder_pub_key = builder_pub_key.as_ref().to_vec();
+        self.with_conn(move |conn| {
+            conn.execute(
+                "
+                    INSERT INTO builder_info (public_key, collateral, is_optimistic, builder_id)
+                    VALUES (?, ?, ?, ?)
+                    ON CONFLICT (public_key)
+                    DO UPDATE SET
+                        collateral = excluded.collateral,
+                        is_optimistic = excluded.is_optimistic
+                ",
+                params![
+                    builder_pub_key,
+                    u256_to_blob(builder_info.collateral),
+                    builder_info.is_optimistic,
+                    builder_info.builder_id,
+                ],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn db_get_builder_info(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<BuilderInfo, DatabaseError> {
+        let builder_pub_key = builder_pub_key.clone();
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare("SELECT * FROM builder_info WHERE public_key = ?")?;
+            let mut rows = stmt.query(params![builder_pub_key.as_ref()])?;
+            match rows.next()? {
+                Some(row) => BuilderInfo::from_row(row),
+                None => Err(DatabaseError::BuilderInfoNotFound { public_key: builder_pub_key }),
+            }
+        })
+        .await
+    }
+
+    async fn get_all_builder_infos(&self) -> Result<Vec<BuilderInfoDocument>, DatabaseError> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare("SELECT * FROM builder_info")?;
+            let mut rows = stmt.query([])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn check_builder_api_key(&self, api_key: &str) -> Result<bool, DatabaseError> {
+        let api_key = api_key.to_string();
+        self.with_conn(move |conn| {
+            Ok(conn
+                .prepare("SELECT 1 FROM builder_info WHERE api_key = ?")?
+                .exists(params![api_key])?)
+        })
+        .await
+    }
+
+    async fn get_builder_infos_by_api_key(
+        &self,
+        api_key: &str,
+    ) -> Result<Vec<BuilderInfoDocument>, DatabaseError> {
+        let api_key = api_key.to_string();
+        self.with_conn(move |conn| {
+            let mut stmt = conn.prepare("SELECT * FROM builder_info WHERE api_key = ?")?;
+            let mut rows = stmt.query(params![api_key])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn db_demote_builder(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        block_hash: &Hash32,
+        reason: String,
+    ) -> Result<(), DatabaseError> {
+        let builder_pub_key = builder_pub_key.as_ref().to_vec();
+        let block_hash = block_hash.as_ref().to_vec();
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            transaction.execute(
+                "UPDATE builder_info SET is_optimistic = FALSE WHERE public_key = ?",
+                params![builder_pub_key],
+            )?;
+            transaction.execute(
+                "
+                    INSERT INTO demotions (public_key, block_hash, demotion_time, reason)
+                    VALUES (?, ?, ?, ?)
+                ",
+                params![builder_pub_key, block_hash, unix_millis(), reason],
+            )?;
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_simulation_result(
+        &self,
+        block_hash: ByteVector<32>,
+        block_sim_result: Result<(), BlockSimError>,
+    ) -> Result<(), DatabaseError> {
+        let Err(e) = block_sim_result else { return Ok(()) };
+        let error = format!("{:?}", e);
+        self.with_conn(move |conn| {
+            conn.execute(
+                "INSERT OR IGNORE INTO simulation_error (block_hash, error) VALUES (?, ?)",
+                params![block_hash.as_ref(), error],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_bids(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<BidSubmissionDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+        self.with_conn(move |conn| {
+            let mut query = format!(
+                "
+                SELECT
+                    block_submission.block_number block_number,
+                    block_submission.slot_number slot_number,
+                    block_submission.parent_hash parent_hash,
+                    block_submission.block_hash block_hash,
+                    block_submission.builder_pubkey builder_public_key,
+                    block_submission.proposer_pubkey proposer_public_key,
+                    block_submission.proposer_fee_recipient proposer_fee_recipient,
+                    block_submission.gas_limit gas_limit,
+                    block_submission.gas_used gas_used,
+                    block_submission.value submission_value,
+                    block_submission.num_txs num_txs,
+                    {SUBMISSION_TIMESTAMP} submission_timestamp,
+                    block_submission.id submission_id
+                FROM
+                    block_submission
+                LEFT JOIN
+                    header_submission ON block_submission.block_hash = header_submission.block_hash
+                WHERE 1 = 1
+            ",
+            );
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(slot) = filters.slot() {
+                query.push_str(" AND block_submission.slot_number = ?");
+                params.push(Box::new(slot));
+            }
+
+            if let Some(block_number) = filters.block_number() {
+                query.push_str(" AND block_submission.block_number = ?");
+                params.push(Box::new(block_number));
+            }
+
+            if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+                query.push_str(" AND block_submission.proposer_pubkey = ?");
+                params.push(Box::new(proposer_pubkey.to_vec()));
+            }
+
+            if let Some(builder_pubkey) = filters.builder_pubkey() {
+                query.push_str(" AND block_submission.builder_pubkey = ?");
+                params.push(Box::new(builder_pubkey.to_vec()));
+            }
+
+            if let Some(block_hash) = filters.block_hash() {
+                query.push_str(" AND block_submission.block_hash = ?");
+                params.push(Box::new(block_hash.to_vec()));
+            }
+
+            // Filter on the same timestamp the response reports as `submission_timestamp`.
+            if let Some(received_at_from) = filters.received_at_from() {
+                query.push_str(&format!(" AND {SUBMISSION_TIMESTAMP} >= ?"));
+                params.push(Box::new(received_at_from));
+            }
+
+            if let Some(received_at_to) = filters.received_at_to() {
+                query.push_str(&format!(" AND {SUBMISSION_TIMESTAMP} < ?"));
+                params.push(Box::new(received_at_to));
+            }
+
+            match (filters.cursor(), filters.cursor_id()) {
+                (Some(cursor), Some(cursor_id)) => {
+                    query.push_str(
+                        " AND (block_submission.slot_number, block_submission.id) < (?, ?)",
+                    );
+                    params.push(Box::new(cursor));
+                    params.push(Box::new(cursor_id));
+                }
+                (Some(cursor), None) => {
+                    query.push_str(" AND block_submission.slot_number <= ?");
+                    params.push(Box::new(cursor));
+                }
+                (None, Some(cursor_id)) => {
+                    query.push_str(" AND block_submission.id < ?");
+                    params.push(Box::new(cursor_id));
+                }
+                (None, None) => {}
+            }
+
+            query.push_str(filters.bids_order_clause());
+
+            if let Some(limit) = filters.limit() {
+                query.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_delivered_payloads(
+        &self,
+        filters: &BidFilters,
+        validator_preferences: Arc<ValidatorPreferences>,
+    ) -> Result<Vec<DeliveredPayloadDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+        self.with_conn(move |conn| {
+            let mut query = String::from(
+                "
+                SELECT
+                    block_submission.slot_number            slot_number,
+                    block_submission.parent_hash            parent_hash,
+                    block_submission.block_hash             block_hash,
+                    block_submission.builder_pubkey         builder_public_key,
+                    block_submission.proposer_pubkey        proposer_public_key,
+                    block_submission.proposer_fee_recipient proposer_fee_recipient,
+                    block_submission.value                  submission_value,
+                    block_submission.gas_limit              gas_limit,
+                    block_submission.gas_used               gas_used,
+                    block_submission.block_number           block_number,
+                    block_submission.num_txs                num_txs
+                FROM
+                    delivered_payload
+                INNER JOIN
+                    block_submission
+                ON
+                    block_submission.block_hash = delivered_payload.block_hash
+            ",
+            );
+
+            let filtering = match validator_preferences.filtering {
+                Filtering::Regional => Some(1_i16),
+                Filtering::Global => None,
+            };
+
+            if validator_preferences.trusted_builders.is_some() || filtering.is_some() {
+                query.push_str(
+                    "
+                    INNER JOIN
+                        delivered_payload_preferences
+                    ON
+                        delivered_payload.block_hash = delivered_payload_preferences.block_hash
+                ",
+                );
+            }
+
+            query.push_str(" WHERE 1 = 1");
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(slot) = filters.slot() {
+                query.push_str(" AND block_submission.slot_number = ?");
+                params.push(Box::new(slot));
+            }
+
+            if let Some(cursor) = filters.cursor() {
+                query.push_str(" AND block_submission.slot_number <= ?");
+                params.push(Box::new(cursor));
+            }
+
+            if let Some(block_number) = filters.block_number() {
+                query.push_str(" AND block_submission.block_number = ?");
+                params.push(Box::new(block_number));
+            }
+
+            if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+                query.push_str(" AND block_submission.proposer_pubkey = ?");
+                params.push(Box::new(proposer_pubkey.to_vec()));
+            }
+
+            if let Some(builder_pubkey) = filters.builder_pubkey() {
+                query.push_str(" AND block_submission.builder_pubkey = ?");
+                params.push(Box::new(builder_pubkey.to_vec()));
+            }
+
+            if let Some(block_hash) = filters.block_hash() {
+                query.push_str(" AND block_submission.block_hash = ?");
+                params.push(Box::new(block_hash.to_vec()));
+            }
+
+            if let Some(filtering) = filtering {
+                query.push_str(" AND delivered_payload_preferences.filtering = ?");
+                params.push(Box::new(filtering));
+            }
+
+            // Equivalent of the Postgres `@>` containment check on the JSON encoded array.
+            if let Some(trusted_builders) = &validator_preferences.trusted_builders {
+                for trusted_builder in trusted_builders {
+                    query.push_str(
+                        " AND EXISTS (SELECT 1 FROM json_each(delivered_payload_preferences.trusted_builders) WHERE json_each.value = ?)",
+                    );
+                    params.push(Box::new(trusted_builder.clone()));
+                }
+            }
+
+            match filters.order() {
+                Some(OrderBy::ValueAsc) => query.push_str(" ORDER BY block_submission.value ASC"),
+                Some(OrderBy::ValueDesc) => query.push_str(" ORDER BY block_submission.value DESC"),
+                _ => query.push_str(" ORDER BY block_submission.slot_number DESC"),
+            }
+
+            if let Some(limit) = filters.limit() {
+                query.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn save_get_header_call(
+        &self,
+        slot: u64,
+        parent_hash: ByteVector<32>,
+        public_key: BlsPublicKey,
+        best_block_hash: ByteVector<32>,
+        trace: GetHeaderTrace,
+        user_agent: Option<String>,
+        bid_history: Vec<BidHistoryEntry>,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            transaction.execute(
+                "
+                    INSERT INTO get_header
+                        (slot_number, region_id, parent_hash, proposer_pubkey, block_hash, user_agent)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    slot as i64,
+                    region_id,
+                    parent_hash.as_ref(),
+                    public_key.as_ref(),
+                    best_block_hash.as_ref(),
+                    user_agent,
+                ],
+            )?;
+            transaction.execute(
+                "
+                    INSERT INTO get_header_trace
+                        (block_hash, region_id, receive, validation_complete, best_bid_fetched)
+                    VALUES
+                        (?, ?, ?, ?, ?)
+                ",
+                params![
+                    best_block_hash.as_ref(),
+                    region_id,
+                    trace.receive as i64,
+                    trace.validation_complete as i64,
+                    trace.best_bid_fetched as i64,
+                ],
+            )?;
+            for entry in &bid_history {
+                transaction.execute(
+                    "
+                        INSERT INTO get_header_bid_history
+                            (slot_number, region_id, parent_hash, proposer_pubkey, served_block_hash,
+                            builder_pubkey, block_hash, value, received_at)
+                        VALUES
+                            (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    ",
+                    params![
+                        slot as i64,
+                        region_id,
+                        parent_hash.as_ref(),
+                        public_key.as_ref(),
+                        best_block_hash.as_ref(),
+                        entry.builder_pubkey.as_ref(),
+                        entry.block_hash.as_ref(),
+                        u256_to_blob(entry.value),
+                        entry.received_at as i64,
+                    ],
+                )?;
+            }
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_failed_get_payload(
+        &self,
+        slot: u64,
+        block_hash: ByteVector<32>,
+        error: String,
+        trace: GetPayloadTrace,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            transaction.execute(
+                "
+                    INSERT INTO failed_payload
+                        (region_id, slot_number, block_hash, error)
+                    VALUES
+                        (?, ?, ?, ?)
+                ",
+                params![region_id, slot as i64, block_hash.as_ref(), error],
+            )?;
+            insert_payload_trace(&transaction, block_hash.as_ref(), region_id, &trace)?;
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_kill_switch_update(
+        &self,
+        update: &KillSwitchUpdate,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        let (action, reason, expires_at_ms) = match update {
+            KillSwitchUpdate::Enable(kill_switch) => {
+                ("enable", Some(kill_switch.reason.clone()), kill_switch.expires_at_ms)
+            }
+            KillSwitchUpdate::Disable { .. } => ("disable", None, None),
+        };
+        let scope = serde_json::to_string(update.scope())?;
+
+        self.with_conn(move |conn| {
+            conn.execute(
+                "
+                    INSERT INTO kill_switch_event
+                        (region_id, action, scope, reason, expires_at_ms)
+                    VALUES
+                        (?, ?, ?, ?, ?)
+                ",
+                params![
+                    region_id,
+                    action,
+                    scope,
+                    reason,
+                    expires_at_ms.map(|expires_at_ms| expires_at_ms as i64),
+                ],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_get_header_calls(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<GetHeaderCallDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+        self.with_conn(move |conn| {
+            let mut query = String::from(
+                "
+                SELECT slot_number, region_id, parent_hash, proposer_pubkey, block_hash, user_agent, created_at
+                FROM get_header
+                WHERE 1 = 1
+            ",
+            );
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(slot) = filters.slot() {
+                query.push_str(" AND slot_number = ?");
+                params.push(Box::new(slot));
+            }
+
+            if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+                query.push_str(" AND proposer_pubkey = ?");
+                params.push(Box::new(proposer_pubkey.to_vec()));
+            }
+
+            query.push_str(" ORDER BY slot_number DESC, created_at DESC");
+
+            if let Some(limit) = filters.limit() {
+                query.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_failed_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<FailedGetPayloadDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+        self.with_conn(move |conn| {
+            let mut query = String::from(
+                "
+                SELECT
+                    failed_payload.slot_number,
+                    failed_payload.region_id,
+                    failed_payload.block_hash,
+                    block_submission.proposer_pubkey,
+                    failed_payload.error,
+                    failed_payload.created_at
+                FROM
+                    failed_payload
+                LEFT JOIN
+                    block_submission ON failed_payload.block_hash = block_submission.block_hash
+                WHERE failed_payload.slot_number IS NOT NULL
+            ",
+            );
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(slot) = filters.slot() {
+                query.push_str(" AND failed_payload.slot_number = ?");
+                params.push(Box::new(slot));
+            }
+
+            if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+                query.push_str(" AND block_submission.proposer_pubkey = ?");
+                params.push(Box::new(proposer_pubkey.to_vec()));
+            }
+
+            query.push_str(
+                " ORDER BY failed_payload.slot_number DESC, failed_payload.created_at DESC",
+            );
+
+            if let Some(limit) = filters.limit() {
+                query.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_too_late_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<TooLateGetPayloadDocument>, DatabaseError> {
+        let filters = PgBidFilters::from(filters);
+        self.with_conn(move |conn| {
+            let mut query = String::from(
+                "
+                SELECT slot_number, region_id, block_hash, proposer_pubkey, message_received, payload_fetched
+                FROM late_payload
+                WHERE 1 = 1
+            ",
+            );
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(slot) = filters.slot() {
+                query.push_str(" AND slot_number = ?");
+                params.push(Box::new(slot));
+            }
+
+            if let Some(proposer_pubkey) = filters.proposer_pubkey() {
+                query.push_str(" AND proposer_pubkey = ?");
+                params.push(Box::new(proposer_pubkey.to_vec()));
+            }
+
+            query.push_str(" ORDER BY slot_number DESC, message_received DESC");
+
+            if let Some(limit) = filters.limit() {
+                query.push_str(" LIMIT ?");
+                params.push(Box::new(limit));
+            }
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_block_trace(
+        &self,
+        block_hash: &Hash32,
+    ) -> Result<Vec<BlockTraceEventDocument>, DatabaseError> {
+        let block_hash = block_hash.as_ref().to_vec();
+        self.with_conn(move |conn| {
+            // Same unpivot as the Postgres query, written as one SELECT per stage since SQLite has
+            // no LATERAL joins.
+            let stages: [(&str, &str, &[&str]); 6] = [
+                (
+                    "submission",
+                    "submission_trace",
+                    &[
+                        "receive",
+                        "decode",
+                        "pre_checks",
+                        "signature",
+                        "floor_bid_checks",
+                        "simulation",
+                        "auctioneer_update",
+                        "request_finish",
+                    ],
+                ),
+                (
+                    "header_submission",
+                    "header_submission_trace",
+                    &[
+                        "receive",
+                        "decode",
+                        "pre_checks",
+                        "signature",
+                        "floor_bid_checks",
+                        "auctioneer_update",
+                        "request_finish",
+                    ],
+                ),
+                (
+                    "gossiped_header",
+                    "gossiped_header_trace",
+                    &["on_receive", "on_gossip_receive", "pre_checks", "auctioneer_update"],
+                ),
+                (
+                    "gossiped_payload",
+                    "gossiped_payload_trace",
+                    &["receive", "pre_checks", "auctioneer_update"],
+                ),
+                (
+                    "get_header",
+                    "get_header_trace",
+                    &["receive", "validation_complete", "best_bid_fetched"],
+                ),
+                (
+                    "get_payload",
+                    "payload_trace",
+                    &[
+                        "receive",
+                        "proposer_index_validated",
+                        "signature_validated",
+                        "payload_fetched",
+                        "validation_complete",
+                        "beacon_client_broadcast",
+                        "broadcaster_block_broadcast",
+                        "on_deliver_payload",
+                    ],
+                ),
+            ];
+
+            let selects: Vec<String> = stages
+                .iter()
+                .flat_map(|(source, table, columns)| {
+                    columns.iter().map(move |column| {
+                        format!(
+                            "SELECT region_id, '{source}' AS source, '{column}' AS stage, {column} AS timestamp FROM {table} WHERE block_hash = ?1"
+                        )
+                    })
+                })
+                .collect();
+
+            // Stages that were never reached are stored as 0.
+            let query = format!(
+                "SELECT * FROM ({}) WHERE timestamp > 0 ORDER BY timestamp ASC",
+                selects.join(" UNION ALL ")
+            );
+
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params![block_hash])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_builder_stats(
+        &self,
+        filters: &BuilderStatsParams,
+    ) -> Result<Vec<BuilderStatsDocument>, DatabaseError> {
+        let filters = filters.clone();
+        self.with_conn(move |conn| {
+            // There is no materialized view here, so the per slot aggregates are computed on read.
+            let mut query = String::from(
+                "
+                SELECT
+                    block_submission.builder_pubkey,
+                    block_submission.slot_number,
+                    block_submission.first_seen,
+                    block_submission.value,
+                    builder_info.builder_id,
+                    EXISTS (
+                        SELECT 1 FROM simulation_error
+                        WHERE simulation_error.block_hash = block_submission.block_hash
+                    ) simulation_failure,
+                    EXISTS (
+                        SELECT 1 FROM submission_trace
+                        WHERE submission_trace.block_hash = block_submission.block_hash
+                        AND submission_trace.optimistic_version > 0
+                    ) optimistic,
+                    EXISTS (
+                        SELECT 1 FROM failed_payload
+                        WHERE failed_payload.block_hash = block_submission.block_hash
+                    ) failed_payload,
+                    EXISTS (
+                        SELECT 1 FROM delivered_payload
+                        WHERE delivered_payload.block_hash = block_submission.block_hash
+                    ) won
+                FROM
+                    block_submission
+                LEFT JOIN
+                    builder_info ON builder_info.public_key = block_submission.builder_pubkey
+                WHERE 1 = 1
+            ",
+            );
+            let mut params: Vec<Box<dyn ToSql>> = Vec::new();
+
+            if let Some(builder_pubkey) = &filters.builder_pubkey {
+                query.push_str(" AND block_submission.builder_pubkey = ?");
+                params.push(Box::new(builder_pubkey.as_ref().to_vec()));
+            }
+
+            if let Some(builder_id) = &filters.builder_id {
+                query.push_str(" AND builder_info.builder_id = ?");
+                params.push(Box::new(builder_id.clone()));
+            }
+
+            struct SlotStats {
+                builder_id: Option<String>,
+                first_seen: i64,
+                submissions: u64,
+                total_value: U256,
+                max_value: U256,
+                simulation_failures: u64,
+                optimistic_submissions: u64,
+                failed_payloads: u64,
+                won: bool,
+            }
+
+            let mut slots: HashMap<(Vec<u8>, i64), SlotStats> = HashMap::new();
+            let mut stmt = conn.prepare(&query)?;
+            let mut rows = stmt.query(params_from_iter(params.iter()))?;
+            while let Some(row) = rows.next()? {
+                let value = parse_blob_to_u256(&row.get::<_, Vec<u8>>("value")?)?;
+                let first_seen: i64 = row.get("first_seen")?;
+                let stats = slots
+                    .entry((row.get("builder_pubkey")?, row.get("slot_number")?))
+                    .or_insert_with(|| SlotStats {
+                        builder_id: None,
+                        first_seen,
+                        submissions: 0,
+                        total_value: U256::ZERO,
+                        max_value: U256::ZERO,
+                        simulation_failures: 0,
+                        optimistic_submissions: 0,
+                        failed_payloads: 0,
+                        won: false,
+                    });
+                stats.builder_id = row.get("builder_id")?;
+                stats.first_seen = stats.first_seen.min(first_seen);
+                stats.submissions += 1;
+                stats.total_value = stats.total_value.saturating_add(value);
+                stats.max_value = stats.max_value.max(value);
+                stats.simulation_failures += row.get::<_, bool>("simulation_failure")? as u64;
+                stats.optimistic_submissions += row.get::<_, bool>("optimistic")? as u64;
+                stats.failed_payloads += row.get::<_, bool>("failed_payload")? as u64;
+                stats.won |= row.get::<_, bool>("won")?;
+            }
+
+            // Demotion times are stored in milliseconds, `first_seen` in nanoseconds.
+            let received_at_from = filters.received_at_from.map(|ms| ms as i64);
+            let received_at_to = filters.received_at_to.map(|ms| ms as i64);
+            let to_ns = |ms: i64| ms.saturating_mul(1_000_000);
+
+            let mut builders: HashMap<Vec<u8>, (BuilderStatsDocument, U256)> = HashMap::new();
+            for ((builder_pubkey, _), stats) in slots {
+                if received_at_from.is_some_and(|from| stats.first_seen < to_ns(from)) ||
+                    received_at_to.is_some_and(|to| stats.first_seen >= to_ns(to))
+                {
+                    continue;
+                }
+
+                let (document, total_value) = match builders.entry(builder_pubkey) {
+                    Entry::Occupied(entry) => entry.into_mut(),
+                    Entry::Vacant(entry) => {
+                        let document = BuilderStatsDocument {
+                            builder_pubkey: parse_bytes_to_pubkey(entry.key())?,
+                            builder_id: stats.builder_id.clone(),
+                            submissions: 0,
+                            slots: 0,
+                            slots_won: 0,
+                            avg_value: U256::ZERO,
+                            max_value: U256::ZERO,
+                            simulation_failures: 0,
+                            optimistic_submissions: 0,
+                            failed_payloads: 0,
+                            demotions: 0,
+                        };
+                        entry.insert((document, U256::ZERO))
+                    }
+                };
+                document.submissions += stats.submissions;
+                document.slots += 1;
+                document.slots_won += stats.won as u64;
+                document.max_value = document.max_value.max(stats.max_value);
+                document.simulation_failures += stats.simulation_failures;
+                document.optimistic_submissions += stats.optimistic_submissions;
+                document.failed_payloads += stats.failed_payloads;
+                *total_value = total_value.saturating_add(stats.total_value);
+            }
+
+            let mut demotions_query =
+                String::from("SELECT COUNT(*) FROM demotions WHERE public_key = ?");
+            if received_at_from.is_some() {
+                demotions_query.push_str(" AND demotion_time >= ?");
+            }
+            if received_at_to.is_some() {
+                demotions_query.push_str(" AND demotion_time < ?");
+            }
+            let mut demotions_stmt = conn.prepare(&demotions_query)?;
+
+            let mut documents = Vec::with_capacity(builders.len());
+            for (builder_pubkey, (mut document, total_value)) in builders {
+                // Rounded like the Postgres `ROUND(SUM / COUNT)`.
+                let submissions = U256::from(document.submissions);
+                document.avg_value = (total_value + submissions / U256::from(2)) / submissions;
+
+                let mut params: Vec<Box<dyn ToSql>> = vec![Box::new(builder_pubkey)];
+                params.extend(received_at_from.map(|from| Box::new(from) as Box<dyn ToSql>));
+                params.extend(received_at_to.map(|to| Box::new(to) as Box<dyn ToSql>));
+                let demotions: i64 =
+                    demotions_stmt.query_row(params_from_iter(params.iter()), |row| row.get(0))?;
+                document.demotions = demotions as u64;
+
+                documents.push(document);
+            }
+
+            documents.sort_by(|a, b| {
+                b.slots_won.cmp(&a.slots_won).then(b.submissions.cmp(&a.submissions))
+            });
+            if let Some(limit) = filters.limit {
+                documents.truncate(limit as usize);
+            }
+
+            Ok(documents)
+        })
+        .await
+    }
+
+    async fn refresh_builder_stats(&self) -> Result<(), DatabaseError> {
+        // Builder stats are aggregated on read.
+        Ok(())
+    }
+
+    async fn prune_table(
+        &self,
+        table: RetentionTable,
+        cutoff: SystemTime,
+    ) -> Result<u64, DatabaseError> {
+        let cutoff = cutoff.duration_since(UNIX_EPOCH).unwrap_or_default();
+        // Each statement deletes at most `PRUNE_BATCH_SIZE` rows.
+        let query = match table {
+            RetentionTable::Transaction => {
+                "
+                DELETE FROM \"transaction\" WHERE rowid IN (
+                    SELECT rowid FROM \"transaction\"
+                    WHERE block_hash IN (SELECT block_hash FROM block_submission WHERE created_at < ?1)
+                    AND block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            "
+            }
+            RetentionTable::BlockSubmission => {
+                "
+                DELETE FROM block_submission WHERE rowid IN (
+                    SELECT rowid FROM block_submission
+                    WHERE created_at < ?1
+                    AND block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            "
+            }
+            RetentionTable::SubmissionTrace => {
+                "
+                DELETE FROM submission_trace WHERE rowid IN (
+                    SELECT rowid FROM submission_trace
+                    WHERE receive < ?1
+                    AND block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            "
+            }
+            RetentionTable::HeaderSubmission => {
+                "
+                DELETE FROM header_submission WHERE rowid IN (
+                    SELECT rowid FROM header_submission
+                    WHERE created_at < ?1
+                    AND block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            "
+            }
+            RetentionTable::GetHeader => {
+                "
+                DELETE FROM get_header WHERE rowid IN (
+                    SELECT rowid FROM get_header
+                    WHERE created_at < ?1
+                    AND block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            "
+            }
+        };
+        // `receive` is stored in nanoseconds, `created_at` in seconds.
+        let cutoff = match table {
+            RetentionTable::SubmissionTrace => cutoff.as_nanos() as i64,
+            _ => cutoff.as_secs() as i64,
+        };
+
+        let bid_history_query = matches!(table, RetentionTable::GetHeader).then_some(
+            "
+                DELETE FROM get_header_bid_history WHERE rowid IN (
+                    SELECT rowid FROM get_header_bid_history
+                    WHERE created_at < ?1
+                    AND served_block_hash NOT IN (SELECT block_hash FROM delivered_payload)
+                    LIMIT ?2
+                )
+            ",
+        );
+
+        self.with_conn(move |conn| {
+            let mut deleted = 0;
+            for query in std::iter::once(query).chain(bid_history_query) {
+                loop {
+                    let batch_deleted = conn.execute(query, params![cutoff, PRUNE_BATCH_SIZE])?;
+                    if batch_deleted == 0 {
+                        break;
+                    }
+                    deleted += batch_deleted as u64;
+                }
+            }
+            Ok(deleted)
+        })
+        .await
+    }
+
+    async fn store_header_submission(
+        &self,
+        submission: Arc<SignedHeaderSubmission>,
+        trace: Arc<HeaderSubmissionTrace>,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+
+            transaction.execute(
+                "
+                    INSERT INTO
+                        header_submission (block_number, slot_number, parent_hash, block_hash, builder_pubkey, proposer_pubkey, proposer_fee_recipient, gas_limit, gas_used, value, timestamp, first_seen)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+                    ON CONFLICT (block_hash)
+                    DO UPDATE SET
+                        first_seen = MIN(header_submission.first_seen, excluded.first_seen)
+                ",
+                params![
+                    submission.execution_payload_header().block_number() as i64,
+                    submission.slot() as i64,
+                    submission.parent_hash().as_ref(),
+                    submission.block_hash().as_ref(),
+                    submission.builder_public_key().as_ref(),
+                    submission.proposer_public_key().as_ref(),
+                    submission.proposer_fee_recipient().as_ref(),
+                    submission.gas_limit() as i64,
+                    submission.gas_used() as i64,
+                    u256_to_blob(submission.value()),
+                    submission.timestamp() as i64,
+                    trace.receive as i64,
+                ],
+            )?;
+
+            transaction.execute(
+                "
+                    INSERT INTO
+                        header_submission_trace (block_hash, region_id, receive, decode, pre_checks, signature, floor_bid_checks, auctioneer_update, request_finish)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    submission.block_hash().as_ref(),
+                    region_id,
+                    trace.receive as i64,
+                    trace.decode as i64,
+                    trace.pre_checks as i64,
+                    trace.signature as i64,
+                    trace.floor_bid_checks as i64,
+                    trace.auctioneer_update as i64,
+                    trace.request_finish as i64,
+                ],
+            )?;
+
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_gossiped_header_trace(
+        &self,
+        block_hash: ByteVector<32>,
+        trace: Arc<GossipedHeaderTrace>,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            conn.execute(
+                "
+                    INSERT INTO
+                        gossiped_header_trace (block_hash, region_id, on_receive, on_gossip_receive, pre_checks, auctioneer_update)
+                    VALUES
+                        (?, ?, ?, ?, ?, ?)
+                ",
+                params![
+                    block_hash.as_ref(),
+                    region_id,
+                    trace.on_receive as i64,
+                    trace.on_gossip_receive as i64,
+                    trace.pre_checks as i64,
+                    trace.auctioneer_update as i64,
+                ],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn save_gossiped_payload_trace(
+        &self,
+        block_hash: ByteVector<32>,
+        trace: Arc<GossipedPayloadTrace>,
+    ) -> Result<(), DatabaseError> {
+        let region_id = self.region;
+        self.with_conn(move |conn| {
+            conn.execute(
+                "
+                    INSERT INTO
+                        gossiped_payload_trace (block_hash, region_id, receive, pre_checks, auctioneer_update)
+                    VALUES
+                        (?, ?, ?, ?, ?)
+                ",
+                params![
+                    block_hash.as_ref(),
+                    region_id,
+                    trace.receive as i64,
+                    trace.pre_checks as i64,
+                    trace.auctioneer_update as i64,
+                ],
+            )?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_trusted_proposers(&self) -> Result<Vec<ProposerInfo>, DatabaseError> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare("SELECT * FROM trusted_proposers")?;
+            let mut rows = stmt.query([])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+
+    async fn get_validator_pool_name(
+        &self,
+        api_key: &str,
+    ) -> Result<Option<String>, DatabaseError> {
+        let api_key = api_key.to_string();
+        self.with_conn(move |conn| {
+            Ok(conn
+                .query_row(
+                    "SELECT name FROM validator_pools WHERE api_key = ?",
+                    params![api_key],
+                    |row| row.get(0),
+                )
+                .optional()?)
+        })
+        .await
+    }
+
+    async fn update_trusted_builders(
+        &self,
+        validator_keys: &Vec<BlsPublicKey>,
+        trusted_builders: &Vec<String>,
+    ) -> Result<(), DatabaseError> {
+        let validator_keys = validator_keys.clone();
+        let trusted_builders = serde_json::to_string(trusted_builders)?;
+        self.with_conn(move |conn| {
+            let transaction = conn.transaction()?;
+            {
+                let mut stmt = transaction.prepare(
+                    "UPDATE validator_preferences SET trusted_builders = ? WHERE public_key = ?",
+                )?;
+                for key in validator_keys.iter() {
+                    stmt.execute(params![trusted_builders, key.as_ref()])?;
+                }
+            }
+            transaction.commit()?;
+            Ok(())
+        })
+        .await
+    }
+
+    async fn get_validator_registrations(
+        &self,
+    ) -> Result<Vec<SignedValidatorRegistrationEntry>, DatabaseError> {
+        self.with_conn(|conn| {
+            let mut stmt = conn.prepare(
+                "
+                SELECT * FROM validator_registrations
+                INNER JOIN validator_preferences
+                ON validator_registrations.public_key = validator_preferences.public_key
+            ",
+            )?;
+            let mut rows = stmt.query([])?;
+            parse_rows(&mut rows)
+        })
+        .await
+    }
+}
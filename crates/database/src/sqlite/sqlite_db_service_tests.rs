@@ -0,0 +1,360 @@
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::Arc,
+        time::{Duration, SystemTime},
+    };
+
+    use ethereum_consensus::{
+        builder::{SignedValidatorRegistration, ValidatorRegistration},
+        crypto::SecretKey,
+        primitives::U256,
+    };
+    use helix_common::{
+        api::{
+            data_api::{BidFilters, BuilderStatsParams, OrderBy},
+            proposer_api::ValidatorRegistrationInfo,
+        },
+        bellatrix::ByteVector,
+        bid_history::BidHistoryEntry,
+        bid_submission::{
+            v2::header_submission::{
+                HeaderSubmissionCapella, SignedHeaderSubmission, SignedHeaderSubmissionCapella,
+            },
+            BidTrace, SignedBidSubmission,
+        },
+        hash_api_key,
+        simulator::BlockSimError,
+        BuilderInfo, Filtering, GetHeaderTrace, HeaderSubmissionTrace, RetentionTable,
+        SubmissionTrace, ValidatorPreferences,
+    };
+
+    use crate::{sqlite::sqlite_db_service::SqliteDatabaseService, DatabaseService};
+
+    fn test_db() -> SqliteDatabaseService {
+        SqliteDatabaseService::new(":memory:", 0).unwrap()
+    }
+
+    fn registration(timestamp: u64) -> ValidatorRegistrationInfo {
+        let mut rng = rand::thread_rng();
+        let key = SecretKey::random(&mut rng).unwrap();
+        ValidatorRegistrationInfo {
+            registration: SignedValidatorRegistration {
+                message: ValidatorRegistration {
+                    fee_recipient: Default::default(),
+                    gas_limit: 30_000_000,
+                    timestamp,
+                    public_key: key.public_key(),
+                },
+                signature: Default::default(),
+            },
+            preferences: ValidatorPreferences {
+                filtering: Filtering::Regional,
+                trusted_builders: Some(vec!["test".to_string()]),
+                blocked_builders: None,
+                header_delay: true,
+                gossip_blobs: false,
+                min_bid_value: Some(U256::from(1_000_000_000u64)),
+            },
+        }
+    }
+
+    fn submission(slot: u64, block_hash: u8, value: u64) -> Arc<SignedBidSubmission> {
+        let bid_trace = BidTrace {
+            slot,
+            block_hash: ByteVector::<32>::try_from([block_hash; 32].as_slice()).unwrap(),
+            value: U256::from(value),
+            ..Default::default()
+        };
+        let mut submission = SignedBidSubmission::default();
+        match &mut submission {
+            SignedBidSubmission::Deneb(submission) => submission.message = bid_trace,
+            SignedBidSubmission::Capella(submission) => submission.message = bid_trace,
+        }
+        Arc::new(submission)
+    }
+
+    async fn store(db: &SqliteDatabaseService, slot: u64, block_hash: u8, value: u64) {
+        store_received_at(db, slot, block_hash, value, block_hash as u64).await;
+    }
+
+    async fn store_received_at(
+        db: &SqliteDatabaseService,
+        slot: u64,
+        block_hash: u8,
+        value: u64,
+        receive: u64,
+    ) {
+        let trace = SubmissionTrace { receive, ..Default::default() };
+        db.store_block_submission(submission(slot, block_hash, value), Arc::new(trace), 0)
+            .await
+            .unwrap();
+    }
+
+    async fn store_header(db: &SqliteDatabaseService, slot: u64, block_hash: u8, receive: u64) {
+        let submission = SignedHeaderSubmission::Capella(SignedHeaderSubmissionCapella {
+            message: HeaderSubmissionCapella {
+                bid_trace: BidTrace {
+                    slot,
+                    block_hash: ByteVector::<32>::try_from([block_hash; 32].as_slice()).unwrap(),
+                    ..Default::default()
+                },
+                execution_payload_header: Default::default(),
+            },
+            signature: Default::default(),
+        });
+        let trace = HeaderSubmissionTrace { receive, ..Default::default() };
+        db.store_header_submission(Arc::new(submission), Arc::new(trace)).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_save_and_get_validator_registration() {
+        let db = test_db();
+        let entry = registration(100);
+        let public_key = entry.registration.message.public_key.clone();
+
+        db.save_validator_registration(entry.clone(), None).await.unwrap();
+        assert!(!db.is_registration_update_required(&entry.registration).await.unwrap());
+
+        let result = db.get_validator_registration(public_key.clone()).await.unwrap();
+        assert_eq!(result.registration_info.registration.message.timestamp, 100);
+        assert_eq!(
+            result.registration_info.preferences.trusted_builders,
+            Some(vec!["test".into()])
+        );
+        assert_eq!(
+            result.registration_info.preferences.min_bid_value,
+            Some(U256::from(1_000_000_000u64))
+        );
+
+        // Older registrations don't overwrite newer ones.
+        let mut stale = entry.clone();
+        stale.registration.message.timestamp = 50;
+        db.save_validator_registrations(vec![stale], None).await.unwrap();
+        let result = db.get_validator_registration(public_key).await.unwrap();
+        assert_eq!(result.registration_info.registration.message.timestamp, 100);
+    }
+
+    #[tokio::test]
+    async fn test_validator_registration_history() {
+        let db = test_db();
+        let entry = registration(100);
+        let public_key = entry.registration.message.public_key.clone();
+        db.save_validator_registration(entry.clone(), None).await.unwrap();
+
+        // Unchanged registrations are not recorded.
+        let mut unchanged = entry.clone();
+        unchanged.registration.message.timestamp = 200;
+        db.save_validator_registration(unchanged, None).await.unwrap();
+
+        let mut changed = entry.clone();
+        changed.registration.message.timestamp = 300;
+        changed.registration.message.fee_recipient =
+            ByteVector::<20>::try_from([1u8; 20].as_slice()).unwrap();
+        db.save_validator_registration(changed.clone(), Some("pool".to_string())).await.unwrap();
+
+        let history = db.get_validator_registration_history(public_key, None).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].timestamp, 300);
+        assert_eq!(history[0].fee_recipient, changed.registration.message.fee_recipient);
+        assert_eq!(
+            history[0].previous_fee_recipient,
+            Some(entry.registration.message.fee_recipient)
+        );
+        assert_eq!(history[0].pool_name.as_deref(), Some("pool"));
+        assert_eq!(history[1].previous_fee_recipient, None);
+    }
+
+    #[tokio::test]
+    async fn test_get_bids() {
+        let db = test_db();
+        store(&db, 1, 1, 300).await;
+        store(&db, 1, 2, 100).await;
+        store(&db, 2, 3, 200).await;
+        // Duplicate submissions keep the earliest first_seen.
+        store(&db, 2, 3, 200).await;
+
+        let bids = db.get_bids(&BidFilters { slot: Some(1), ..Default::default() }).await.unwrap();
+        assert_eq!(bids.len(), 2);
+
+        let filters = BidFilters { order_by: Some(OrderBy::ValueDesc), ..Default::default() };
+        let bids = db.get_bids(&filters).await.unwrap();
+        let values: Vec<U256> = bids.iter().map(|bid| bid.bid_trace.value).collect();
+        assert_eq!(values, vec![U256::from(300), U256::from(200), U256::from(100)]);
+
+        let filters = BidFilters { limit: Some(1), ..Default::default() };
+        let bids = db.get_bids(&filters).await.unwrap();
+        assert_eq!(bids.len(), 1);
+        assert_eq!(bids[0].bid_trace.slot, 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_bids_cursor_pagination() {
+        let db = test_db();
+        store(&db, 1, 1, 100).await;
+        store(&db, 1, 2, 100).await;
+        store(&db, 2, 3, 100).await;
+        store(&db, 3, 4, 100).await;
+        store(&db, 3, 5, 100).await;
+
+        let mut pages = Vec::new();
+        let mut filters = BidFilters { limit: Some(2), ..Default::default() };
+        loop {
+            let bids = db.get_bids(&filters).await.unwrap();
+            let Some(last) = bids.last() else { break };
+            filters.cursor = Some(last.bid_trace.slot);
+            filters.cursor_id = Some(last.submission_id);
+            pages.push(bids.iter().map(|bid| bid.bid_trace.block_hash[0]).collect::<Vec<_>>());
+        }
+        assert_eq!(pages, vec![vec![5, 4], vec![3, 2], vec![1]]);
+
+        // New bids don't shift the pages that follow a cursor.
+        store(&db, 4, 6, 100).await;
+        let filters = BidFilters { cursor: Some(3), cursor_id: Some(5), ..Default::default() };
+        let bids = db.get_bids(&filters).await.unwrap();
+        let hashes: Vec<u8> = bids.iter().map(|bid| bid.bid_trace.block_hash[0]).collect();
+        assert_eq!(hashes, vec![4, 3, 2, 1]);
+    }
+
+    #[tokio::test]
+    async fn test_get_bids_received_at_filters_reported_timestamp() {
+        const MS: u64 = 1_000_000;
+        let db = test_db();
+        store_received_at(&db, 1, 1, 100, 5 * MS).await;
+        store_received_at(&db, 1, 2, 100, 20 * MS).await;
+        // The header of the second bid arrived before its payload.
+        store_header(&db, 1, 2, 8 * MS).await;
+
+        let filters = BidFilters {
+            received_at_from: Some(0),
+            received_at_to: Some(10),
+            ..Default::default()
+        };
+        let bids = db.get_bids(&filters).await.unwrap();
+        let mut timestamps: Vec<u64> = bids.iter().map(|bid| bid.timestamp).collect();
+        timestamps.sort();
+        assert_eq!(timestamps, vec![5 * MS, 8 * MS]);
+
+        let filters = BidFilters { received_at_from: Some(10), ..Default::default() };
+        assert!(db.get_bids(&filters).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_block_submissions() {
+        let db = test_db();
+        store(&db, 1, 1, 300).await;
+        store(&db, 1, 2, 100).await;
+
+        let cutoff = SystemTime::now() - Duration::from_secs(60 * 60);
+        let deleted = db.prune_table(RetentionTable::BlockSubmission, cutoff).await.unwrap();
+        assert_eq!(deleted, 0, "Submissions inside the window should be kept");
+
+        let cutoff = SystemTime::now() + Duration::from_secs(60 * 60);
+        let deleted = db.prune_table(RetentionTable::BlockSubmission, cutoff).await.unwrap();
+        assert_eq!(deleted, 2);
+        assert!(db.get_bids(&BidFilters::default()).await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_prune_get_header_with_bid_history() {
+        let db = test_db();
+        let bid_history = vec![
+            BidHistoryEntry { value: U256::from(100), received_at: 1, ..Default::default() },
+            BidHistoryEntry { value: U256::from(200), received_at: 2, ..Default::default() },
+        ];
+        db.save_get_header_call(
+            1,
+            Default::default(),
+            Default::default(),
+            Default::default(),
+            GetHeaderTrace::default(),
+            None,
+            bid_history,
+        )
+        .await
+        .unwrap();
+
+        let cutoff = SystemTime::now() - Duration::from_secs(60 * 60);
+        let deleted = db.prune_table(RetentionTable::GetHeader, cutoff).await.unwrap();
+        assert_eq!(deleted, 0, "Calls inside the window should be kept");
+
+        // The get_header row and both of its bid history rows
+        let cutoff = SystemTime::now() + Duration::from_secs(60 * 60);
+        let deleted = db.prune_table(RetentionTable::GetHeader, cutoff).await.unwrap();
+        assert_eq!(deleted, 3);
+    }
+
+    #[tokio::test]
+    async fn test_builder_info_and_demotion() {
+        let db = test_db();
+        let public_key = SecretKey::random(&mut rand::thread_rng()).unwrap().public_key();
+        let builder_info = BuilderInfo {
+            collateral: U256::from(1_000_000),
+            is_optimistic: true,
+            builder_id: Some("builder".to_string()),
+            ..Default::default()
+        };
+
+        db.store_builder_info(&public_key, builder_info).await.unwrap();
+        db.db_demote_builder(&public_key, &Default::default(), "test".to_string()).await.unwrap();
+
+        let result = db.db_get_builder_info(&public_key).await.unwrap();
+        assert_eq!(result.collateral, U256::from(1_000_000));
+        assert!(!result.is_optimistic);
+        assert_eq!(db.get_all_builder_infos().await.unwrap().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_builder_info_api_key_and_limits() {
+        let db = test_db();
+        let public_key = SecretKey::random(&mut rand::thread_rng()).unwrap().public_key();
+        db.store_builder_info(&public_key, BuilderInfo::default()).await.unwrap();
+
+        // Keys and limits are managed directly in the table
+        let key_bytes = public_key.as_ref().to_vec();
+        db.with_conn(move |conn| {
+            conn.execute(
+                "
+                    UPDATE builder_info
+                    SET api_key = 'secret', api_key_required = 1,
+                        max_submissions_per_second = 10, max_submission_bytes_per_slot = 1000000
+                    WHERE public_key = ?
+                ",
+                rusqlite::params![key_bytes],
+            )?;
+            Ok(())
+        })
+        .await
+        .unwrap();
+
+        let result = db.db_get_builder_info(&public_key).await.unwrap();
+        assert_eq!(result.api_key_hash, Some(hash_api_key("secret")));
+        assert!(result.is_api_key("secret"));
+        assert!(!result.is_api_key("other"));
+        assert!(result.api_key_required);
+        assert_eq!(result.max_submissions_per_second, Some(10));
+        assert_eq!(result.max_submission_bytes_per_slot, Some(1_000_000));
+    }
+
+    #[tokio::test]
+    async fn test_builder_stats() {
+        let db = test_db();
+        store(&db, 1, 1, 100).await;
+        store(&db, 1, 2, 201).await;
+        store(&db, 2, 3, 300).await;
+        db.save_simulation_result(
+            ByteVector::<32>::try_from([2u8; 32].as_slice()).unwrap(),
+            Err(BlockSimError::Timeout),
+        )
+        .await
+        .unwrap();
+
+        let stats = db.get_builder_stats(&BuilderStatsParams::default()).await.unwrap();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].submissions, 3);
+        assert_eq!(stats[0].slots, 2);
+        assert_eq!(stats[0].avg_value, U256::from(200));
+        assert_eq!(stats[0].max_value, U256::from(300));
+        assert_eq!(stats[0].simulation_failures, 1);
+    }
+}
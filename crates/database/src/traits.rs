@@ -1,4 +1,4 @@
-use std::{collections::HashSet, sync::Arc};
+use std::{collections::HashSet, sync::Arc, time::SystemTime};
 
 use async_trait::async_trait;
 use ethereum_consensus::{
@@ -8,24 +8,31 @@ use ethereum_consensus::{
 
 use helix_common::{
     api::{
-        builder_api::BuilderGetValidatorsResponseEntry, data_api::BidFilters,
+        builder_api::BuilderGetValidatorsResponseEntry,
+        data_api::{BidFilters, BuilderStatsParams},
         proposer_api::ValidatorRegistrationInfo,
     },
+    bid_history::BidHistoryEntry,
     bid_submission::{
         v2::header_submission::SignedHeaderSubmission, BidTrace, SignedBidSubmission,
     },
     builder_info::BuilderInfo,
     deneb::SignedValidatorRegistration,
+    kill_switch::KillSwitchUpdate,
     simulator::BlockSimError,
     versioned_payload::PayloadAndBlobs,
     GetHeaderTrace, GetPayloadTrace, GossipedHeaderTrace, GossipedPayloadTrace,
-    HeaderSubmissionTrace, ProposerInfo, SignedValidatorRegistrationEntry, SubmissionTrace,
-    ValidatorPreferences, ValidatorSummary,
+    HeaderSubmissionTrace, ProposerInfo, RetentionTable, SignedValidatorRegistrationEntry,
+    SubmissionTrace, ValidatorPreferences, ValidatorSummary,
 };
 
 use crate::{
     error::DatabaseError,
-    types::{BidSubmissionDocument, BuilderInfoDocument, DeliveredPayloadDocument},
+    types::{
+        BidSubmissionDocument, BlockTraceEventDocument, BuilderInfoDocument, BuilderStatsDocument,
+        DeliveredPayloadDocument, FailedGetPayloadDocument, GetHeaderCallDocument,
+        SubmissionWriterStats, TooLateGetPayloadDocument, ValidatorRegistrationHistoryDocument,
+    },
 };
 
 #[async_trait]
@@ -62,6 +69,13 @@ pub trait DatabaseService: Send + Sync + Clone {
         pub_key: BlsPublicKey,
     ) -> Result<u64, DatabaseError>;
 
+    /// Returns the recorded registration and preference changes of `pub_key`, newest first.
+    async fn get_validator_registration_history(
+        &self,
+        pub_key: BlsPublicKey,
+        limit: Option<u64>,
+    ) -> Result<Vec<ValidatorRegistrationHistoryDocument>, DatabaseError>;
+
     async fn set_proposer_duties(
         &self,
         proposer_duties: Vec<BuilderGetValidatorsResponseEntry>,
@@ -106,6 +120,11 @@ pub trait DatabaseService: Send + Sync + Clone {
         optimistic_version: i16,
     ) -> Result<(), DatabaseError>;
 
+    /// Counters of the buffered block submission writer. Backends without one report zeros.
+    fn submission_writer_stats(&self) -> SubmissionWriterStats {
+        SubmissionWriterStats::default()
+    }
+
     async fn store_builder_info(
         &self,
         builder_pub_key: &BlsPublicKey,
@@ -121,6 +140,12 @@ pub trait DatabaseService: Send + Sync + Clone {
 
     async fn check_builder_api_key(&self, api_key: &str) -> Result<bool, DatabaseError>;
 
+    /// Returns the builders registered under `api_key`.
+    async fn get_builder_infos_by_api_key(
+        &self,
+        api_key: &str,
+    ) -> Result<Vec<BuilderInfoDocument>, DatabaseError>;
+
     async fn db_demote_builder(
         &self,
         builder_pub_key: &BlsPublicKey,
@@ -145,6 +170,9 @@ pub trait DatabaseService: Send + Sync + Clone {
         validator_preferences: Arc<ValidatorPreferences>,
     ) -> Result<Vec<DeliveredPayloadDocument>, DatabaseError>;
 
+    /// Saves a served get_header call along with `bid_history`, the auction's bids at the time the
+    /// header was served.
+    #[allow(clippy::too_many_arguments)]
     async fn save_get_header_call(
         &self,
         slot: u64,
@@ -153,6 +181,7 @@ pub trait DatabaseService: Send + Sync + Clone {
         best_block_hash: ByteVector<32>,
         trace: GetHeaderTrace,
         user_agent: Option<String>,
+        bid_history: Vec<BidHistoryEntry>,
     ) -> Result<(), DatabaseError>;
 
     async fn save_failed_get_payload(
@@ -163,6 +192,53 @@ pub trait DatabaseService: Send + Sync + Clone {
         trace: GetPayloadTrace,
     ) -> Result<(), DatabaseError>;
 
+    /// Records a kill switch being enabled or disabled, for audit.
+    async fn save_kill_switch_update(&self, update: &KillSwitchUpdate)
+        -> Result<(), DatabaseError>;
+
+    /// Returns get_header calls matching the slot and proposer filters, newest slot first.
+    async fn get_get_header_calls(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<GetHeaderCallDocument>, DatabaseError>;
+
+    /// Returns failed get_payload calls matching the slot and proposer filters, newest slot first.
+    async fn get_failed_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<FailedGetPayloadDocument>, DatabaseError>;
+
+    /// Returns get_payload calls that arrived too late, newest slot first.
+    async fn get_too_late_get_payloads(
+        &self,
+        filters: &BidFilters,
+    ) -> Result<Vec<TooLateGetPayloadDocument>, DatabaseError>;
+
+    /// Returns every recorded trace stage for `block_hash` across all regions, including the
+    /// gossip traces, sorted by timestamp.
+    async fn get_block_trace(
+        &self,
+        block_hash: &Hash32,
+    ) -> Result<Vec<BlockTraceEventDocument>, DatabaseError>;
+
+    /// Returns aggregated builder statistics from the `builder_slot_stats` materialized view,
+    /// sorted by slots won.
+    async fn get_builder_stats(
+        &self,
+        filters: &BuilderStatsParams,
+    ) -> Result<Vec<BuilderStatsDocument>, DatabaseError>;
+
+    /// Refreshes the materialized views backing the builder statistics.
+    async fn refresh_builder_stats(&self) -> Result<(), DatabaseError>;
+
+    /// Deletes rows of `table` older than `cutoff`, keeping rows of delivered payloads.
+    /// Returns the number of deleted rows.
+    async fn prune_table(
+        &self,
+        table: RetentionTable,
+        cutoff: SystemTime,
+    ) -> Result<u64, DatabaseError>;
+
     async fn store_header_submission(
         &self,
         submission: Arc<SignedHeaderSubmission>,
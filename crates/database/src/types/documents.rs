@@ -1,5 +1,5 @@
 use ethereum_consensus::{
-    primitives::{BlsPublicKey, Hash32},
+    primitives::{BlsPublicKey, ExecutionAddress, Hash32},
     ssz::prelude::*,
 };
 use serde::{Deserialize, Serialize};
@@ -7,12 +7,16 @@ use serde::{Deserialize, Serialize};
 use helix_common::{
     api::{
         builder_api::BuilderGetValidatorsResponseEntry,
-        data_api::{DeliveredPayloadsResponse, ReceivedBlocksResponse},
+        data_api::{
+            BuilderStatsResponse, DeliveredPayloadsResponse, FailedGetPayloadResponse,
+            GetHeaderCallResponse, ReceivedBlocksResponse, TooLateGetPayloadResponse,
+            ValidatorRegistrationHistoryResponse,
+        },
     },
     bid_submission::BidTrace,
     builder_info::BuilderInfo,
     simulator::BlockSimError,
-    SubmissionTrace,
+    SubmissionTrace, ValidatorPreferences,
 };
 
 #[derive(Serialize, Deserialize)]
@@ -23,12 +27,74 @@ pub struct ProposerDutiesDocument {
 #[derive(Debug, Serialize, Deserialize)]
 pub struct TooLateGetPayloadDocument {
     pub slot: u64,
-    pub proposer_pub_key: BlsPublicKey,
+    pub region_id: i16,
+    pub proposer_pub_key: Option<BlsPublicKey>,
     pub payload_hash: Hash32,
     pub message_received: u64,
     pub payload_fetched: u64,
 }
 
+impl From<TooLateGetPayloadDocument> for TooLateGetPayloadResponse {
+    fn from(value: TooLateGetPayloadDocument) -> Self {
+        TooLateGetPayloadResponse {
+            slot: value.slot,
+            region_id: value.region_id,
+            block_hash: value.payload_hash,
+            proposer_pubkey: value.proposer_pub_key,
+            message_received: value.message_received,
+            payload_fetched: value.payload_fetched,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct GetHeaderCallDocument {
+    pub slot: u64,
+    pub region_id: i16,
+    pub parent_hash: Hash32,
+    pub proposer_pub_key: BlsPublicKey,
+    pub block_hash: Hash32,
+    pub user_agent: Option<String>,
+    pub timestamp: u64,
+}
+
+impl From<GetHeaderCallDocument> for GetHeaderCallResponse {
+    fn from(value: GetHeaderCallDocument) -> Self {
+        GetHeaderCallResponse {
+            slot: value.slot,
+            region_id: value.region_id,
+            parent_hash: value.parent_hash,
+            proposer_pubkey: value.proposer_pub_key,
+            block_hash: value.block_hash,
+            user_agent: value.user_agent,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FailedGetPayloadDocument {
+    pub slot: u64,
+    pub region_id: i16,
+    pub block_hash: Hash32,
+    pub proposer_pub_key: Option<BlsPublicKey>,
+    pub error: String,
+    pub timestamp: u64,
+}
+
+impl From<FailedGetPayloadDocument> for FailedGetPayloadResponse {
+    fn from(value: FailedGetPayloadDocument) -> Self {
+        FailedGetPayloadResponse {
+            slot: value.slot,
+            region_id: value.region_id,
+            block_hash: value.block_hash,
+            proposer_pubkey: value.proposer_pub_key,
+            error: value.error,
+            timestamp: value.timestamp,
+        }
+    }
+}
+
 #[derive(Debug, Serialize, Deserialize)]
 pub struct DeliveredPayloadDocument {
     pub bid_trace: BidTrace,
@@ -108,6 +174,7 @@ pub struct BidSubmissionDocument {
     pub bid_trace: BidTrace,
     pub block_number: u64,
     pub num_txs: usize,
+    pub submission_id: u64,
 }
 
 impl From<BidSubmissionDocument> for ReceivedBlocksResponse {
@@ -127,6 +194,79 @@ impl From<BidSubmissionDocument> for ReceivedBlocksResponse {
             // Other mev-boost relays return this timestamp in seconds
             timestamp: value.timestamp / 1_000_000_000,
             timestamp_ms: value.timestamp / 1_000_000,
+            submission_id: value.submission_id,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BlockTraceEventDocument {
+    pub region_id: i16,
+    pub source: String,
+    pub stage: String,
+    pub timestamp: u64,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BuilderStatsDocument {
+    pub builder_pubkey: BlsPublicKey,
+    pub builder_id: Option<String>,
+    pub submissions: u64,
+    pub slots: u64,
+    pub slots_won: u64,
+    pub avg_value: U256,
+    pub max_value: U256,
+    pub simulation_failures: u64,
+    pub optimistic_submissions: u64,
+    pub failed_payloads: u64,
+    pub demotions: u64,
+}
+
+impl From<BuilderStatsDocument> for BuilderStatsResponse {
+    fn from(value: BuilderStatsDocument) -> Self {
+        let ratio = |n: u64, d: u64| if d == 0 { 0.0 } else { n as f64 / d as f64 };
+
+        BuilderStatsResponse {
+            submissions_per_slot: ratio(value.submissions, value.slots),
+            win_rate: ratio(value.slots_won, value.slots),
+            simulation_failure_rate: ratio(value.simulation_failures, value.submissions),
+            optimistic_share: ratio(value.optimistic_submissions, value.submissions),
+            builder_pubkey: value.builder_pubkey,
+            builder_id: value.builder_id,
+            submissions: value.submissions,
+            slots: value.slots,
+            slots_won: value.slots_won,
+            avg_value: value.avg_value,
+            max_value: value.max_value,
+            failed_payloads: value.failed_payloads,
+            demotions: value.demotions,
+        }
+    }
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ValidatorRegistrationHistoryDocument {
+    pub public_key: BlsPublicKey,
+    pub fee_recipient: ExecutionAddress,
+    pub previous_fee_recipient: Option<ExecutionAddress>,
+    pub gas_limit: u64,
+    pub timestamp: u64,
+    pub preferences: ValidatorPreferences,
+    pub pool_name: Option<String>,
+    pub inserted_at: u64,
+}
+
+impl From<ValidatorRegistrationHistoryDocument> for ValidatorRegistrationHistoryResponse {
+    fn from(value: ValidatorRegistrationHistoryDocument) -> Self {
+        ValidatorRegistrationHistoryResponse {
+            pubkey: value.public_key,
+            fee_recipient: value.fee_recipient,
+            previous_fee_recipient: value.previous_fee_recipient,
+            gas_limit: value.gas_limit,
+            timestamp: value.timestamp,
+            preferences: value.preferences,
+            pool_name: value.pool_name,
+            inserted_at: value.inserted_at,
         }
     }
 }
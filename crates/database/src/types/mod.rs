@@ -1,3 +1,5 @@
 pub mod documents;
+pub mod submission_writer_stats;
 
 pub use documents::*;
+pub use submission_writer_stats::*;
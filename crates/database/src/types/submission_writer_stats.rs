@@ -0,0 +1,16 @@
+use serde::{Deserialize, Serialize};
+
+/// Point-in-time snapshot of the buffered block submission writer counters.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubmissionWriterStats {
+    pub queue_depth: u64,
+    pub enqueued: u64,
+    pub written: u64,
+    /// Submissions put back in the queue after their batch failed to write.
+    pub retried: u64,
+    /// Submissions discarded after failing `MAX_WRITE_ATTEMPTS` batch writes.
+    pub failed: u64,
+    /// Submissions dropped because the queue was full.
+    pub dropped: u64,
+    pub dropped_transactions: u64,
+}
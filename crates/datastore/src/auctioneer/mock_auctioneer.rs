@@ -6,11 +6,14 @@ use async_trait::async_trait;
 use ethereum_consensus::primitives::{BlsPublicKey, Hash32, U256};
 
 use helix_common::{
+    api::builder_api::{TopBidEvent, TopBidUpdate},
     bellatrix::Node,
+    bid_history::BidHistoryEntry,
     bid_submission::{
         v2::header_submission::SignedHeaderSubmission, BidTrace, SignedBidSubmission,
     },
     eth::SignedBuilderBid,
+    kill_switch::{KillSwitch, KillSwitchScope},
     pending_block::PendingBlock,
     signing::RelaySigningContext,
     versioned_payload::PayloadAndBlobs,
@@ -25,8 +28,15 @@ use crate::{error::AuctioneerError, types::SaveBidAndUpdateTopBidResponse, Aucti
 pub struct MockAuctioneer {
     pub builder_info: Option<BuilderInfo>,
     pub builder_demoted: Arc<AtomicBool>,
+    pub builder_bid_deleted: Arc<AtomicBool>,
+    pub floor_bid_value: Option<U256>,
     pub best_bid: Arc<Mutex<Option<SignedBuilderBid>>>,
     pub versioned_execution_payload: Arc<Mutex<Option<PayloadAndBlobs>>>,
+    pub bid_trace: Arc<Mutex<Option<BidTrace>>>,
+    pub kill_switches: Arc<Mutex<Vec<KillSwitch>>>,
+    pub latest_bid_values: Arc<Mutex<Vec<(BlsPublicKey, U256)>>>,
+    pub builder_submissions_this_second: Arc<Mutex<u64>>,
+    pub builder_submission_bytes_this_slot: Arc<Mutex<u64>>,
 }
 
 impl MockAuctioneer {
@@ -34,8 +44,15 @@ impl MockAuctioneer {
         Self {
             builder_info: None,
             builder_demoted: Arc::new(AtomicBool::new(false)),
+            builder_bid_deleted: Arc::new(AtomicBool::new(false)),
+            floor_bid_value: None,
             best_bid: Arc::new(Mutex::new(None)),
             versioned_execution_payload: Arc::new(Mutex::new(None)),
+            bid_trace: Arc::new(Mutex::new(None)),
+            kill_switches: Arc::new(Mutex::new(Vec::new())),
+            latest_bid_values: Arc::new(Mutex::new(Vec::new())),
+            builder_submissions_this_second: Arc::new(Mutex::new(0)),
+            builder_submission_bytes_this_slot: Arc::new(Mutex::new(0)),
         }
     }
 }
@@ -67,12 +84,19 @@ impl Auctioneer for MockAuctioneer {
         }
         Ok(self.best_bid.lock().unwrap().clone())
     }
+    async fn get_top_bid_update(
+        &self,
+        _slot: u64,
+        _parent_hash: &Hash32,
+        _proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<TopBidUpdate>, AuctioneerError> {
+        Ok(None)
+    }
     async fn get_best_bids(
         &self,
-    ) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin> {
-        Box::new(tokio_stream::iter(
-            vec![Ok(vec![0; 188]), Ok(vec![0; 188]), Ok(vec![0; 188])],
-        ))
+    ) -> Box<dyn Stream<Item = Result<TopBidEvent, AuctioneerError>> + Send + Unpin> {
+        let event = TopBidEvent::new(TopBidUpdate::default(), BlsPublicKey::default());
+        Box::new(tokio_stream::iter(vec![Ok(event.clone()), Ok(event.clone()), Ok(event)]))
     }
 
     async fn save_execution_payload(
@@ -99,7 +123,7 @@ impl Auctioneer for MockAuctioneer {
         _proposer_pub_key: &BlsPublicKey,
         _block_hash: &Hash32,
     ) -> Result<Option<BidTrace>, AuctioneerError> {
-        Ok(None)
+        Ok(self.bid_trace.lock().unwrap().clone())
     }
     async fn save_bid_trace(&self, _bid_trace: &BidTrace) -> Result<(), AuctioneerError> {
         Ok(())
@@ -156,13 +180,30 @@ impl Auctioneer for MockAuctioneer {
     ) -> Result<Option<U256>, AuctioneerError> {
         Ok(None)
     }
+    async fn get_latest_bid_values(
+        &self,
+        _slot: u64,
+        _parent_hash: &Hash32,
+        _proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<(BlsPublicKey, U256)>, AuctioneerError> {
+        Ok(self.latest_bid_values.lock().unwrap().clone())
+    }
     async fn get_floor_bid_value(
         &self,
         _slot: u64,
         _parent_hash: &Hash32,
         _proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError> {
-        Ok(None)
+        Ok(self.floor_bid_value)
+    }
+
+    async fn get_bid_history(
+        &self,
+        _slot: u64,
+        _parent_hash: &Hash32,
+        _proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<BidHistoryEntry>, AuctioneerError> {
+        Ok(vec![])
     }
 
     async fn delete_builder_bid(
@@ -172,6 +213,7 @@ impl Auctioneer for MockAuctioneer {
         _proposer_pub_key: &BlsPublicKey,
         _builder_pub_key: &BlsPublicKey,
     ) -> Result<(), AuctioneerError> {
+        self.builder_bid_deleted.store(true, std::sync::atomic::Ordering::Relaxed);
         Ok(())
     }
 
@@ -206,13 +248,14 @@ impl Auctioneer for MockAuctioneer {
 
     async fn save_signed_builder_bid_and_update_top_bid(
         &self,
-        _builder_bid: &SignedBuilderBid,
+        builder_bid: &SignedBuilderBid,
         _bid_trace: &BidTrace,
         _received_at: u128,
         __cancellations_enabled: bool,
         _floor_value: U256,
         _state: &mut SaveBidAndUpdateTopBidResponse,
     ) -> Result<(), AuctioneerError> {
+        *self.best_bid.lock().unwrap() = Some(builder_bid.clone());
         Ok(())
     }
 
@@ -291,15 +334,46 @@ impl Auctioneer for MockAuctioneer {
         Ok(None)
     }
 
-    async fn kill_switch_enabled(&self) -> Result<bool, AuctioneerError> {
-        Ok(false)
+    async fn get_kill_switches(&self) -> Result<Vec<KillSwitch>, AuctioneerError> {
+        Ok(self.kill_switches.lock().unwrap().clone())
     }
 
-    async fn enable_kill_switch(&self) -> Result<(), AuctioneerError> {
+    async fn enable_kill_switch(&self, kill_switch: &KillSwitch) -> Result<(), AuctioneerError> {
+        let mut kill_switches = self.kill_switches.lock().unwrap();
+        kill_switches.retain(|existing| existing.scope != kill_switch.scope);
+        kill_switches.push(kill_switch.clone());
         Ok(())
     }
 
-    async fn disable_kill_switch(&self) -> Result<(), AuctioneerError> {
+    async fn disable_kill_switch(&self, scope: &KillSwitchScope) -> Result<(), AuctioneerError> {
+        self.kill_switches.lock().unwrap().retain(|existing| existing.scope != *scope);
         Ok(())
     }
+
+    async fn record_builder_submission(
+        &self,
+        _builder_pub_key: &BlsPublicKey,
+    ) -> Result<u64, AuctioneerError> {
+        let mut submissions = self.builder_submissions_this_second.lock().unwrap();
+        *submissions += 1;
+        Ok(*submissions)
+    }
+
+    async fn record_builder_submission_bytes(
+        &self,
+        _builder_pub_key: &BlsPublicKey,
+        _slot: u64,
+        size: u64,
+    ) -> Result<u64, AuctioneerError> {
+        let mut bytes = self.builder_submission_bytes_this_slot.lock().unwrap();
+        *bytes += size;
+        Ok(*bytes)
+    }
+
+    async fn get_proposer_pool(
+        &self,
+        _proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<String>, AuctioneerError> {
+        Ok(None)
+    }
 }
@@ -2,12 +2,15 @@
 use async_trait::async_trait;
 use ethereum_consensus::primitives::{BlsPublicKey, Hash32, U256};
 use helix_common::{
+    api::builder_api::{TopBidEvent, TopBidUpdate},
     bellatrix::Node,
+    bid_history::BidHistoryEntry,
     bid_submission::{
         v2::header_submission::SignedHeaderSubmission, BidTrace, SignedBidSubmission,
     },
     builder_info::BuilderInfo,
     eth::SignedBuilderBid,
+    kill_switch::{KillSwitch, KillSwitchScope, KillSwitchTarget},
     pending_block::PendingBlock,
     signing::RelaySigningContext,
     versioned_payload::PayloadAndBlobs,
@@ -15,7 +18,10 @@ use helix_common::{
 };
 use helix_database::BuilderInfoDocument;
 
-use crate::{error::AuctioneerError, types::SaveBidAndUpdateTopBidResponse};
+use crate::{
+    error::AuctioneerError,
+    types::SaveBidAndUpdateTopBidResponse,
+};
 use tokio_stream::{Stream};
 
 #[async_trait]
@@ -35,9 +41,17 @@ pub trait Auctioneer: Send + Sync + Clone {
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<SignedBuilderBid>, AuctioneerError>;
 
+    /// Returns the top bid of the auction as pushed to top bid subscribers.
+    async fn get_top_bid_update(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<TopBidUpdate>, AuctioneerError>;
+
     async fn get_best_bids(
         &self,
-    ) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin>;
+    ) -> Box<dyn Stream<Item = Result<TopBidEvent, AuctioneerError>> + Send + Unpin>;
 
     async fn save_execution_payload(
         &self,
@@ -102,6 +116,13 @@ pub trait Auctioneer: Send + Sync + Clone {
         proposer_pub_key: &BlsPublicKey,
         builder_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError>;
+    /// Returns every builder's latest bid value in the auction.
+    async fn get_latest_bid_values(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<(BlsPublicKey, U256)>, AuctioneerError>;
     async fn get_floor_bid_value(
         &self,
         slot: u64,
@@ -109,6 +130,14 @@ pub trait Auctioneer: Send + Sync + Clone {
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError>;
 
+    /// Returns every bid saved for the auction, oldest first.
+    async fn get_bid_history(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<BidHistoryEntry>, AuctioneerError>;
+
     async fn delete_builder_bid(
         &self,
         slot: u64,
@@ -203,9 +232,61 @@ pub trait Auctioneer: Send + Sync + Clone {
         block_hash: &Hash32,
     ) -> Result<Option<Node>, AuctioneerError>;
 
-    async fn kill_switch_enabled(&self) -> Result<bool, AuctioneerError>;
+    /// Returns the kill switches that haven't expired.
+    async fn get_kill_switches(&self) -> Result<Vec<KillSwitch>, AuctioneerError>;
+
+    /// Enables `kill_switch`, replacing any switch with the same scope.
+    async fn enable_kill_switch(&self, kill_switch: &KillSwitch) -> Result<(), AuctioneerError>;
+
+    async fn disable_kill_switch(&self, scope: &KillSwitchScope) -> Result<(), AuctioneerError>;
+
+    /// Counts a submission from `builder_pub_key` and returns how many it made this second,
+    /// including this one. Counters are shared by all relay instances.
+    async fn record_builder_submission(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<u64, AuctioneerError>;
+
+    /// Counts `size` submitted bytes from `builder_pub_key` towards `slot` and returns the slot's
+    /// total, including them. Counters are shared by all relay instances.
+    async fn record_builder_submission_bytes(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        slot: u64,
+        size: u64,
+    ) -> Result<u64, AuctioneerError>;
+
+    /// Returns the name of the pool a trusted proposer registered through.
+    async fn get_proposer_pool(
+        &self,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<String>, AuctioneerError>;
+
+    /// Returns the first active kill switch that applies to `target`. The proposer's pool is
+    /// looked up only if a pool switch is active and the target doesn't already name one.
+    async fn find_kill_switch(
+        &self,
+        target: KillSwitchTarget<'_>,
+    ) -> Result<Option<KillSwitch>, AuctioneerError> {
+        let kill_switches = self.get_kill_switches().await?;
+        if kill_switches.is_empty() {
+            return Ok(None);
+        }
 
-    async fn enable_kill_switch(&self) -> Result<(), AuctioneerError>;
+        let has_pool_switch = kill_switches
+            .iter()
+            .any(|kill_switch| matches!(kill_switch.scope, KillSwitchScope::ProposerPool(_)));
+        let proposer_pool = match (target.proposer_pool, target.proposer_pubkey) {
+            (None, Some(proposer_pubkey)) if has_pool_switch => {
+                self.get_proposer_pool(proposer_pubkey).await?
+            }
+            _ => None,
+        };
+        let target = KillSwitchTarget {
+            proposer_pool: target.proposer_pool.or(proposer_pool.as_deref()),
+            ..target
+        };
 
-    async fn disable_kill_switch(&self) -> Result<(), AuctioneerError>;
+        Ok(kill_switches.into_iter().find(|kill_switch| kill_switch.scope.matches(&target)))
+    }
 }
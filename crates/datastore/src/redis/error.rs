@@ -12,15 +12,27 @@ pub enum RedisCacheError {
     #[error("redis create pool error: {0}")]
     CreatePoolError(#[from] deadpool_redis::CreatePoolError),
 
+    #[error("no sentinel knows a primary for master {master_name}")]
+    PrimaryNotFound { master_name: String },
+
+    #[error("invalid redis primary url: {0}")]
+    InvalidPrimaryUrl(#[from] url::ParseError),
+
     #[error("redis copy error. Could not copy from {from} to {to}")]
     RedisCopyError { from: String, to: String },
 
+    #[error("builders kept joining the auction while the bid was being saved")]
+    BidBuildersChanged,
+
     #[error("unexpected value type")]
     UnexpectedValueType,
 
     #[error("serde_json error: {0}")]
     SerdeJsonError(#[from] serde_json::Error),
 
+    #[error("zstd error: {0}")]
+    ZstdError(std::io::Error),
+
     #[error("from utf8 error: {0}")]
     FromUtf8Error(#[from] std::string::FromUtf8Error),
 
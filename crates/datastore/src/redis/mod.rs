@@ -1,3 +1,4 @@
 pub mod error;
+pub mod pool;
 pub mod redis_cache;
 mod utils;
@@ -0,0 +1,317 @@
+use std::{sync::RwLock, time::Duration};
+
+use deadpool_redis::{Config, Pool, Runtime};
+use helix_common::{RedisConfig, RedisSentinelConfig};
+use redis::{
+    aio::{ConnectionLike, PubSub},
+    cluster::ClusterClient,
+    cluster_async::ClusterConnection,
+    Cmd, Pipeline, RedisFuture, Value,
+};
+use tokio::sync::watch;
+use tracing::{error, info, warn};
+use url::Url;
+
+use crate::redis::error::RedisCacheError;
+
+const SENTINEL_POLL_INTERVAL: Duration = Duration::from_secs(1);
+
+/// Connections to a single Redis node, a Sentinel-managed primary or a Redis Cluster.
+pub enum RedisPool {
+    Standalone(Pool),
+    Sentinel(SentinelPool),
+    Cluster { nodes: Vec<String>, conn: ClusterConnection },
+}
+
+/// A pool to the current primary of a Sentinel-monitored master. The primary is re-resolved in
+/// the background and the pool swapped when it changes.
+pub struct SentinelPool {
+    config: RedisSentinelConfig,
+    pool: RwLock<Pool>,
+    primary_tx: watch::Sender<String>,
+}
+
+pub enum RedisConnection {
+    Pooled(deadpool_redis::Connection),
+    Cluster(ClusterConnection),
+}
+
+impl RedisPool {
+    pub fn standalone(url: &str) -> Result<Self, RedisCacheError> {
+        Ok(Self::Standalone(create_pool(url)?))
+    }
+
+    pub async fn from_config(config: &RedisConfig) -> Result<Self, RedisCacheError> {
+        if let Some(nodes) = &config.cluster_nodes {
+            let conn = ClusterClient::new(nodes.clone())?.get_async_connection().await?;
+            return Ok(Self::Cluster { nodes: nodes.clone(), conn });
+        }
+
+        if let Some(sentinel) = &config.sentinel {
+            let primary = resolve_primary(sentinel).await?;
+            info!(
+                primary = %primary,
+                master_name = %sentinel.master_name,
+                "resolved redis primary"
+            );
+            return Ok(Self::Sentinel(SentinelPool::new(sentinel.clone(), primary)?));
+        }
+
+        Self::standalone(&config.url)
+    }
+
+    pub fn is_cluster(&self) -> bool {
+        matches!(self, Self::Cluster { .. })
+    }
+
+    pub async fn get(&self) -> Result<RedisConnection, RedisCacheError> {
+        match self {
+            Self::Standalone(pool) => Ok(RedisConnection::Pooled(pool.get().await?)),
+            Self::Sentinel(sentinel) => {
+                let pool =
+                    sentinel.pool.read().map_err(|_| RedisCacheError::InternalError)?.clone();
+                Ok(RedisConnection::Pooled(pool.get().await?))
+            }
+            Self::Cluster { conn, .. } => Ok(RedisConnection::Cluster(conn.clone())),
+        }
+    }
+
+    /// Opens a dedicated pub/sub connection. Published messages are forwarded to every cluster
+    /// node, so subscribing on the first seed node that accepts a connection is enough.
+    pub async fn pubsub(&self) -> Result<PubSub, RedisCacheError> {
+        match self {
+            Self::Standalone(pool) => {
+                Ok(deadpool_redis::Connection::take(pool.get().await?).into_pubsub())
+            }
+            Self::Sentinel(sentinel) => {
+                let pool =
+                    sentinel.pool.read().map_err(|_| RedisCacheError::InternalError)?.clone();
+                Ok(deadpool_redis::Connection::take(pool.get().await?).into_pubsub())
+            }
+            Self::Cluster { nodes, .. } => {
+                let mut last_err = None;
+                for node in nodes {
+                    let conn = match redis::Client::open(node.as_str()) {
+                        Ok(client) => client.get_async_connection().await,
+                        Err(err) => Err(err),
+                    };
+                    match conn {
+                        Ok(conn) => return Ok(conn.into_pubsub()),
+                        Err(err) => {
+                            warn!(err = %err, node = %node, "failed to connect to cluster node");
+                            last_err = Some(err);
+                        }
+                    }
+                }
+                Err(last_err.map_or(RedisCacheError::InternalError, RedisCacheError::from))
+            }
+        }
+    }
+
+    /// Notified with the new primary after a Sentinel failover.
+    pub fn subscribe_failover(&self) -> Option<watch::Receiver<String>> {
+        match self {
+            Self::Sentinel(sentinel) => Some(sentinel.primary_tx.subscribe()),
+            _ => None,
+        }
+    }
+
+    /// Polls the sentinels and swaps the pool when the primary changes. Returns immediately for
+    /// pools that aren't Sentinel-managed.
+    pub async fn monitor_failover(&self) {
+        let Self::Sentinel(sentinel) = self else {
+            return;
+        };
+
+        let mut interval = tokio::time::interval(SENTINEL_POLL_INTERVAL);
+        loop {
+            interval.tick().await;
+
+            match resolve_primary(&sentinel.config).await {
+                Ok(primary) => {
+                    sentinel.swap_primary(primary);
+                }
+                Err(err) => error!(err = %err, "failed to resolve redis primary"),
+            }
+        }
+    }
+}
+
+impl SentinelPool {
+    pub fn new(config: RedisSentinelConfig, primary: String) -> Result<Self, RedisCacheError> {
+        let pool = RwLock::new(create_pool(&primary)?);
+        let (primary_tx, _) = watch::channel(primary);
+        Ok(Self { config, pool, primary_tx })
+    }
+
+    /// Points the pool at `primary` and notifies failover subscribers. Returns false if it
+    /// already was the primary or the new pool couldn't be created.
+    pub(crate) fn swap_primary(&self, primary: String) -> bool {
+        if *self.primary_tx.borrow() == primary {
+            return false;
+        }
+
+        let pool = match create_pool(&primary) {
+            Ok(pool) => pool,
+            Err(err) => {
+                error!(
+                    err = %err,
+                    primary = %primary,
+                    "failed to create pool for new redis primary"
+                );
+                return false;
+            }
+        };
+        match self.pool.write() {
+            Ok(mut current) => *current = pool,
+            Err(_) => {
+                error!("redis primary pool lock poisoned");
+                return false;
+            }
+        }
+
+        warn!(
+            primary = %primary,
+            master_name = %self.config.master_name,
+            "redis primary changed"
+        );
+        self.primary_tx.send_replace(primary);
+        true
+    }
+}
+
+impl ConnectionLike for RedisConnection {
+    fn req_packed_command<'a>(&'a mut self, cmd: &'a Cmd) -> RedisFuture<'a, Value> {
+        match self {
+            Self::Pooled(conn) => conn.req_packed_command(cmd),
+            Self::Cluster(conn) => conn.req_packed_command(cmd),
+        }
+    }
+
+    fn req_packed_commands<'a>(
+        &'a mut self,
+        cmd: &'a Pipeline,
+        offset: usize,
+        count: usize,
+    ) -> RedisFuture<'a, Vec<Value>> {
+        match self {
+            Self::Pooled(conn) => conn.req_packed_commands(cmd, offset, count),
+            Self::Cluster(conn) => conn.req_packed_commands(cmd, offset, count),
+        }
+    }
+
+    fn get_db(&self) -> i64 {
+        match self {
+            Self::Pooled(conn) => conn.get_db(),
+            Self::Cluster(conn) => conn.get_db(),
+        }
+    }
+}
+
+fn create_pool(url: &str) -> Result<Pool, RedisCacheError> {
+    Ok(Config::from_url(url).create_pool(Some(Runtime::Tokio1))?)
+}
+
+/// Asks each sentinel in turn for the current primary of the configured master.
+async fn resolve_primary(config: &RedisSentinelConfig) -> Result<String, RedisCacheError> {
+    for url in &config.urls {
+        let mut conn = match redis::Client::open(url.as_str()) {
+            Ok(client) => match client.get_async_connection().await {
+                Ok(conn) => conn,
+                Err(err) => {
+                    warn!(err = %err, sentinel = %url, "failed to connect to sentinel");
+                    continue;
+                }
+            },
+            Err(err) => {
+                warn!(err = %err, sentinel = %url, "invalid sentinel url");
+                continue;
+            }
+        };
+
+        let addr: Option<(String, u16)> = match redis::cmd("SENTINEL")
+            .arg("get-master-addr-by-name")
+            .arg(&config.master_name)
+            .query_async(&mut conn)
+            .await
+        {
+            Ok(addr) => addr,
+            Err(err) => {
+                warn!(err = %err, sentinel = %url, "failed to query sentinel");
+                continue;
+            }
+        };
+
+        if let Some((host, port)) = addr {
+            return primary_url(&host, port, config.password.as_deref());
+        }
+    }
+
+    Err(RedisCacheError::PrimaryNotFound { master_name: config.master_name.clone() })
+}
+
+/// Builds the url of a primary reported by a sentinel, percent-encoding the password.
+fn primary_url(host: &str, port: u16, password: Option<&str>) -> Result<String, RedisCacheError> {
+    let mut url = Url::parse(&format!("redis://{host}:{port}/"))?;
+    if password.is_some() {
+        url.set_password(password).map_err(|_| RedisCacheError::InternalError)?;
+    }
+    Ok(url.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sentinel_pool(primary: &str) -> SentinelPool {
+        let config = RedisSentinelConfig {
+            urls: vec!["redis://127.0.0.1:26379/".to_string()],
+            master_name: "mymaster".to_string(),
+            password: None,
+        };
+        SentinelPool::new(config, primary.to_string()).unwrap()
+    }
+
+    #[test]
+    fn test_primary_url() {
+        assert_eq!(primary_url("10.0.0.1", 6379, None).unwrap(), "redis://10.0.0.1:6379/");
+        assert_eq!(
+            primary_url("10.0.0.1", 6379, Some("secret")).unwrap(),
+            "redis://:secret@10.0.0.1:6379/"
+        );
+        assert_eq!(
+            primary_url("10.0.0.1", 6379, Some("p@ss:w/rd#1")).unwrap(),
+            "redis://:p%40ss%3Aw%2Frd%231@10.0.0.1:6379/"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_from_config_standalone() {
+        let config = RedisConfig { url: "redis://127.0.0.1/".to_string(), ..Default::default() };
+        let pool = RedisPool::from_config(&config).await.unwrap();
+        assert!(matches!(pool, RedisPool::Standalone(_)));
+        assert!(!pool.is_cluster());
+        assert!(pool.subscribe_failover().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_swap_primary_notifies_subscribers() {
+        let pool = RedisPool::Sentinel(sentinel_pool("redis://10.0.0.1:6379/"));
+        let RedisPool::Sentinel(sentinel) = &pool else { unreachable!() };
+        let mut failover = pool.subscribe_failover().unwrap();
+
+        // Re-resolving the same primary keeps the pool
+        assert!(!sentinel.swap_primary("redis://10.0.0.1:6379/".to_string()));
+        assert!(!failover.has_changed().unwrap());
+
+        assert!(sentinel.swap_primary("redis://10.0.0.2:6379/".to_string()));
+        failover.changed().await.unwrap();
+        assert_eq!(*failover.borrow(), "redis://10.0.0.2:6379/");
+    }
+
+    #[tokio::test]
+    async fn test_monitor_failover_returns_for_standalone() {
+        let pool = RedisPool::standalone("redis://127.0.0.1/").unwrap();
+        tokio::time::timeout(Duration::from_secs(1), pool.monitor_failover()).await.unwrap();
+    }
+}
@@ -1,23 +1,28 @@
-use std::collections::HashMap;
+use std::{
+    collections::HashMap,
+    sync::{Arc, RwLock},
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
 
 use async_trait::async_trait;
-use deadpool_redis::{Config, CreatePoolError, Pool, Runtime};
 use ethereum_consensus::{
     primitives::{BlsPublicKey, Hash32},
-    ssz::{self, prelude::*},
+    ssz::prelude::*,
 };
 use futures_util::TryStreamExt;
 use helix_common::{
-    api::builder_api::TopBidUpdate,
+    api::builder_api::{TopBidEvent, TopBidUpdate},
+    bid_history::BidHistoryEntry,
     bid_submission::{v2::header_submission::SignedHeaderSubmission, BidSubmission},
+    kill_switch::{KillSwitch, KillSwitchScope},
     pending_block::PendingBlock,
     versioned_payload::PayloadAndBlobs,
-    ProposerInfo,
+    ProposerInfo, RedisConfig, Route,
 };
 use redis::{AsyncCommands, RedisResult, Script, Value};
 use serde::{de::DeserializeOwned, Serialize};
 use tokio::sync::broadcast;
-use tracing::error;
+use tracing::{error, warn};
 
 use helix_common::{
     bid_submission::{BidTrace, SignedBidSubmission},
@@ -33,18 +38,21 @@ use crate::{
     error::AuctioneerError,
     redis::{
         error::RedisCacheError,
+        pool::RedisPool,
         utils::{
-            get_builder_latest_bid_time_key, get_builder_latest_bid_value_key,
-            get_cache_bid_trace_key, get_cache_get_header_response_key, get_execution_payload_key,
-            get_floor_bid_key, get_floor_bid_value_key, get_latest_bid_by_builder_key,
+            decode, encode_compressed, get_bid_history_key, get_builder_latest_bid_time_key,
+            get_builder_latest_bid_value_key, get_cache_bid_trace_key,
+            get_cache_get_header_response_key, get_execution_payload_key, get_floor_bid_key,
+            get_floor_bid_value_key, get_latest_bid_by_builder_key,
             get_latest_bid_by_builder_key_str_builder_pub_key, get_seen_block_hashes_key,
             get_top_bid_value_key,
         },
     },
     types::{
         keys::{
-            BUILDER_INFO_KEY, HOUSEKEEPER_LOCK_KEY, KILL_SWITCH,
-            LAST_HASH_DELIVERED_KEY, LAST_SLOT_DELIVERED_KEY, PRIMEV_PROPOSERS_KEY,
+            BUILDER_INFO_KEY, CLUSTER_LAST_HASH_DELIVERED_KEY, CLUSTER_LAST_SLOT_DELIVERED_KEY,
+            HOUSEKEEPER_LOCK_KEY, KILL_SWITCHES_KEY, LAST_HASH_DELIVERED_KEY,
+            LAST_SLOT_DELIVERED_KEY, LEGACY_KILL_SWITCH_KEY, PRIMEV_PROPOSERS_KEY,
             PROPOSER_WHITELIST_KEY,
         },
         signed_builder_bid_wrapper::SignedBuilderBidWrapper,
@@ -54,15 +62,22 @@ use crate::{
 };
 
 use super::utils::{
-    get_hash_from_hex, get_header_tx_root_key, get_pending_block_builder_block_hash_key,
+    get_builder_submission_bytes_key, get_builder_submission_count_key, get_hash_from_hex,
+    get_header_tx_root_key, get_pending_block_builder_block_hash_key,
     get_pending_block_builder_key, get_pubkey_from_hex,
 };
 
 const BID_CACHE_EXPIRY_S: usize = 45;
 const PENDING_BLOCK_EXPIRY_S: usize = 45;
 const HOUSEKEEPER_LOCK_EXPIRY_MS: usize = 45_000;
+const BUILDER_SUBMISSION_COUNT_EXPIRY_S: usize = 2;
+/// How often the local copy of the kill switches is reloaded, to pick up switches set by other
+/// instances sharing this Redis.
+const KILL_SWITCH_REFRESH_INTERVAL: Duration = Duration::from_secs(1);
 
 const BEST_BIDS_CHANNEL: &str = "best_bids";
+/// Times a bid is retried when builders start bidding on the auction while it is being saved.
+const SAVE_BID_ATTEMPTS: usize = 10;
 
 const RENEW_SCRIPT: &str = r#"
 if redis.call('get', KEYS[1]) == ARGV[1] then
@@ -71,44 +86,233 @@ end
 return nil
 "#;
 
+/// Saves a builder bid, recomputes the top bid and floor, copies the new get_header response and
+/// publishes it in a single call, so concurrent submissions can't interleave between the steps.
+///
+/// U256 values are stored as JSON hex strings and compared by their significant digits, as Lua
+/// numbers can't represent them.
+///
+/// KEYS: latest bid, bid times, bid values, get_header response, top bid value, floor bid, floor
+/// bid value, bid history, then the latest bid of each builder in ARGV[10..].
+/// ARGV: builder pub key, latest bid, received at, bid value, floor value, expiry, set new floor,
+/// best bids channel, bid history entry, then the pub keys of the builders bidding on the auction.
+///
+/// Returns `[was_saved, was_top_bid_updated, is_new_top_bid, top_bid_value,
+/// prev_top_bid_value]`. Nothing is saved if a builder of the auction has no latest bid key in
+/// KEYS, so the caller can retry with the new builders.
+const SAVE_BID_AND_UPDATE_TOP_BID_SCRIPT: &str = r#"
+local function digits(value)
+    local hex = string.gsub(string.gsub(value, '"', ''), '^0x', '')
+    return (string.gsub(hex, '^0+', ''))
+end
+local function gt(a, b)
+    local x, y = digits(a), digits(b)
+    if #x ~= #y then
+        return #x > #y
+    end
+    return x > y
+end
+
+local bid_keys = {[ARGV[1]] = KEYS[1]}
+for i = 10, #ARGV do
+    bid_keys[ARGV[i]] = KEYS[i - 1]
+end
+
+local bids = {}
+local prev_top = ARGV[5]
+local stored = redis.call('HGETALL', KEYS[3])
+for i = 1, #stored, 2 do
+    if bid_keys[stored[i]] == nil then
+        return {0, 0, 0, '', ''}
+    end
+    bids[stored[i]] = stored[i + 1]
+    if gt(stored[i + 1], prev_top) then
+        prev_top = stored[i + 1]
+    end
+end
+
+redis.call('SET', KEYS[1], ARGV[2], 'EX', ARGV[6])
+redis.call('HSET', KEYS[2], ARGV[1], ARGV[3])
+redis.call('EXPIRE', KEYS[2], ARGV[6])
+redis.call('HSET', KEYS[3], ARGV[1], ARGV[4])
+redis.call('EXPIRE', KEYS[3], ARGV[6])
+redis.call('RPUSH', KEYS[8], ARGV[9])
+redis.call('EXPIRE', KEYS[8], ARGV[6])
+bids[ARGV[1]] = ARGV[4]
+
+local top_builder, top = nil, nil
+for builder, value in pairs(bids) do
+    if top == nil or gt(value, top) then
+        top_builder, top = builder, value
+    end
+end
+
+-- TODO: the floor may have raised but we will exit early here.
+if digits(top) == digits(prev_top) then
+    return {1, 0, 0, top, prev_top}
+end
+
+local source, top_value = bid_keys[top_builder], top
+if gt(ARGV[5], top) then
+    source, top_value = KEYS[6], ARGV[5]
+end
+if redis.call('COPY', source, KEYS[4], 'REPLACE') == 0 then
+    return redis.error_reply('could not copy from ' .. source .. ' to ' .. KEYS[4])
+end
+redis.call('EXPIRE', KEYS[4], ARGV[6])
+redis.call('SET', KEYS[5], top_value, 'EX', ARGV[6])
+redis.call('PUBLISH', ARGV[8], KEYS[4])
+
+if ARGV[7] == '1' then
+    redis.call('COPY', KEYS[1], KEYS[6], 'REPLACE')
+    redis.call('EXPIRE', KEYS[6], ARGV[6])
+    redis.call('SET', KEYS[7], ARGV[4], 'EX', ARGV[6])
+end
+
+local was_top_bid_updated = digits(top_value) ~= digits(prev_top)
+local is_new_top_bid = digits(ARGV[4]) == digits(top_value)
+return {1, was_top_bid_updated and 1 or 0, is_new_top_bid and 1 or 0, top_value, prev_top}
+"#;
+
 #[derive(Clone)]
 pub struct RedisCache {
-    pool: Pool,
-    tx: broadcast::Sender<Vec<u8>>,
+    pool: Arc<RedisPool>,
+    tx: broadcast::Sender<TopBidEvent>,
+    /// Active kill switches, checked on every request without a round trip to Redis.
+    kill_switches: Arc<RwLock<Vec<KillSwitch>>>,
+    /// Whether bids and payloads are written zstd-compressed. Reads accept both formats.
+    compress_values: bool,
 }
 
 impl RedisCache {
     pub async fn new(
         conn_str: &str,
         builder_infos: Vec<BuilderInfoDocument>,
-    ) -> Result<Self, CreatePoolError> {
-        let cfg = Config::from_url(conn_str);
-        let pool = cfg.create_pool(Some(Runtime::Tokio1))?;
+    ) -> Result<Self, RedisCacheError> {
+        Self::with_pool(RedisPool::standalone(conn_str)?, builder_infos, false).await
+    }
+
+    /// Connects to a single node, a Sentinel-managed primary or a Redis Cluster depending on the
+    /// config. Sentinel pools follow failovers in the background.
+    pub async fn from_config(
+        config: &RedisConfig,
+        builder_infos: Vec<BuilderInfoDocument>,
+    ) -> Result<Self, RedisCacheError> {
+        let pool = RedisPool::from_config(config).await?;
+        let cache = Self::with_pool(pool, builder_infos, config.compress_values).await?;
+
+        let pool = cache.pool.clone();
+        tokio::spawn(async move { pool.monitor_failover().await });
+
+        Ok(cache)
+    }
+
+    async fn with_pool(
+        pool: RedisPool,
+        builder_infos: Vec<BuilderInfoDocument>,
+        compress_values: bool,
+    ) -> Result<Self, RedisCacheError> {
+        let pool = Arc::new(pool);
         let (tx, mut rx) = broadcast::channel(1000);
 
         // ensure at least one subscriber is running
         tokio::spawn(async move { while let Ok(_message) = rx.recv().await {} });
 
-        let cache = Self { pool, tx };
+        let cache =
+            Self { pool, tx, kill_switches: Arc::new(RwLock::new(Vec::new())), compress_values };
 
         // Load in builder info
         if let Err(err) = cache.update_builder_infos(builder_infos).await {
             error!(err=%err, "Failed to initialise builder info")
         }
 
+        // Load in kill switches and keep them up to date
+        if let Err(err) = cache.migrate_legacy_kill_switch().await {
+            error!(err=%err, "Failed to migrate legacy kill switch")
+        }
+        let refresh_cache = cache.clone();
+        tokio::spawn(async move {
+            let mut interval = tokio::time::interval(KILL_SWITCH_REFRESH_INTERVAL);
+            loop {
+                interval.tick().await;
+                if let Err(err) = refresh_cache.refresh_kill_switches().await {
+                    error!(err=%err, "Failed to refresh kill switches")
+                }
+            }
+        });
+
         Ok(cache)
     }
 
+    /// Reloads the kill switches from Redis, deleting the ones that have expired.
+    async fn refresh_kill_switches(&self) -> Result<(), RedisCacheError> {
+        let kill_switches: Option<HashMap<String, KillSwitch>> =
+            self.hgetall(KILL_SWITCHES_KEY).await?;
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+
+        let mut active = Vec::new();
+        for (field, kill_switch) in kill_switches.unwrap_or_default() {
+            if kill_switch.is_expired(now_ms as u64) {
+                // Hash fields can't expire on their own
+                self.hdel(KILL_SWITCHES_KEY, &field).await?;
+            } else {
+                active.push(kill_switch);
+            }
+        }
+
+        *self.kill_switches.write().map_err(|_| RedisCacheError::InternalError)? = active;
+        Ok(())
+    }
+
+    /// Carries over the single kill switch from before switches were scoped. It only ever
+    /// turned off get_header.
+    async fn migrate_legacy_kill_switch(&self) -> Result<(), RedisCacheError> {
+        let Some(enabled) = self.get::<bool>(LEGACY_KILL_SWITCH_KEY).await? else {
+            return Ok(());
+        };
+
+        if enabled {
+            let kill_switch = KillSwitch {
+                scope: KillSwitchScope::Route(Route::GetHeader),
+                reason: "enabled before kill switches were scoped".to_string(),
+                expires_at_ms: None,
+            };
+            let field = serde_json::to_string(&kill_switch.scope)?;
+            self.hset(KILL_SWITCHES_KEY, &field, &kill_switch).await?;
+            warn!("migrated enabled legacy kill switch to a get_header kill switch");
+        }
+        self.clear_key(LEGACY_KILL_SWITCH_KEY).await
+    }
+
+    /// Forwards published top bids to local subscribers. Returns when the subscription ends or
+    /// the Sentinel primary changes, so the caller can resubscribe.
     pub async fn start_best_bid_listener(&self) -> Result<(), RedisCacheError> {
-        let conn = self.pool.get().await?;
-        let mut pubsub = deadpool_redis::Connection::take(conn).into_pubsub();
+        let mut failover = self.pool.subscribe_failover();
+        let mut pubsub = self.pool.pubsub().await?;
         pubsub.subscribe(BEST_BIDS_CHANNEL).await?;
 
         let mut message_stream = pubsub.on_message();
 
         let mut conn = self.pool.get().await?;
 
-        while let Some(message) = message_stream.next().await {
+        loop {
+            let message = tokio::select! {
+                message = message_stream.next() => message,
+                Ok(()) = async {
+                    match failover.as_mut() {
+                        Some(failover) => failover.changed().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    warn!("redis primary changed, resubscribing to best bids");
+                    return Ok(());
+                }
+            };
+            let Some(message) = message else {
+                warn!("best bids subscription ended");
+                return Ok(());
+            };
+
             let payload: String = match message.get_payload() {
                 Ok(payload) => payload,
                 Err(err) => {
@@ -117,14 +321,14 @@ impl RedisCache {
                 }
             };
 
-            let data: String = match conn.get(payload).await {
+            let data: Vec<u8> = match conn.get(payload).await {
                 Ok(data) => data,
                 Err(err) => {
                     error!(err=%err, "Failed to get data from redis");
                     continue;
                 }
             };
-            let sig_bid: SignedBuilderBidWrapper = match serde_json::from_str(&data) {
+            let sig_bid: SignedBuilderBidWrapper = match decode(&data) {
                 Ok(sig_bid) => sig_bid,
                 Err(err) => {
                     error!(err=%err, "Failed to deserialize data");
@@ -132,23 +336,14 @@ impl RedisCache {
                 }
             };
 
-            let top_bid_update: TopBidUpdate = sig_bid.into();
-            //ssz encode the top bid update
-            let serialized = match ssz::prelude::serialize(&top_bid_update) {
-                Ok(serialized) => serialized,
-                Err(err) => {
-                    error!(err=%err, "Failed to serialize top bid update");
-                    continue;
-                }
-            };
+            let proposer_pubkey = sig_bid.proposer_pub_key.clone();
+            let top_bid_event = TopBidEvent::new(sig_bid.into(), proposer_pubkey);
 
-            if let Err(err) = self.tx.send(serialized) {
+            if let Err(err) = self.tx.send(top_bid_event) {
                 error!(err=%err, "Failed to send top bid update");
                 continue;
             }
         }
-
-        Ok(())
     }
 
     async fn get<T: DeserializeOwned>(&self, key: &str) -> Result<Option<T>, RedisCacheError> {
@@ -157,7 +352,7 @@ impl RedisCache {
         if let redis::Value::Nil = value {
             Ok(None)
         } else if let redis::Value::Data(data) = value {
-            let deserialized: T = serde_json::from_slice(&data)?;
+            let deserialized: T = decode(&data)?;
             Ok(Some(deserialized))
         } else {
             Err(RedisCacheError::UnexpectedValueType)
@@ -206,7 +401,6 @@ impl RedisCache {
         Ok(entries)
     }
 
-    #[allow(dead_code)]
     async fn lrange<T: DeserializeOwned>(
         &self,
         key: &str,
@@ -240,6 +434,51 @@ impl RedisCache {
         }
     }
 
+    /// Increments the counter at `key` by `delta`, (re)setting its expiry, and returns the new
+    /// value.
+    async fn incr_with_expiry(
+        &self,
+        key: &str,
+        delta: u64,
+        expiry: usize,
+    ) -> Result<u64, RedisCacheError> {
+        let mut conn = self.pool.get().await?;
+        let (value,): (u64,) = redis::pipe()
+            .atomic()
+            .incr(key, delta)
+            .expire(key, expiry)
+            .ignore()
+            .query_async(&mut conn)
+            .await?;
+        Ok(value)
+    }
+
+    /// Like `set`, but stores the value as zstd-compressed JSON when `compress_values` is on.
+    /// Read back with `get`.
+    async fn set_compressed(
+        &self,
+        key: &str,
+        value: &impl Serialize,
+        expiry: Option<usize>,
+    ) -> Result<(), RedisCacheError> {
+        let mut conn = self.pool.get().await?;
+        let encoded = self.encode(value)?;
+
+        match expiry {
+            Some(expiry) => Ok(conn.set_ex(key, encoded, expiry).await?),
+            None => Ok(conn.set(key, encoded).await?),
+        }
+    }
+
+    /// Serializes `value` as JSON, compressed if `compress_values` is on.
+    fn encode(&self, value: &impl Serialize) -> Result<Vec<u8>, RedisCacheError> {
+        if self.compress_values {
+            encode_compressed(value)
+        } else {
+            Ok(serde_json::to_vec(value)?)
+        }
+    }
+
     async fn publish(&self, channel: &str, key: &str) -> Result<(), RedisCacheError> {
         let mut conn = self.pool.get().await?;
         Ok(conn.publish(channel, key).await?)
@@ -370,7 +609,6 @@ impl RedisCache {
         Ok(conn.hdel(key, field).await?)
     }
 
-    #[allow(dead_code)]
     async fn clear_key(&self, key: &str) -> Result<(), RedisCacheError> {
         let mut conn = self.pool.get().await?;
         Ok(conn.del(key).await?)
@@ -444,8 +682,9 @@ impl RedisCache {
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<HashMap<String, U256>, RedisCacheError> {
+        let hash_tag = self.pool.is_cluster();
         let key_latest_bids_value =
-            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key);
+            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.hgetall(&key_latest_bids_value).await?.unwrap_or_default())
     }
 
@@ -462,6 +701,7 @@ impl RedisCache {
         if builder_bids.is_empty() {
             return Ok(());
         }
+        let hash_tag = self.pool.is_cluster();
 
         // Determine the current top bid.
         let (top_bid_builder_pub_key, top_builder_bid_value) =
@@ -469,7 +709,7 @@ impl RedisCache {
 
         // Use the floor value if it's greater than the top bid value.
         let (top_bid_value, top_bid_source_key) = if floor_value > top_builder_bid_value {
-            (floor_value, get_floor_bid_key(slot, parent_hash, proposer_pub_key))
+            (floor_value, get_floor_bid_key(slot, parent_hash, proposer_pub_key, hash_tag))
         } else {
             (
                 top_builder_bid_value,
@@ -478,17 +718,20 @@ impl RedisCache {
                     parent_hash,
                     proposer_pub_key,
                     &top_bid_builder_pub_key,
+                    hash_tag,
                 ),
             )
         };
         state.top_bid_value = top_bid_value;
 
         // Set the get header response to the new best bid
-        let top_bid_key = get_cache_get_header_response_key(slot, parent_hash, proposer_pub_key);
+        let top_bid_key =
+            get_cache_get_header_response_key(slot, parent_hash, proposer_pub_key, hash_tag);
         self.copy(&top_bid_source_key, &top_bid_key, Some(BID_CACHE_EXPIRY_S)).await?;
 
         // Update the global top bid value
-        let top_bid_value_key = get_top_bid_value_key(slot, parent_hash, proposer_pub_key);
+        let top_bid_value_key =
+            get_top_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         self.set(&top_bid_value_key, &state.top_bid_value, Some(BID_CACHE_EXPIRY_S)).await?;
 
         self.publish(BEST_BIDS_CHANNEL, &top_bid_key).await?;
@@ -499,33 +742,24 @@ impl RedisCache {
     }
 
     async fn get_last_hash_delivered(&self) -> Result<Option<Hash32>, RedisCacheError> {
-        self.get(LAST_HASH_DELIVERED_KEY).await
+        self.get(self.last_delivered_keys().1).await
     }
 
-    /// 1) Update floor bid payload by copying from the best builder bid to floor bid.
-    /// 2) Update floor bid value.
-    async fn set_new_floor(
-        &self,
-        new_floor_value: U256,
-        slot: u64,
-        parent_hash: &Hash32,
-        proposer_pub_key: &BlsPublicKey,
-        builder_pub_key: &BlsPublicKey,
-    ) -> Result<(), RedisCacheError> {
-        let key_bid_source =
-            get_latest_bid_by_builder_key(slot, parent_hash, proposer_pub_key, builder_pub_key);
-        let key_floor_bid = get_floor_bid_key(slot, parent_hash, proposer_pub_key);
-        self.copy(&key_bid_source, &key_floor_bid, Some(BID_CACHE_EXPIRY_S)).await?;
-
-        let key_floor_bid_value = get_floor_bid_value_key(slot, parent_hash, proposer_pub_key);
-        self.set(&key_floor_bid_value, &new_floor_value, Some(BID_CACHE_EXPIRY_S)).await
+    /// Keys of the last delivered slot and hash. Only Redis Cluster deployments hash-tag them,
+    /// so single node and Sentinel deployments keep reading the keys they already have.
+    fn last_delivered_keys(&self) -> (&'static str, &'static str) {
+        if self.pool.is_cluster() {
+            (CLUSTER_LAST_SLOT_DELIVERED_KEY, CLUSTER_LAST_HASH_DELIVERED_KEY)
+        } else {
+            (LAST_SLOT_DELIVERED_KEY, LAST_HASH_DELIVERED_KEY)
+        }
     }
 }
 
 #[async_trait]
 impl Auctioneer for RedisCache {
     async fn get_last_slot_delivered(&self) -> Result<Option<u64>, AuctioneerError> {
-        self.get(LAST_SLOT_DELIVERED_KEY).await.map_err(AuctioneerError::RedisError)
+        self.get(self.last_delivered_keys().0).await.map_err(AuctioneerError::RedisError)
     }
 
     async fn check_and_set_last_slot_and_hash_delivered(
@@ -562,13 +796,14 @@ impl Auctioneer for RedisCache {
         let slot_value = serde_json::to_string(&slot).map_err(RedisCacheError::from)?;
         let hash_value =
             serde_json::to_string(&format!("{hash:?}")).map_err(RedisCacheError::from)?;
+        let (last_slot_key, last_hash_key) = self.last_delivered_keys();
         pipe.atomic()
             .cmd("SET")
-            .arg(LAST_SLOT_DELIVERED_KEY)
+            .arg(last_slot_key)
             .arg(slot_value)
             .ignore()
             .cmd("SET")
-            .arg(LAST_HASH_DELIVERED_KEY)
+            .arg(last_hash_key)
             .arg(hash_value)
             .ignore();
 
@@ -581,14 +816,27 @@ impl Auctioneer for RedisCache {
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<SignedBuilderBid>, AuctioneerError> {
-        let key = get_cache_get_header_response_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_cache_get_header_response_key(slot, parent_hash, proposer_pub_key, hash_tag);
         let wrapped_bid: Option<SignedBuilderBidWrapper> = self.get(&key).await?;
         Ok(wrapped_bid.map(|wrapped_bid| wrapped_bid.bid))
     }
 
+    async fn get_top_bid_update(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<TopBidUpdate>, AuctioneerError> {
+        let hash_tag = self.pool.is_cluster();
+        let key = get_cache_get_header_response_key(slot, parent_hash, proposer_pub_key, hash_tag);
+        let wrapped_bid: Option<SignedBuilderBidWrapper> = self.get(&key).await?;
+        Ok(wrapped_bid.map(TopBidUpdate::from))
+    }
+
     async fn get_best_bids(
         &self,
-    ) -> Box<dyn Stream<Item = Result<Vec<u8>, AuctioneerError>> + Send + Unpin> {
+    ) -> Box<dyn Stream<Item = Result<TopBidEvent, AuctioneerError>> + Send + Unpin> {
         let rx = self.tx.subscribe();
         let stream = BroadcastStream::new(rx).map_err(AuctioneerError::from);
         Box::new(stream)
@@ -602,7 +850,7 @@ impl Auctioneer for RedisCache {
         execution_payload: &PayloadAndBlobs,
     ) -> Result<(), AuctioneerError> {
         let key = get_execution_payload_key(slot, proposer_pub_key, block_hash);
-        Ok(self.set(&key, &execution_payload, Some(BID_CACHE_EXPIRY_S)).await?)
+        Ok(self.set_compressed(&key, &execution_payload, Some(BID_CACHE_EXPIRY_S)).await?)
     }
 
     async fn get_execution_payload(
@@ -641,14 +889,16 @@ impl Auctioneer for RedisCache {
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<u64>, AuctioneerError> {
-        let key = get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.hget(&key, &format!("{builder_pub_key:?}")).await?)
     }
 
-    /// This function performs three operations:
+    /// This function performs four operations:
     /// 1. Stores the full `SignedBuilderBid` object.
     /// 2. Stores the time at which this bid was received.
     /// 3. Stores the value of the bid.
+    /// 4. Appends the bid to the auction's bid history.
     async fn save_builder_bid(
         &self,
         slot: u64,
@@ -658,6 +908,7 @@ impl Auctioneer for RedisCache {
         received_at: u128,
         builder_bid: &SignedBuilderBid,
     ) -> Result<(), AuctioneerError> {
+        let hash_tag = self.pool.is_cluster();
         let mut conn = self.pool.get().await.map_err(RedisCacheError::from)?;
         let mut pipe = redis::pipe();
 
@@ -666,21 +917,35 @@ impl Auctioneer for RedisCache {
             slot,
             builder_pub_key.clone(),
             received_at,
+            proposer_pub_key.clone(),
         );
 
-        let serialised_bid =
-            serde_json::to_string(&wrapped_builder_bid).map_err(RedisCacheError::from)?;
+        let serialised_bid = self.encode(&wrapped_builder_bid)?;
         let serialised_value =
             serde_json::to_string(&builder_bid.value()).map_err(RedisCacheError::from)?;
 
-        let key_latest_bid =
-            get_latest_bid_by_builder_key(slot, parent_hash, proposer_pub_key, builder_pub_key);
+        let key_latest_bid = get_latest_bid_by_builder_key(
+            slot,
+            parent_hash,
+            proposer_pub_key,
+            builder_pub_key,
+            hash_tag,
+        );
         let key_latest_bids_time =
-            get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key);
+            get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key, hash_tag);
         let key_latest_bids_value =
-            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key);
+            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
+        let key_bid_history = get_bid_history_key(slot, parent_hash, proposer_pub_key, hash_tag);
         let builder_pub_key_str = format!("{:?}", builder_pub_key);
 
+        let history_entry = serde_json::to_string(&BidHistoryEntry {
+            builder_pubkey: builder_pub_key.clone(),
+            block_hash: builder_bid.block_hash().clone(),
+            value: builder_bid.value(),
+            received_at: received_at as u64,
+        })
+        .map_err(RedisCacheError::from)?;
+
         pipe.atomic()
             // Store the full SignedBuilderBid object with expiry
             .cmd("SET")
@@ -698,6 +963,11 @@ impl Auctioneer for RedisCache {
             .hset(&key_latest_bids_value, &builder_pub_key_str, serialised_value)
             .ignore()
             .expire(&key_latest_bids_value, BID_CACHE_EXPIRY_S)
+            .ignore()
+            // Append the bid to the auction's history with expiry
+            .rpush(&key_bid_history, history_entry)
+            .ignore()
+            .expire(&key_bid_history, BID_CACHE_EXPIRY_S)
             .ignore();
 
         Ok(pipe.query_async(&mut conn).await.map_err(RedisCacheError::from)?)
@@ -763,7 +1033,8 @@ impl Auctioneer for RedisCache {
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError> {
-        let key = get_top_bid_value_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_top_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.get(&key).await?)
     }
 
@@ -774,20 +1045,50 @@ impl Auctioneer for RedisCache {
         proposer_pub_key: &BlsPublicKey,
         builder_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError> {
-        let key = get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.hget(&key, &format!("{builder_pub_key:?}")).await?)
     }
 
+    async fn get_latest_bid_values(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<(BlsPublicKey, U256)>, AuctioneerError> {
+        let hash_tag = self.pool.is_cluster();
+        let key = get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
+        let values: Option<HashMap<String, U256>> = self.hgetall(&key).await?;
+
+        let mut latest_values = Vec::new();
+        for (builder_pub_key, value) in values.unwrap_or_default() {
+            latest_values.push((get_pubkey_from_hex(&builder_pub_key)?, value));
+        }
+        Ok(latest_values)
+    }
+
     async fn get_floor_bid_value(
         &self,
         slot: u64,
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<Option<U256>, AuctioneerError> {
-        let key = get_floor_bid_value_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_floor_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.get(&key).await?)
     }
 
+    async fn get_bid_history(
+        &self,
+        slot: u64,
+        parent_hash: &Hash32,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Vec<BidHistoryEntry>, AuctioneerError> {
+        let hash_tag = self.pool.is_cluster();
+        let key = get_bid_history_key(slot, parent_hash, proposer_pub_key, hash_tag);
+        Ok(self.lrange(&key, 0, -1).await?)
+    }
+
     async fn delete_builder_bid(
         &self,
         slot: u64,
@@ -795,13 +1096,15 @@ impl Auctioneer for RedisCache {
         proposer_pub_key: &BlsPublicKey,
         builder_pub_key: &BlsPublicKey,
     ) -> Result<(), AuctioneerError> {
+        let hash_tag = self.pool.is_cluster();
         // Delete the value
         let key_latest_value =
-            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key);
+            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
         self.hdel(&key_latest_value, &format!("{builder_pub_key:?}")).await?;
 
         // Delete the time
-        let key_latest_time = get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key);
+        let key_latest_time =
+            get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key, hash_tag);
         self.hdel(&key_latest_time, &format!("{builder_pub_key:?}")).await?;
 
         // Update bids now to determine current top bid
@@ -879,7 +1182,8 @@ impl Auctioneer for RedisCache {
         parent_hash: &Hash32,
         proposer_pub_key: &BlsPublicKey,
     ) -> Result<bool, AuctioneerError> {
-        let key = get_seen_block_hashes_key(slot, parent_hash, proposer_pub_key);
+        let hash_tag = self.pool.is_cluster();
+        let key = get_seen_block_hashes_key(slot, parent_hash, proposer_pub_key, hash_tag);
         Ok(self.seen_or_add(&key, block_hash).await?)
     }
 
@@ -898,77 +1202,125 @@ impl Auctioneer for RedisCache {
             return Ok(());
         }
 
-        // Load the latest bids from all builders for the current slot, parent hash, and proposer
-        let mut builder_bids = self
-            .get_new_builder_bids(
-                bid_trace.slot,
-                &bid_trace.parent_hash,
-                &bid_trace.proposer_public_key,
-            )
-            .await?;
-
-        // Get the current top bid. It will be the max of all builder bids and the current floor.
-        state.top_bid_value =
-            builder_bids.values().max().cloned().unwrap_or(U256::ZERO).max(floor_value);
-
-        state.prev_top_bid_value = state.top_bid_value;
+        // Save the bid trace first so it is available as soon as the bid can be served
+        self.save_bid_trace(bid_trace).await?;
+        state.set_latency_save_trace();
 
-        state.set_latency_get_prev_top_bid();
+        let slot = bid_trace.slot;
+        let parent_hash = &bid_trace.parent_hash;
+        let proposer_pub_key = &bid_trace.proposer_public_key;
+        let builder_pub_key = &bid_trace.builder_public_key;
+        let hash_tag = self.pool.is_cluster();
 
-        // Save the latest bid for this builder
-        self.save_builder_bid(
-            bid_trace.slot,
-            &bid_trace.parent_hash,
-            &bid_trace.proposer_public_key,
-            &bid_trace.builder_public_key,
+        let wrapped_builder_bid = SignedBuilderBidWrapper::new(
+            builder_bid.clone(),
+            slot,
+            builder_pub_key.clone(),
             received_at,
-            builder_bid,
-        )
-        .await?;
-        state.was_bid_saved = true;
-        builder_bids.insert(format!("{:?}", bid_trace.builder_public_key), builder_bid.value());
-        state.set_latency_save_bid();
+            proposer_pub_key.clone(),
+        );
+        let serialised_bid = self.encode(&wrapped_builder_bid)?;
+        let serialised_value =
+            serde_json::to_string(&builder_bid.value()).map_err(RedisCacheError::from)?;
+        let serialised_floor_value =
+            serde_json::to_string(&floor_value).map_err(RedisCacheError::from)?;
+        let history_entry = serde_json::to_string(&BidHistoryEntry {
+            builder_pubkey: builder_pub_key.clone(),
+            block_hash: bid_trace.block_hash.clone(),
+            value: builder_bid.value(),
+            received_at: received_at as u64,
+        })
+        .map_err(RedisCacheError::from)?;
 
-        // Save the bid trace
-        self.save_bid_trace(bid_trace).await?;
-        state.set_latency_save_trace();
+        // Only non-cancellable bids above the floor should set a new floor.
+        let set_new_floor = !cancellations_enabled && is_bid_above_floor;
+
+        // Save the bid and update the top bid and floor in one round trip. The script may only
+        // touch the keys it is given, so the latest bid keys of the builders bidding on the
+        // auction are fetched first.
+        let builder_pub_key_str = format!("{builder_pub_key:?}");
+        let key_bid_values =
+            get_builder_latest_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag);
+        let mut conn = self.pool.get().await.map_err(RedisCacheError::from)?;
+        let script = Script::new(SAVE_BID_AND_UPDATE_TOP_BID_SCRIPT);
+        for _ in 0..SAVE_BID_ATTEMPTS {
+            let builders: Vec<String> =
+                conn.hkeys(&key_bid_values).await.map_err(RedisCacheError::from)?;
+            let other_builders: Vec<String> =
+                builders.into_iter().filter(|builder| *builder != builder_pub_key_str).collect();
+            state.set_latency_get_prev_top_bid();
+
+            let mut invocation = script.prepare_invoke();
+            invocation
+                .key(get_latest_bid_by_builder_key(
+                    slot,
+                    parent_hash,
+                    proposer_pub_key,
+                    builder_pub_key,
+                    hash_tag,
+                ))
+                .key(get_builder_latest_bid_time_key(slot, parent_hash, proposer_pub_key, hash_tag))
+                .key(&key_bid_values)
+                .key(get_cache_get_header_response_key(
+                    slot,
+                    parent_hash,
+                    proposer_pub_key,
+                    hash_tag,
+                ))
+                .key(get_top_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag))
+                .key(get_floor_bid_key(slot, parent_hash, proposer_pub_key, hash_tag))
+                .key(get_floor_bid_value_key(slot, parent_hash, proposer_pub_key, hash_tag))
+                .key(get_bid_history_key(slot, parent_hash, proposer_pub_key, hash_tag))
+                .arg(&builder_pub_key_str)
+                .arg(&serialised_bid)
+                .arg(received_at as u64)
+                .arg(&serialised_value)
+                .arg(&serialised_floor_value)
+                .arg(BID_CACHE_EXPIRY_S)
+                .arg(set_new_floor as u8)
+                .arg(BEST_BIDS_CHANNEL)
+                .arg(&history_entry);
+            for builder in &other_builders {
+                invocation
+                    .key(get_latest_bid_by_builder_key_str_builder_pub_key(
+                        slot,
+                        parent_hash,
+                        proposer_pub_key,
+                        builder,
+                        hash_tag,
+                    ))
+                    .arg(builder);
+            }
 
-        // Abort if the top bid hasn't changed
-        // TODO: the floor may have raised but we will exit early here.
-        state.top_bid_value = builder_bids.values().max().cloned().unwrap_or(U256::ZERO);
-        if state.top_bid_value == state.prev_top_bid_value {
-            return Ok(());
-        }
+            let (was_saved, was_top_bid_updated, is_new_top_bid, top_bid_value, prev_top_value): (
+                bool,
+                bool,
+                bool,
+                String,
+                String,
+            ) = invocation.invoke_async(&mut conn).await.map_err(RedisCacheError::from)?;
+            if !was_saved {
+                // Another builder started bidding on the auction, retry with its key
+                continue;
+            }
 
-        // Update the top bid
-        self.update_top_bid(
-            state,
-            &builder_bids,
-            bid_trace.slot,
-            &bid_trace.parent_hash,
-            &bid_trace.proposer_public_key,
-            floor_value,
-        )
-        .await?;
-        state.is_new_top_bid = builder_bid.value() == state.top_bid_value;
-        state.set_latency_update_top_bid();
+            state.was_bid_saved = true;
+            state.set_latency_save_bid();
+            state.was_top_bid_updated = was_top_bid_updated;
+            state.is_new_top_bid = is_new_top_bid;
+            state.top_bid_value =
+                serde_json::from_str(&top_bid_value).map_err(RedisCacheError::from)?;
+            state.prev_top_bid_value =
+                serde_json::from_str(&prev_top_value).map_err(RedisCacheError::from)?;
+            state.set_latency_update_top_bid();
+            if was_top_bid_updated && set_new_floor {
+                state.set_latency_update_floor();
+            }
 
-        // Handle floor value updates only if needed.
-        // Only non-cancellable bids above the floor should set a new floor.
-        if cancellations_enabled || !is_bid_above_floor {
             return Ok(());
         }
-        self.set_new_floor(
-            builder_bid.value(),
-            bid_trace.slot,
-            &bid_trace.parent_hash,
-            &bid_trace.proposer_public_key,
-            &bid_trace.builder_public_key,
-        )
-        .await?;
-        state.set_latency_update_floor();
 
-        Ok(())
+        Err(RedisCacheError::BidBuildersChanged.into())
     }
 
     async fn get_header_tx_root(
@@ -1229,20 +1581,61 @@ impl Auctioneer for RedisCache {
         return self.set_lock(HOUSEKEEPER_LOCK_KEY, leader_id, HOUSEKEEPER_LOCK_EXPIRY_MS).await;
     }
 
-    async fn kill_switch_enabled(&self) -> Result<bool, AuctioneerError> {
-        let kill_switch: Option<bool> = self.get(KILL_SWITCH).await?;
-        Ok(kill_switch.unwrap_or_default())
+    /// Served from the local copy, which is refreshed every `KILL_SWITCH_REFRESH_INTERVAL` and
+    /// whenever a switch is enabled or disabled through this instance.
+    async fn get_kill_switches(&self) -> Result<Vec<KillSwitch>, AuctioneerError> {
+        let now_ms = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_millis();
+        let kill_switches =
+            self.kill_switches.read().map_err(|_| RedisCacheError::InternalError)?;
+        Ok(kill_switches
+            .iter()
+            .filter(|kill_switch| !kill_switch.is_expired(now_ms as u64))
+            .cloned()
+            .collect())
     }
 
-    async fn enable_kill_switch(&self) -> Result<(), AuctioneerError> {
-        self.set(KILL_SWITCH, &true, None).await?;
+    async fn enable_kill_switch(&self, kill_switch: &KillSwitch) -> Result<(), AuctioneerError> {
+        let field = serde_json::to_string(&kill_switch.scope).map_err(RedisCacheError::from)?;
+        self.hset(KILL_SWITCHES_KEY, &field, kill_switch).await?;
+        self.refresh_kill_switches().await?;
         Ok(())
     }
 
-    async fn disable_kill_switch(&self) -> Result<(), AuctioneerError> {
-        self.set(KILL_SWITCH, &false, None).await?;
+    async fn disable_kill_switch(&self, scope: &KillSwitchScope) -> Result<(), AuctioneerError> {
+        let field = serde_json::to_string(scope).map_err(RedisCacheError::from)?;
+        self.hdel(KILL_SWITCHES_KEY, &field).await?;
+        self.refresh_kill_switches().await?;
         Ok(())
     }
+
+    async fn record_builder_submission(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+    ) -> Result<u64, AuctioneerError> {
+        let now_s = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs();
+        let key = get_builder_submission_count_key(builder_pub_key, now_s);
+        Ok(self.incr_with_expiry(&key, 1, BUILDER_SUBMISSION_COUNT_EXPIRY_S).await?)
+    }
+
+    async fn record_builder_submission_bytes(
+        &self,
+        builder_pub_key: &BlsPublicKey,
+        slot: u64,
+        size: u64,
+    ) -> Result<u64, AuctioneerError> {
+        let key = get_builder_submission_bytes_key(builder_pub_key, slot);
+        Ok(self.incr_with_expiry(&key, size, BID_CACHE_EXPIRY_S).await?)
+    }
+
+    async fn get_proposer_pool(
+        &self,
+        proposer_pub_key: &BlsPublicKey,
+    ) -> Result<Option<String>, AuctioneerError> {
+        let key_str = format!("{proposer_pub_key:?}");
+        let proposer_info: Option<ProposerInfo> =
+            self.hget(PROPOSER_WHITELIST_KEY, &key_str).await?;
+        Ok(proposer_info.map(|proposer_info| proposer_info.name))
+    }
 }
 
 fn get_top_bid(bid_values: &HashMap<String, U256>) -> Option<(String, U256)> {
@@ -1253,8 +1646,13 @@ fn get_top_bid(bid_values: &HashMap<String, U256>) -> Option<(String, U256)> {
 mod tests {
 
     use super::*;
+    use crate::redis::pool::SentinelPool;
     use ethereum_consensus::clock::get_current_unix_time_in_nanos;
-    use helix_common::capella::{self, ExecutionPayloadHeader};
+    use helix_common::{
+        capella::{self, ExecutionPayloadHeader},
+        kill_switch::KillSwitchTarget,
+        RedisSentinelConfig, Route,
+    };
     
     use serde::{Deserialize, Serialize};
 
@@ -1404,7 +1802,7 @@ mod tests {
         let slot = 5_u64;
         let parent_hash = Hash32::default();
         let proposer_pub_key = BlsPublicKey::default();
-        let key = get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key);
+        let key = get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key, false);
 
         // Bid info
         let pub_key_1 = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
@@ -1507,7 +1905,7 @@ mod tests {
         assert!(state.was_top_bid_updated, "Top bid should be updated");
 
         // Test the Redis cache
-        let _key_top_bid = get_top_bid_value_key(slot, &parent_hash, &proposer_pub_key);
+        let _key_top_bid = get_top_bid_value_key(slot, &parent_hash, &proposer_pub_key, false);
         let mut _conn = cache.pool.get().await.unwrap();
 
         let cache_value =
@@ -1522,7 +1920,7 @@ mod tests {
             ..Default::default()
         });
 
-        let key_floor_bid = get_floor_bid_key(slot, &parent_hash, &proposer_pub_key);
+        let key_floor_bid = get_floor_bid_key(slot, &parent_hash, &proposer_pub_key, false);
         let res = cache.set(&key_floor_bid, &floor_bid, None).await;
         assert!(res.is_ok(), "Failed to set floor bid");
 
@@ -1620,6 +2018,92 @@ mod tests {
         assert!(matches!(set_result, Err(AuctioneerError::UnexpectedValueType)));
     }
 
+    #[tokio::test]
+    async fn test_last_delivered_keys_are_not_hash_tagged_outside_cluster() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        // Written before the cluster hash tags were introduced
+        assert!(cache.set(LAST_SLOT_DELIVERED_KEY, &42u64, None).await.is_ok());
+
+        let block_hash = Hash32::try_from([4u8; 32].as_ref()).unwrap();
+        let set_result = cache.check_and_set_last_slot_and_hash_delivered(41, &block_hash).await;
+        assert!(matches!(set_result, Err(AuctioneerError::PastSlotAlreadyDelivered)));
+
+        cache.check_and_set_last_slot_and_hash_delivered(43, &block_hash).await.unwrap();
+        let last_slot: Option<u64> = cache.get(LAST_SLOT_DELIVERED_KEY).await.unwrap();
+        assert_eq!(last_slot, Some(43));
+        let tagged_slot: Option<u64> = cache.get(CLUSTER_LAST_SLOT_DELIVERED_KEY).await.unwrap();
+        assert_eq!(tagged_slot, None);
+    }
+
+    #[tokio::test]
+    async fn test_auction_keys_are_not_hash_tagged_outside_cluster() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 42;
+        let parent_hash = Hash32::default();
+        let proposer_pub_key = BlsPublicKey::default();
+        let mut capella_bid = capella::SignedBuilderBid::default();
+        capella_bid.message.value = U256::from(10);
+        let bid_trace = BidTrace { slot, value: capella_bid.message.value, ..Default::default() };
+
+        let mut state = SaveBidAndUpdateTopBidResponse::default();
+        cache
+            .save_signed_builder_bid_and_update_top_bid(
+                &SignedBuilderBid::Capella(capella_bid),
+                &bid_trace,
+                1000,
+                true,
+                U256::ZERO,
+                &mut state,
+            )
+            .await
+            .unwrap();
+        assert!(state.was_top_bid_updated);
+
+        // Readable under the key written before the cluster hash tags were introduced
+        let key = format!("top-bid-value:{slot}_{parent_hash:?}_{proposer_pub_key:?}");
+        let top_bid_value: Option<U256> = cache.get(&key).await.unwrap();
+        assert_eq!(top_bid_value, Some(U256::from(10)));
+        let tagged_key = get_top_bid_value_key(slot, &parent_hash, &proposer_pub_key, true);
+        let tagged_value: Option<U256> = cache.get(&tagged_key).await.unwrap();
+        assert_eq!(tagged_value, None);
+    }
+
+    #[tokio::test]
+    async fn test_best_bid_listener_returns_on_failover() {
+        let config = RedisSentinelConfig {
+            urls: vec!["redis://127.0.0.1:26379/".to_string()],
+            master_name: "mymaster".to_string(),
+            password: None,
+        };
+        let pool = RedisPool::Sentinel(
+            SentinelPool::new(config, "redis://127.0.0.1/".to_string()).unwrap(),
+        );
+        let cache = RedisCache::with_pool(pool, Vec::new(), false).await.unwrap();
+
+        let listener_cache = cache.clone();
+        let listener = tokio::spawn(async move { listener_cache.start_best_bid_listener().await });
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(!listener.is_finished());
+
+        // Same server, but a different primary url as far as the pool can tell
+        let RedisPool::Sentinel(sentinel) = cache.pool.as_ref() else { unreachable!() };
+        assert!(sentinel.swap_primary("redis://localhost/".to_string()));
+
+        let result =
+            tokio::time::timeout(std::time::Duration::from_secs(1), listener).await.unwrap();
+        assert!(result.unwrap().is_ok());
+
+        // The caller's restart loop resubscribes against the new primary
+        let listener = tokio::spawn(async move { cache.start_best_bid_listener().await });
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(!listener.is_finished());
+        listener.abort();
+    }
+
     #[tokio::test]
     async fn test_get_and_set_best_bid() {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
@@ -1634,7 +2118,7 @@ mod tests {
         let best_bid = SignedBuilderBid::Capella(capella_bid);
 
         // Save the best bid
-        let key = get_cache_get_header_response_key(slot, &parent_hash, &proposer_pub_key);
+        let key = get_cache_get_header_response_key(slot, &parent_hash, &proposer_pub_key, false);
         let set_result = cache.set(&key, &best_bid, None).await;
         assert!(set_result.is_ok(), "Failed to set best bid in cache");
 
@@ -1691,6 +2175,41 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_get_execution_payload_stored_as_json() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 42;
+        let proposer_pub_key = BlsPublicKey::default();
+        let block_hash = Hash32::default();
+
+        let mut capella_payload = capella::ExecutionPayload::default();
+        capella_payload.gas_limit = 999;
+        let versioned_execution_payload = PayloadAndBlobs {
+            execution_payload: ethereum_consensus::types::mainnet::ExecutionPayload::Capella(
+                capella_payload,
+            ),
+            blobs_bundle: None,
+        };
+
+        // Save the execution payload in the uncompressed format
+        let key = get_execution_payload_key(slot, &proposer_pub_key, &block_hash);
+        cache.set(&key, &versioned_execution_payload, Some(BID_CACHE_EXPIRY_S)).await.unwrap();
+
+        // Test: Get the execution payload
+        let fetched_execution_payload = cache
+            .get_execution_payload(slot, &proposer_pub_key, &block_hash)
+            .await
+            .unwrap()
+            .expect("Execution payload is None");
+        assert_eq!(
+            fetched_execution_payload.execution_payload.gas_limit(),
+            999,
+            "Execution payload mismatch"
+        );
+    }
+
     #[tokio::test]
     async fn test_save_builder_bid_and_get_latest_payload_received_at() {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
@@ -1726,8 +2245,13 @@ mod tests {
         assert!(res.is_ok(), "Failed to execute save_builder_bid");
 
         // Validate: the SignedBuilderBid object is correctly set
-        let key_latest_bid =
-            get_latest_bid_by_builder_key(slot, &parent_hash, &proposer_pub_key, &builder_pub_key);
+        let key_latest_bid = get_latest_bid_by_builder_key(
+            slot,
+            &parent_hash,
+            &proposer_pub_key,
+            &builder_pub_key,
+            false,
+        );
         let fetched_bid: Result<Option<SignedBuilderBid>, _> = cache.get(&key_latest_bid).await;
         assert!(fetched_bid.is_ok(), "Failed to fetch the latest bid");
         assert_eq!(
@@ -1751,13 +2275,100 @@ mod tests {
 
         // Validate the value is correctly set
         let key_latest_bids_value =
-            get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key);
+            get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key, false);
         let fetched_value: Result<Option<U256>, _> =
             cache.hget(&key_latest_bids_value, &format!("{builder_pub_key:?}")).await;
         assert!(fetched_value.is_ok(), "Failed to fetch the latest bid value");
         assert_eq!(fetched_value.unwrap().unwrap(), value, "Mismatch in saved value");
     }
 
+    #[tokio::test]
+    async fn test_get_bid_history() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 1;
+        let parent_hash = Hash32::default();
+        let proposer_pub_key = BlsPublicKey::default();
+        let builder_pub_key = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+
+        // Save two bids from the same builder, the second replacing the first
+        for (i, value) in [100, 200].into_iter().enumerate() {
+            let mut bid = capella::SignedBuilderBid {
+                message: helix_common::eth::capella::BuilderBid {
+                    value: U256::from(value),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            bid.message.header.block_hash = Hash32::try_from([i as u8; 32].as_ref()).unwrap();
+            cache
+                .save_builder_bid(
+                    slot,
+                    &parent_hash,
+                    &proposer_pub_key,
+                    &builder_pub_key,
+                    i as u128,
+                    &SignedBuilderBid::Capella(bid),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Test: both bids are kept, oldest first
+        let history = cache.get_bid_history(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        assert_eq!(history.len(), 2);
+        assert_eq!(history[0].value, U256::from(100));
+        assert_eq!(history[0].received_at, 0);
+        assert_eq!(history[1].value, U256::from(200));
+        assert_eq!(history[1].block_hash, Hash32::try_from([1u8; 32].as_ref()).unwrap());
+        assert!(history.iter().all(|entry| entry.builder_pubkey == builder_pub_key));
+    }
+
+    #[tokio::test]
+    async fn test_get_latest_bid_values() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 1;
+        let parent_hash = Hash32::default();
+        let proposer_pub_key = BlsPublicKey::default();
+
+        for (i, value) in [100, 300].into_iter().enumerate() {
+            let builder_pub_key = BlsPublicKey::try_from([i as u8 + 1; 48].as_ref()).unwrap();
+            let bid = capella::SignedBuilderBid {
+                message: helix_common::eth::capella::BuilderBid {
+                    value: U256::from(value),
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+            cache
+                .save_builder_bid(
+                    slot,
+                    &parent_hash,
+                    &proposer_pub_key,
+                    &builder_pub_key,
+                    i as u128,
+                    &SignedBuilderBid::Capella(bid),
+                )
+                .await
+                .unwrap();
+        }
+
+        // Test: every builder's latest value is returned
+        let mut values =
+            cache.get_latest_bid_values(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        values.sort_by_key(|(_, value)| *value);
+        assert_eq!(
+            values,
+            vec![
+                (BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap(), U256::from(100)),
+                (BlsPublicKey::try_from([2u8; 48].as_ref()).unwrap(), U256::from(300)),
+            ]
+        );
+    }
+
     #[tokio::test]
     async fn test_get_floor_bid_value() {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
@@ -1769,7 +2380,7 @@ mod tests {
         let floor_bid_value = U256::from(1000);
 
         // Set the floor value
-        let key = get_floor_bid_value_key(slot, &parent_hash, &proposer_pub_key);
+        let key = get_floor_bid_value_key(slot, &parent_hash, &proposer_pub_key, false);
         let set_result = cache.set(&key, &floor_bid_value, None).await;
         assert!(set_result.is_ok(), "Failed to set the floor value");
 
@@ -1793,7 +2404,7 @@ mod tests {
         let latest_value = U256::from(100);
 
         // Set the latest value
-        let key = get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key);
+        let key = get_builder_latest_bid_value_key(slot, &parent_hash, &proposer_pub_key, false);
         let set_result: Result<(), RedisCacheError> =
             cache.hset(&key, &format!("{builder_pub_key:?}"), &latest_value).await;
         assert!(set_result.is_ok(), "Failed to set the latest value");
@@ -1819,6 +2430,7 @@ mod tests {
             collateral: U256::from(12),
             is_optimistic: true,
             builder_id: None,
+            ..Default::default()
         };
 
         // Test case 1: Builder exists
@@ -1905,6 +2517,7 @@ mod tests {
             collateral: U256::from(12),
             is_optimistic: false,
             builder_id: None,
+            ..Default::default()
         };
 
         // Set builder info in the cache
@@ -1927,6 +2540,7 @@ mod tests {
             collateral: U256::from(12),
             is_optimistic: true,
             builder_id: None,
+            ..Default::default()
         };
 
         // Set builder info in the cache
@@ -2079,6 +2693,9 @@ mod tests {
         assert!(result.is_ok(), "Save failed");
         assert!(state.was_bid_saved, "Bid should be saved");
         assert!(state.is_new_top_bid, "Bid should be new top bid");
+        assert!(state.latency_get_prev_top_bid > 0, "Fetching the builders should be timed");
+        assert!(state.latency_save_bid > 0, "Saving the bid should be timed");
+        assert!(state.latency_update_floor > 0, "Updating the floor should be timed");
 
         // Validate bid is new floor
         let new_floor_value = cache
@@ -2200,6 +2817,67 @@ mod tests {
         assert!(!state.is_new_top_bid, "Bid should not be the new top bid");
     }
 
+    #[tokio::test]
+    async fn test_concurrent_submissions_no_lost_updates() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let slot = 1;
+        let parent_hash = Hash32::default();
+        let proposer_pub_key = BlsPublicKey::default();
+        let num_builders = 32u8;
+
+        // Values cross a byte boundary, so bids of different hex lengths are compared.
+        let handles: Vec<_> = (0..num_builders)
+            .map(|i| {
+                let cache = cache.clone();
+                tokio::spawn(async move {
+                    let builder_pub_key = BlsPublicKey::try_from([i + 1; 48].as_ref()).unwrap();
+                    let mut capella_bid = capella::SignedBuilderBid::default();
+                    capella_bid.message.value = U256::from(240 + i as u64);
+                    let bid_trace = BidTrace {
+                        slot,
+                        builder_public_key: builder_pub_key,
+                        value: capella_bid.message.value,
+                        ..Default::default()
+                    };
+
+                    let mut state = SaveBidAndUpdateTopBidResponse::default();
+                    cache
+                        .save_signed_builder_bid_and_update_top_bid(
+                            &SignedBuilderBid::Capella(capella_bid),
+                            &bid_trace,
+                            1000,
+                            true,
+                            U256::ZERO,
+                            &mut state,
+                        )
+                        .await
+                        .unwrap();
+                    state
+                })
+            })
+            .collect();
+
+        for handle in handles {
+            let state = handle.await.unwrap();
+            assert!(state.was_bid_saved, "Bid should be saved");
+        }
+
+        let max_value = U256::from(240 + num_builders as u64 - 1);
+
+        let builder_bids =
+            cache.get_new_builder_bids(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        assert_eq!(builder_bids.len(), num_builders as usize, "Builder bids were lost");
+
+        let top_bid_value =
+            cache.get_top_bid_value(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        assert_eq!(top_bid_value, Some(max_value), "Top bid value mismatch");
+
+        let best_bid = cache.get_best_bid(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        assert_eq!(best_bid.unwrap().value(), max_value, "Best bid mismatch");
+    }
+
     async fn setup_save_and_update_test() -> (RedisCache, SignedBidSubmission, U256, u128) {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
         cache.clear_cache().await.unwrap();
@@ -2234,6 +2912,111 @@ mod tests {
         (cache, submission, floor_value, received_at)
     }
 
+    #[tokio::test]
+    async fn test_compressed_bid_round_trip() {
+        let pool = RedisPool::standalone("redis://127.0.0.1/").unwrap();
+        let cache = RedisCache::with_pool(pool, Vec::new(), true).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let listener_cache = cache.clone();
+        tokio::spawn(async move { listener_cache.start_best_bid_listener().await });
+        let mut best_bids = cache.get_best_bids().await;
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        let slot = 1;
+        let parent_hash = Hash32::default();
+        let proposer_pub_key = BlsPublicKey::default();
+        let received_at = 1616237123000u128;
+
+        // A lower bid saved directly, followed by a new top bid saved through the script
+        let other_builder = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        let other_bid = SignedBuilderBid::Capella(capella::SignedBuilderBid {
+            message: helix_common::eth::capella::BuilderBid {
+                value: U256::from(50),
+                ..Default::default()
+            },
+            ..Default::default()
+        });
+        cache
+            .save_builder_bid(
+                slot,
+                &parent_hash,
+                &proposer_pub_key,
+                &other_builder,
+                received_at,
+                &other_bid,
+            )
+            .await
+            .unwrap();
+
+        let mut submission = SignedBidSubmission::default();
+        submission.message_mut().slot = slot;
+        submission.message_mut().value = U256::from(100);
+        let mut state = SaveBidAndUpdateTopBidResponse::default();
+        cache
+            .save_bid_and_update_top_bid(
+                &submission,
+                received_at + 1,
+                false,
+                U256::ZERO,
+                &mut state,
+                &RelaySigningContext::default(),
+            )
+            .await
+            .unwrap();
+        assert!(state.is_new_top_bid);
+
+        // Both bids and the copied top bid are stored compressed
+        let mut conn = cache.pool.get().await.unwrap();
+        for key in [
+            get_latest_bid_by_builder_key(
+                slot,
+                &parent_hash,
+                &proposer_pub_key,
+                &other_builder,
+                false,
+            ),
+            get_latest_bid_by_builder_key(
+                slot,
+                &parent_hash,
+                &proposer_pub_key,
+                &submission.message().builder_public_key,
+                false,
+            ),
+            get_cache_get_header_response_key(slot, &parent_hash, &proposer_pub_key, false),
+        ] {
+            let raw: Vec<u8> = conn.get(&key).await.unwrap();
+            assert!(zstd::decode_all(raw.as_slice()).is_ok(), "{key} is not compressed");
+        }
+
+        let fetched_time = cache
+            .get_builder_latest_payload_received_at(
+                slot,
+                &other_builder,
+                &parent_hash,
+                &proposer_pub_key,
+            )
+            .await
+            .unwrap();
+        assert_eq!(fetched_time, Some(received_at as u64));
+
+        let best_bid = cache.get_best_bid(slot, &parent_hash, &proposer_pub_key).await.unwrap();
+        assert_eq!(best_bid.unwrap().value(), U256::from(100));
+
+        let update =
+            cache.get_top_bid_update(slot, &parent_hash, &proposer_pub_key).await.unwrap().unwrap();
+        assert_eq!(update.value, U256::from(100));
+        assert_eq!(update.builder_pubkey, submission.message().builder_public_key);
+
+        let event = tokio::time::timeout(std::time::Duration::from_secs(1), best_bids.next())
+            .await
+            .expect("no top bid was forwarded")
+            .unwrap()
+            .unwrap();
+        assert_eq!(event.update.value, U256::from(100));
+        assert_eq!(event.proposer_pubkey, proposer_pub_key);
+    }
+
     #[tokio::test]
     async fn test_seen_or_insert_block_hash() {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
@@ -2335,6 +3118,7 @@ mod tests {
                 collateral: U256::from(100),
                 is_optimistic: true,
                 builder_id: None,
+                ..Default::default()
             },
             pub_key: BlsPublicKey::default(),
         }];
@@ -2371,6 +3155,7 @@ mod tests {
                 collateral: U256::from(100),
                 is_optimistic: true,
                 builder_id: None,
+                ..Default::default()
             },
             pub_key: BlsPublicKey::default(),
         }];
@@ -2434,6 +3219,7 @@ mod tests {
                 collateral: U256::from(100),
                 is_optimistic: true,
                 builder_id: None,
+                ..Default::default()
             },
             pub_key: BlsPublicKey::default(),
         }];
@@ -2468,6 +3254,7 @@ mod tests {
                 collateral: U256::from(100),
                 is_optimistic: true,
                 builder_id: None,
+                ..Default::default()
             },
             pub_key: BlsPublicKey::default(),
         }];
@@ -2502,6 +3289,7 @@ mod tests {
                 collateral: U256::from(100),
                 is_optimistic: true,
                 builder_id: None,
+                ..Default::default()
             },
             pub_key: BlsPublicKey::default(),
         }];
@@ -2538,17 +3326,141 @@ mod tests {
         let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
         cache.clear_cache().await.unwrap();
 
-        let result = cache.kill_switch_enabled().await.unwrap();
-        assert!(!result, "Kill switch should be disabled by default");
+        let result = cache.get_kill_switches().await.unwrap();
+        assert!(result.is_empty(), "Kill switches should be disabled by default");
 
-        cache.enable_kill_switch().await.unwrap();
+        let kill_switch = KillSwitch {
+            scope: KillSwitchScope::Route(Route::SubmitBlock),
+            reason: "test".to_string(),
+            expires_at_ms: None,
+        };
+        cache.enable_kill_switch(&kill_switch).await.unwrap();
+
+        let result = cache.get_kill_switches().await.unwrap();
+        assert_eq!(result, vec![kill_switch.clone()], "Kill switch should be enabled");
+
+        let target = KillSwitchTarget::new(Route::SubmitBlock);
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert_eq!(result, Some(kill_switch.clone()), "Kill switch should apply to the route");
+
+        let target = KillSwitchTarget::new(Route::GetHeader);
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert!(result.is_none(), "Kill switch should not apply to other routes");
+
+        cache.disable_kill_switch(&kill_switch.scope).await.unwrap();
+
+        let result = cache.get_kill_switches().await.unwrap();
+        assert!(result.is_empty(), "Kill switch should be disabled");
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_expiry() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let kill_switch = KillSwitch {
+            scope: KillSwitchScope::Relay,
+            reason: "test".to_string(),
+            expires_at_ms: Some(1),
+        };
+        cache.enable_kill_switch(&kill_switch).await.unwrap();
+
+        let result = cache.get_kill_switches().await.unwrap();
+        assert!(result.is_empty(), "Expired kill switch should not be returned");
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_set_by_another_instance() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+        let other = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+
+        let kill_switch = KillSwitch {
+            scope: KillSwitchScope::Relay,
+            reason: "test".to_string(),
+            expires_at_ms: None,
+        };
+        other.enable_kill_switch(&kill_switch).await.unwrap();
 
-        let result = cache.kill_switch_enabled().await.unwrap();
-        assert!(result, "Kill switch should be enabled");
+        tokio::time::sleep(KILL_SWITCH_REFRESH_INTERVAL * 2).await;
+        let result = cache.get_kill_switches().await.unwrap();
+        assert_eq!(result, vec![kill_switch], "Kill switch should be picked up on refresh");
+    }
+
+    #[tokio::test]
+    async fn test_legacy_kill_switch_migration() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+        cache.set(LEGACY_KILL_SWITCH_KEY, &true, None).await.unwrap();
+
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        tokio::time::sleep(KILL_SWITCH_REFRESH_INTERVAL * 2).await;
+
+        let target = KillSwitchTarget::new(Route::GetHeader);
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert!(result.is_some(), "Legacy kill switch should still turn off get_header");
+
+        let target = KillSwitchTarget::new(Route::SubmitBlock);
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert!(result.is_none(), "Legacy kill switch only applied to get_header");
+
+        let legacy: Option<bool> = cache.get(LEGACY_KILL_SWITCH_KEY).await.unwrap();
+        assert!(legacy.is_none(), "Legacy kill switch should be removed");
+    }
+
+    #[tokio::test]
+    async fn test_record_builder_submission() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let builder_pub_key = BlsPublicKey::try_from([2u8; 48].as_ref()).unwrap();
+        let slot = 42;
+
+        let submissions = cache.record_builder_submission(&builder_pub_key).await.unwrap();
+        assert!(submissions >= 1);
+
+        let bytes =
+            cache.record_builder_submission_bytes(&builder_pub_key, slot, 100).await.unwrap();
+        assert_eq!(bytes, 100);
+
+        let bytes =
+            cache.record_builder_submission_bytes(&builder_pub_key, slot, 50).await.unwrap();
+        assert_eq!(bytes, 150, "Bytes should accumulate within the slot");
+
+        let bytes =
+            cache.record_builder_submission_bytes(&builder_pub_key, slot + 1, 10).await.unwrap();
+        assert_eq!(bytes, 10, "Bytes should reset for a new slot");
+    }
+
+    #[tokio::test]
+    async fn test_kill_switch_proposer_pool() {
+        let cache = RedisCache::new("redis://127.0.0.1/", Vec::new()).await.unwrap();
+        cache.clear_cache().await.unwrap();
+
+        let proposer_pub_key = BlsPublicKey::try_from([1u8; 48].as_ref()).unwrap();
+        cache
+            .update_trusted_proposers(vec![ProposerInfo {
+                name: "pool".to_string(),
+                pub_key: proposer_pub_key.clone(),
+            }])
+            .await
+            .unwrap();
+
+        let kill_switch = KillSwitch {
+            scope: KillSwitchScope::ProposerPool("pool".to_string()),
+            reason: "test".to_string(),
+            expires_at_ms: None,
+        };
+        cache.enable_kill_switch(&kill_switch).await.unwrap();
 
-        cache.disable_kill_switch().await.unwrap();
+        // Test: the pool is resolved from the proposer's pubkey
+        let mut target = KillSwitchTarget::new(Route::GetHeader);
+        target.proposer_pubkey = Some(&proposer_pub_key);
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert_eq!(result, Some(kill_switch), "Kill switch should apply to the pool's proposers");
 
-        let result = cache.kill_switch_enabled().await.unwrap();
-        assert!(!result, "Kill switch should be disabled");
+        target.proposer_pubkey = Some(&BlsPublicKey::default());
+        let result = cache.find_kill_switch(target).await.unwrap();
+        assert!(result.is_none(), "Kill switch should not apply to other proposers");
     }
 }
@@ -1,21 +1,49 @@
 use ethereum_consensus::primitives::{BlsPublicKey, Hash32};
+use serde::{de::DeserializeOwned, Serialize};
 
 use crate::{
     error::AuctioneerError,
+    redis::error::RedisCacheError,
     types::keys::{
-        BID_FLOOR_KEY, BID_FLOOR_VALUE_KEY, BID_TRACE_KEY, BLOCK_BUILDER_LATEST_BID_KEY,
-        BLOCK_BUILDER_LATEST_BID_TIME_KEY, BLOCK_BUILDER_LATEST_BID_VALUE_KEY, EXEC_PAYLOAD_KEY,
-        GET_HEADER_RESPONSE_KEY, HEADER_TX_ROOT, PENDING_BLOCK_KEY,
-        SEEN_BLOCK_HASHES_KEY, TOP_BID_VALUE_KEY,
+        BID_FLOOR_KEY, BID_FLOOR_VALUE_KEY, BID_HISTORY_KEY, BID_TRACE_KEY,
+        BLOCK_BUILDER_LATEST_BID_KEY, BLOCK_BUILDER_LATEST_BID_TIME_KEY,
+        BLOCK_BUILDER_LATEST_BID_VALUE_KEY, BUILDER_SUBMISSION_BYTES_KEY,
+        BUILDER_SUBMISSION_COUNT_KEY, EXEC_PAYLOAD_KEY, GET_HEADER_RESPONSE_KEY, HEADER_TX_ROOT,
+        PENDING_BLOCK_KEY, SEEN_BLOCK_HASHES_KEY, TOP_BID_VALUE_KEY,
     },
 };
 
+/// Every zstd frame starts with this magic number, which can't begin a JSON document. Used to tell
+/// compressed values apart from plain JSON written before compression was introduced.
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+const ZSTD_LEVEL: i32 = 1;
+
+/// Identifies an auction in the keys scoped to it. On Redis Cluster the id is hash-tagged so all
+/// keys of an auction land on the same shard, which multi-key pipelines and scripts require.
+/// Single node and Sentinel deployments keep the untagged keys they already have.
+fn auction_id(
+    slot: u64,
+    parent_hash: &Hash32,
+    proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
+) -> String {
+    if hash_tag {
+        format!("{{{slot}_{parent_hash:?}_{proposer_pub_key:?}}}")
+    } else {
+        format!("{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    }
+}
+
 pub fn get_cache_get_header_response_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{GET_HEADER_RESPONSE_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!(
+        "{GET_HEADER_RESPONSE_KEY}:{}",
+        auction_id(slot, parent_hash, proposer_pub_key, hash_tag)
+    )
 }
 
 pub fn get_execution_payload_key(
@@ -39,9 +67,11 @@ pub fn get_latest_bid_by_builder_key(
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
     builder_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
     format!(
-        "{BLOCK_BUILDER_LATEST_BID_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}/{builder_pub_key:?}"
+        "{BLOCK_BUILDER_LATEST_BID_KEY}:{}/{builder_pub_key:?}",
+        auction_id(slot, parent_hash, proposer_pub_key, hash_tag)
     )
 }
 
@@ -50,9 +80,11 @@ pub fn get_latest_bid_by_builder_key_str_builder_pub_key(
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
     builder_pub_key: &str,
+    hash_tag: bool,
 ) -> String {
     format!(
-        "{BLOCK_BUILDER_LATEST_BID_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}/{builder_pub_key}"
+        "{BLOCK_BUILDER_LATEST_BID_KEY}:{}/{builder_pub_key}",
+        auction_id(slot, parent_hash, proposer_pub_key, hash_tag)
     )
 }
 
@@ -60,48 +92,69 @@ pub fn get_builder_latest_bid_value_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{BLOCK_BUILDER_LATEST_BID_VALUE_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!(
+        "{BLOCK_BUILDER_LATEST_BID_VALUE_KEY}:{}",
+        auction_id(slot, parent_hash, proposer_pub_key, hash_tag)
+    )
 }
 
 pub fn get_builder_latest_bid_time_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{BLOCK_BUILDER_LATEST_BID_TIME_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!(
+        "{BLOCK_BUILDER_LATEST_BID_TIME_KEY}:{}",
+        auction_id(slot, parent_hash, proposer_pub_key, hash_tag)
+    )
 }
 
 pub fn get_top_bid_value_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{TOP_BID_VALUE_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!("{TOP_BID_VALUE_KEY}:{}", auction_id(slot, parent_hash, proposer_pub_key, hash_tag))
 }
 
 pub fn get_floor_bid_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{BID_FLOOR_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!("{BID_FLOOR_KEY}:{}", auction_id(slot, parent_hash, proposer_pub_key, hash_tag))
 }
 
 pub fn get_floor_bid_value_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{BID_FLOOR_VALUE_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!("{BID_FLOOR_VALUE_KEY}:{}", auction_id(slot, parent_hash, proposer_pub_key, hash_tag))
+}
+
+pub fn get_bid_history_key(
+    slot: u64,
+    parent_hash: &Hash32,
+    proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
+) -> String {
+    format!("{BID_HISTORY_KEY}:{}", auction_id(slot, parent_hash, proposer_pub_key, hash_tag))
 }
 
 pub fn get_seen_block_hashes_key(
     slot: u64,
     parent_hash: &Hash32,
     proposer_pub_key: &BlsPublicKey,
+    hash_tag: bool,
 ) -> String {
-    format!("{SEEN_BLOCK_HASHES_KEY}:{slot}_{parent_hash:?}_{proposer_pub_key:?}")
+    format!("{SEEN_BLOCK_HASHES_KEY}:{}", auction_id(slot, parent_hash, proposer_pub_key, hash_tag))
 }
 
 pub fn get_pending_block_builder_key(builder_pub_key: &BlsPublicKey) -> String {
@@ -115,6 +168,17 @@ pub fn get_pending_block_builder_block_hash_key(
     format!("{PENDING_BLOCK_KEY}:{builder_pub_key:?}_{block_hash:?}")
 }
 
+pub fn get_builder_submission_count_key(
+    builder_pub_key: &BlsPublicKey,
+    unix_second: u64,
+) -> String {
+    format!("{BUILDER_SUBMISSION_COUNT_KEY}:{builder_pub_key:?}_{unix_second}")
+}
+
+pub fn get_builder_submission_bytes_key(builder_pub_key: &BlsPublicKey, slot: u64) -> String {
+    format!("{BUILDER_SUBMISSION_BYTES_KEY}:{builder_pub_key:?}_{slot}")
+}
+
 pub fn get_pubkey_from_hex(
     pubkey: &str,
 ) -> Result<BlsPublicKey, ethereum_consensus::crypto::Error> {
@@ -134,3 +198,19 @@ pub fn get_hash_from_hex(hash: &str) -> Result<Hash32, AuctioneerError> {
 pub fn get_header_tx_root_key(hash: &Hash32) -> String {
     format!("{HEADER_TX_ROOT}:{hash:?}")
 }
+
+/// Serializes `value` as zstd-compressed JSON.
+pub fn encode_compressed<T: Serialize>(value: &T) -> Result<Vec<u8>, RedisCacheError> {
+    let json = serde_json::to_vec(value)?;
+    zstd::encode_all(json.as_slice(), ZSTD_LEVEL).map_err(RedisCacheError::ZstdError)
+}
+
+/// Deserializes a value written either by `encode_compressed` or as plain JSON.
+pub fn decode<T: DeserializeOwned>(data: &[u8]) -> Result<T, RedisCacheError> {
+    if data.starts_with(&ZSTD_MAGIC) {
+        let json = zstd::decode_all(data).map_err(RedisCacheError::ZstdError)?;
+        Ok(serde_json::from_slice(&json)?)
+    } else {
+        Ok(serde_json::from_slice(data)?)
+    }
+}
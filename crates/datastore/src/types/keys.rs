@@ -1,12 +1,16 @@
 // Auctioneer
 pub(crate) const LAST_HASH_DELIVERED_KEY: &str = "last-hash-delivered";
 pub(crate) const LAST_SLOT_DELIVERED_KEY: &str = "last-slot-delivered";
+// Set together in one transaction, so on Redis Cluster they share a hash tag to stay on one shard.
+pub(crate) const CLUSTER_LAST_HASH_DELIVERED_KEY: &str = "{last-delivered}:last-hash-delivered";
+pub(crate) const CLUSTER_LAST_SLOT_DELIVERED_KEY: &str = "{last-delivered}:last-slot-delivered";
 pub(crate) const BID_TRACE_KEY: &str = "cache-bid-trace";
 pub(crate) const GET_HEADER_RESPONSE_KEY: &str = "cache-gethead-response";
 pub(crate) const TOP_BID_VALUE_KEY: &str = "top-bid-value";
 pub(crate) const BLOCK_BUILDER_LATEST_BID_KEY: &str = "block-builder-latest-bid";
 pub(crate) const BLOCK_BUILDER_LATEST_BID_TIME_KEY: &str = "block-builder-latest-bid-time";
 pub(crate) const BLOCK_BUILDER_LATEST_BID_VALUE_KEY: &str = "block-builder-latest-bid-value";
+pub(crate) const BID_HISTORY_KEY: &str = "bid-history";
 pub(crate) const BID_FLOOR_KEY: &str = "bid-floor";
 pub(crate) const BID_FLOOR_VALUE_KEY: &str = "bid-floor-value";
 pub(crate) const EXEC_PAYLOAD_KEY: &str = "cache-exec-payload";
@@ -17,4 +21,8 @@ pub(crate) const HOUSEKEEPER_LOCK_KEY: &str = "housekeeper-lock";
 pub(crate) const PENDING_BLOCK_KEY: &str = "pending-block";
 pub(crate) const PRIMEV_PROPOSERS_KEY: &str = "primev-proposers";
 pub(crate) const HEADER_TX_ROOT: &str = "header-tx-root";
-pub(crate) const KILL_SWITCH: &str = "kill-switch";
+pub(crate) const KILL_SWITCHES_KEY: &str = "kill-switches";
+/// Boolean switch from before kill switches were scoped. Migrated on startup.
+pub(crate) const LEGACY_KILL_SWITCH_KEY: &str = "kill-switch";
+pub(crate) const BUILDER_SUBMISSION_COUNT_KEY: &str = "builder-submission-count";
+pub(crate) const BUILDER_SUBMISSION_BYTES_KEY: &str = "builder-submission-bytes";
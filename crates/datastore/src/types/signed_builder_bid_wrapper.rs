@@ -7,6 +7,8 @@ pub struct SignedBuilderBidWrapper {
     pub slot: u64,
     pub builder_pub_key: BlsPublicKey,
     pub received_at_ms: u64,
+    #[serde(default)]
+    pub proposer_pub_key: BlsPublicKey,
 }
 
 impl SignedBuilderBidWrapper {
@@ -15,11 +17,12 @@ impl SignedBuilderBidWrapper {
         slot: u64,
         builder_pub_key: BlsPublicKey,
         received_at: u128,
+        proposer_pub_key: BlsPublicKey,
     ) -> Self {
         // convert received_at to millis, from nanos.
         let received_at = received_at / 1_000_000;
 
-        Self { bid, slot, builder_pub_key, received_at_ms: received_at as u64 }
+        Self { bid, slot, builder_pub_key, received_at_ms: received_at as u64, proposer_pub_key }
     }
 }
 
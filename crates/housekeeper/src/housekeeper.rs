@@ -37,6 +37,11 @@ const PROPOSER_DUTIES_UPDATE_FREQ: u64 = 1;
 
 const TRUSTED_PROPOSERS_UPDATE_FREQ: u64 = 5;
 
+const BUILDER_STATS_REFRESH_FREQ: u64 = EPOCH_SLOTS;
+
+// Prune old rows roughly once an hour.
+const RETENTION_PRUNE_FREQ: u64 = 300;
+
 const CUTT_OFF_TIME: u64 = 4;
 
 // Constants for known validators refresh logic.
@@ -83,6 +88,12 @@ pub struct Housekeeper<
     refreshed_trusted_proposers_slot: Mutex<u64>,
     refresh_trusted_proposers_lock: Mutex<()>,
 
+    refreshed_builder_stats_slot: Mutex<u64>,
+    refresh_builder_stats_lock: Mutex<()>,
+
+    pruned_slot: Mutex<u64>,
+    prune_lock: Mutex<()>,
+
     leader_id: String,
 
     config: RelayConfig,
@@ -113,6 +124,10 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             re_sync_builder_info_lock: Mutex::new(()),
             refreshed_trusted_proposers_slot: Mutex::new(0),
             refresh_trusted_proposers_lock: Mutex::new(()),
+            refreshed_builder_stats_slot: Mutex::new(0),
+            refresh_builder_stats_lock: Mutex::new(()),
+            pruned_slot: Mutex::new(0),
+            prune_lock: Mutex::new(()),
             leader_id: Uuid::new_v4().to_string(),
             config,
             chain_info,
@@ -169,8 +184,14 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             return;
         }
 
-        // Skip processing if the GetPayload route is enabled.
-        if self.config.router_config.enabled_routes.iter().any(|r| r.route == Route::GetPayload) {
+        // Skip processing if either GetPayload route is enabled.
+        if self
+            .config
+            .router_config
+            .enabled_routes
+            .iter()
+            .any(|r| matches!(r.route, Route::GetPayload | Route::GetPayloadV2))
+        {
             return;
         }
 
@@ -224,6 +245,22 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
             });
         }
 
+        // Spawn a task to asynchronously refresh the builder stats views.
+        if self.should_refresh_builder_stats(head_slot).await {
+            let cloned_self = self.clone();
+            tokio::spawn(async move {
+                let _ = cloned_self.refresh_builder_stats(head_slot).await;
+            });
+        }
+
+        // Spawn a task to asynchronously prune rows outside the retention windows.
+        if self.should_prune(head_slot).await {
+            let cloned_self = self.clone();
+            tokio::spawn(async move {
+                let _ = cloned_self.prune_old_rows(head_slot).await;
+            });
+        }
+
         debug!(
             head_slot = head_slot,
             head_slot_pos = (head_slot % EPOCH_SLOTS) + 1,
@@ -526,6 +563,7 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
                         collateral: ethereum_consensus::primitives::U256::from(0),
                         is_optimistic: false,
                         builder_id: Some("PrimevBuilder".to_string()),
+                        ..Default::default()
                     },
                 )
                 .await?;
@@ -540,6 +578,83 @@ impl<DB: DatabaseService, BeaconClient: MultiBeaconClientTrait, A: Auctioneer>
         Ok(())
     }
 
+    /// Refresh the materialized views backing the builder stats data API.
+    ///
+    /// This will lock `refresh_builder_stats_lock` as a refresh can take longer than a slot.
+    async fn refresh_builder_stats(
+        self: &SharedHousekeeper<DB, BeaconClient, A>,
+        head_slot: u64,
+    ) -> Result<(), HousekeeperError> {
+        let _guard = self.refresh_builder_stats_lock.try_lock()?;
+
+        let start_refresh_ts = Instant::now();
+
+        if let Err(err) = self.db.refresh_builder_stats().await {
+            error!(err = %err, "failed to refresh builder stats");
+            return Err(HousekeeperError::DatabaseError(err));
+        }
+
+        *self.refreshed_builder_stats_slot.lock().await = head_slot;
+
+        info!(head_slot = head_slot, refresh_latency_ms = start_refresh_ts.elapsed().as_millis());
+        Ok(())
+    }
+
+    /// Determine if the builder stats should be refreshed for the given slot.
+    async fn should_refresh_builder_stats(
+        self: &SharedHousekeeper<DB, BeaconClient, A>,
+        head_slot: u64,
+    ) -> bool {
+        let builder_stats_slot = *self.refreshed_builder_stats_slot.lock().await;
+        head_slot.saturating_sub(builder_stats_slot) >= BUILDER_STATS_REFRESH_FREQ
+    }
+
+    /// Delete rows older than the configured retention window of each table.
+    ///
+    /// This will lock `prune_lock` to ensure that only one task is pruning at a time.
+    async fn prune_old_rows(
+        self: &SharedHousekeeper<DB, BeaconClient, A>,
+        head_slot: u64,
+    ) -> Result<(), HousekeeperError> {
+        let _guard = self.prune_lock.try_lock()?;
+
+        let now = SystemTime::now();
+        for (table, window) in self.config.retention.windows() {
+            let start_prune_ts = Instant::now();
+            let Some(cutoff) = now.checked_sub(window) else {
+                warn!(?table, "retention window reaches before the epoch, skipping");
+                continue;
+            };
+
+            match self.db.prune_table(table, cutoff).await {
+                Ok(num_deleted) => info!(
+                    ?table,
+                    num_deleted = num_deleted,
+                    prune_latency_ms = start_prune_ts.elapsed().as_millis(),
+                    "pruned table",
+                ),
+                Err(err) => {
+                    error!(?table, err = %err, "failed to prune table");
+                    return Err(HousekeeperError::DatabaseError(err));
+                }
+            }
+        }
+
+        *self.pruned_slot.lock().await = head_slot;
+
+        Ok(())
+    }
+
+    /// Determine if old rows should be pruned for the given slot.
+    async fn should_prune(self: &SharedHousekeeper<DB, BeaconClient, A>, head_slot: u64) -> bool {
+        if self.config.retention.windows().is_empty() {
+            return false;
+        }
+
+        let pruned_slot = *self.pruned_slot.lock().await;
+        head_slot.saturating_sub(pruned_slot) >= RETENTION_PRUNE_FREQ
+    }
+
     /// Determine if the trusted proposers should be refreshed for the given slot.
     ///     
     /// This function checks two conditions:
@@ -1,4 +1,10 @@
+use std::{
+    io::{self, Read, Write},
+    sync::Arc,
+};
+
 use serde::{Deserialize, Serialize};
+use zstd::dict::DecoderDictionary;
 
 #[derive(Debug, Clone, Copy, PartialEq, Deserialize, Serialize)]
 pub enum Encoding {
@@ -6,19 +12,156 @@ pub enum Encoding {
     Ssz,
     JsonGzip,
     SszGzip,
+    JsonZstd,
+    SszZstd,
+    JsonLz4,
+    SszLz4,
 }
 
 impl Encoding {
     pub fn to_headers(&self, request: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        let request = request.header("Content-Type", self.content_type());
+        match self.content_encoding() {
+            Some(content_encoding) => {
+                request.header("Content-Encoding", content_encoding.header_value())
+            }
+            None => request,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
         match self {
-            Encoding::Json => request.header("Content-Type", "application/json"),
-            Encoding::Ssz => request.header("Content-Type", "application/octet-stream"),
-            Encoding::JsonGzip => request
-                .header("Content-Type", "application/json")
-                .header("Content-Encoding", "gzip"),
-            Encoding::SszGzip => request
-                .header("Content-Type", "application/octet-stream")
-                .header("Content-Encoding", "gzip"),
+            Encoding::Json | Encoding::JsonGzip | Encoding::JsonZstd | Encoding::JsonLz4 => {
+                "application/json"
+            }
+            Encoding::Ssz | Encoding::SszGzip | Encoding::SszZstd | Encoding::SszLz4 => {
+                "application/octet-stream"
+            }
+        }
+    }
+
+    /// The compression the body should be sent with, if any.
+    pub fn content_encoding(&self) -> Option<ContentEncoding> {
+        match self {
+            Encoding::Json | Encoding::Ssz => None,
+            Encoding::JsonGzip | Encoding::SszGzip => Some(ContentEncoding::Gzip),
+            Encoding::JsonZstd | Encoding::SszZstd => Some(ContentEncoding::Zstd),
+            Encoding::JsonLz4 | Encoding::SszLz4 => Some(ContentEncoding::Lz4),
+        }
+    }
+}
+
+/// Compression codec of a request body, as named by its `Content-Encoding` header.
+///
+/// Zstd can optionally use a pre-trained dictionary, which pays off for small, repetitive bodies
+/// such as bid submissions. Both sides must use the same dictionary. Lz4 uses the frame format.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+pub enum ContentEncoding {
+    Gzip,
+    Zstd,
+    Lz4,
+}
+
+impl ContentEncoding {
+    /// Parses a `Content-Encoding` header value. Returns `None` for `identity` and any codec we
+    /// don't support.
+    pub fn from_header_value(value: &str) -> Option<Self> {
+        match value.trim() {
+            "gzip" => Some(ContentEncoding::Gzip),
+            "zstd" => Some(ContentEncoding::Zstd),
+            "lz4" => Some(ContentEncoding::Lz4),
+            _ => None,
+        }
+    }
+
+    pub fn header_value(&self) -> &'static str {
+        match self {
+            ContentEncoding::Gzip => "gzip",
+            ContentEncoding::Zstd => "zstd",
+            ContentEncoding::Lz4 => "lz4",
+        }
+    }
+
+    /// Compresses `data`. `dictionary` is only used by zstd.
+    pub fn compress(&self, data: &[u8], dictionary: Option<&[u8]>) -> io::Result<Vec<u8>> {
+        match self {
+            ContentEncoding::Gzip => {
+                let mut encoder =
+                    flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            ContentEncoding::Zstd => match dictionary {
+                Some(dictionary) => {
+                    let mut encoder = zstd::stream::write::Encoder::with_dictionary(
+                        Vec::new(),
+                        zstd::DEFAULT_COMPRESSION_LEVEL,
+                        dictionary,
+                    )?;
+                    encoder.write_all(data)?;
+                    encoder.finish()
+                }
+                None => zstd::encode_all(data, zstd::DEFAULT_COMPRESSION_LEVEL),
+            },
+            ContentEncoding::Lz4 => {
+                let mut encoder = lz4_flex::frame::FrameEncoder::new(Vec::new());
+                encoder.write_all(data)?;
+                encoder.finish().map_err(|err| io::Error::new(io::ErrorKind::Other, err))
+            }
+        }
+    }
+
+    /// Decompresses `data`. `dictionary` is only used by zstd.
+    ///
+    /// At most `limit` bytes are inflated, so a result of exactly `limit` bytes may have been
+    /// truncated. Callers should pass one more than the largest size they accept.
+    pub fn decompress(
+        &self,
+        data: &[u8],
+        dictionary: Option<&ZstdDictionary>,
+        limit: usize,
+    ) -> io::Result<Vec<u8>> {
+        let decoder: Box<dyn Read + '_> = match self {
+            ContentEncoding::Gzip => Box::new(flate2::read::GzDecoder::new(data)),
+            ContentEncoding::Zstd => match dictionary {
+                Some(dictionary) => {
+                    Box::new(zstd::stream::read::Decoder::with_prepared_dictionary(
+                        data,
+                        &dictionary.decoder,
+                    )?)
+                }
+                None => Box::new(zstd::stream::read::Decoder::new(data)?),
+            },
+            ContentEncoding::Lz4 => Box::new(lz4_flex::frame::FrameDecoder::new(data)),
+        };
+
+        // TODO: profile this. 2 is a guess.
+        let mut buf = Vec::with_capacity((data.len() * 2).min(limit));
+        decoder.take(limit as u64).read_to_end(&mut buf)?;
+        Ok(buf)
+    }
+}
+
+/// A zstd dictionary prepared once for decompression, so it isn't parsed again for every body.
+#[derive(Clone)]
+pub struct ZstdDictionary {
+    decoder: Arc<DecoderDictionary<'static>>,
+}
+
+impl ZstdDictionary {
+    /// Reads the dictionary at `path`. Fails if the file can't be read or isn't a valid
+    /// dictionary.
+    pub fn load(path: &str) -> io::Result<Self> {
+        Self::new(&std::fs::read(path)?)
+    }
+
+    /// Prepares `dictionary`, which is either a trained dictionary or raw content.
+    pub fn new(dictionary: &[u8]) -> io::Result<Self> {
+        if dictionary.is_empty() {
+            return Err(io::Error::new(io::ErrorKind::InvalidData, "empty zstd dictionary"));
         }
+        // Preparing a malformed trained dictionary panics, loading it into a decoder errors
+        zstd::stream::read::Decoder::with_dictionary(io::empty(), dictionary)?;
+        Ok(Self { decoder: Arc::new(DecoderDictionary::copy(dictionary)) })
     }
 }
@@ -1,3 +1,8 @@
+use blst::{
+    blst_scalar,
+    min_pk::{PublicKey, Signature},
+    BLST_ERROR,
+};
 use ethereum_consensus::{
     crypto::SecretKey,
     domains::DomainType,
@@ -8,6 +13,12 @@ use ethereum_consensus::{
     state_transition::Context,
     Error, Fork,
 };
+use rand::Rng;
+
+/// Domain separation tag of the proof-of-possession scheme used by the consensus specs.
+const BLS_DST: &[u8] = b"BLS_SIG_BLS12381G2_XMD:SHA-256_SSWU_RO_POP_";
+/// Bit length of the random scalars used to combine signatures in a batch.
+const BATCH_RAND_BITS: usize = 64;
 
 pub fn verify_signed_consensus_message<T: Merkleized>(
     message: &mut T,
@@ -40,6 +51,61 @@ pub fn verify_signed_builder_message<T: Merkleized>(
     Ok(())
 }
 
+/// Verifies many signatures with a single multi-pairing. Each signature is weighted by a random
+/// scalar so that invalid signatures can't cancel each other out. Returns false if any signature
+/// is invalid or malformed, without saying which.
+pub fn batch_verify_signatures(
+    signing_roots: &[Root],
+    signatures: &[&BlsSignature],
+    public_keys: &[&BlsPublicKey],
+) -> bool {
+    if signing_roots.len() != signatures.len() || signing_roots.len() != public_keys.len() {
+        return false;
+    }
+    if signing_roots.is_empty() {
+        return true;
+    }
+
+    let Ok(public_keys) = public_keys
+        .iter()
+        .map(|public_key| PublicKey::from_bytes(public_key.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return false;
+    };
+    let Ok(signatures) = signatures
+        .iter()
+        .map(|signature| Signature::from_bytes(signature.as_ref()))
+        .collect::<Result<Vec<_>, _>>()
+    else {
+        return false;
+    };
+
+    let mut rng = rand::thread_rng();
+    let rands: Vec<blst_scalar> = (0..signing_roots.len())
+        .map(|_| {
+            let mut scalar = blst_scalar::default();
+            scalar.b[..8].copy_from_slice(&rng.gen_range(1..=u64::MAX).to_le_bytes());
+            scalar
+        })
+        .collect();
+
+    let messages: Vec<&[u8]> = signing_roots.iter().map(|root| root.as_ref()).collect();
+    let public_keys: Vec<&PublicKey> = public_keys.iter().collect();
+    let signatures: Vec<&Signature> = signatures.iter().collect();
+
+    Signature::verify_multiple_aggregate_signatures(
+        &messages,
+        BLS_DST,
+        &public_keys,
+        true,
+        &signatures,
+        true,
+        &rands,
+        BATCH_RAND_BITS,
+    ) == BLST_ERROR::BLST_SUCCESS
+}
+
 pub fn compute_consensus_signing_root<T: Merkleized>(
     data: &mut T,
     slot: Slot,